@@ -1,5 +1,6 @@
 /// Functional programming primitives exposed to Python via PyO3
-/// Rust Result<T, E> and Option<T> monads for Python notebooks
+/// Rust Result<T, E>, Option<T> and Either<L, R> monads for Python notebooks,
+/// plus a Validation applicative that accumulates errors
 use pyo3::prelude::*;
 use pyo3::exceptions::PyValueError;
 use pyo3::types::PyModule;
@@ -54,6 +55,14 @@ impl MonadResult {
         }
     }
 
+    /// Unwrap error or raise exception if Ok
+    fn unwrap_err(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match &*self.value {
+            ResultValue::Ok(_) => Err(PyValueError::new_err("Called unwrap_err() on an Ok value")),
+            ResultValue::Err(e) => Ok(e.clone_ref(py)),
+        }
+    }
+
     /// Unwrap or return default
     fn unwrap_or(&self, py: Python, default: Py<PyAny>) -> Py<PyAny> {
         match &*self.value {
@@ -93,6 +102,19 @@ impl MonadResult {
         }
     }
 
+    /// Map function over Err value: result.map_err(lambda e: f"load failed: {e}")
+    fn map_err(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
+        match &*self.value {
+            ResultValue::Ok(_) => Ok(self.clone()),
+            ResultValue::Err(e) => {
+                let error = f.call1(py, (e.clone_ref(py),))?;
+                Ok(MonadResult {
+                    value: Arc::new(ResultValue::Err(error)),
+                })
+            }
+        }
+    }
+
     /// FlatMap for chaining: result.flat_map(lambda x: Result.ok(x * 2))
     fn flat_map(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
         match &*self.value {
@@ -221,6 +243,28 @@ impl MonadOption {
         }
     }
 
+    /// Fallback chaining: option.or_else(lambda: Option.some(default))
+    fn or_else(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
+        match &*self.value {
+            OptionValue::Some(_) => Ok(self.clone()),
+            OptionValue::Nothing => {
+                let opt: MonadOption = f.call0(py)?.extract(py)?;
+                Ok(opt)
+            }
+        }
+    }
+
+    /// Inner value, or compute one with f() when Nothing
+    ///
+    /// Options are immutable, so the computed value is returned but not
+    /// stored in this instance.
+    fn get_or_insert_with(&self, py: Python, f: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        match &*self.value {
+            OptionValue::Some(v) => Ok(v.clone_ref(py)),
+            OptionValue::Nothing => f.call0(py),
+        }
+    }
+
     /// Filter by predicate
     fn filter(&self, py: Python, predicate: Py<PyAny>) -> PyResult<Self> {
         match &*self.value {
@@ -258,6 +302,188 @@ impl MonadOption {
     }
 }
 
+/// Either<L, R> monad - Symmetric branching that isn't error-shaped
+#[pyclass(name = "Either", module = "polars.monads")]
+#[derive(Clone)]
+pub struct MonadEither {
+    value: Arc<EitherValue>,
+}
+
+enum EitherValue {
+    Left(Py<PyAny>),
+    Right(Py<PyAny>),
+}
+
+#[pymethods]
+impl MonadEither {
+    /// Create Left variant: Either.left(value)
+    #[staticmethod]
+    fn left(value: Py<PyAny>) -> PyResult<Self> {
+        Ok(MonadEither {
+            value: Arc::new(EitherValue::Left(value)),
+        })
+    }
+
+    /// Create Right variant: Either.right(value)
+    #[staticmethod]
+    fn right(value: Py<PyAny>) -> PyResult<Self> {
+        Ok(MonadEither {
+            value: Arc::new(EitherValue::Right(value)),
+        })
+    }
+
+    /// Check if Left
+    fn is_left(&self) -> bool {
+        matches!(*self.value, EitherValue::Left(_))
+    }
+
+    /// Check if Right
+    fn is_right(&self) -> bool {
+        matches!(*self.value, EitherValue::Right(_))
+    }
+
+    /// Map function over Right value: either.map(lambda x: x * 2)
+    fn map(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
+        match &*self.value {
+            EitherValue::Right(v) => {
+                let result = f.call1(py, (v.clone_ref(py),))?;
+                Ok(MonadEither {
+                    value: Arc::new(EitherValue::Right(result)),
+                })
+            }
+            EitherValue::Left(_) => Ok(self.clone()),
+        }
+    }
+
+    /// Map function over Left value: either.map_left(lambda x: str(x))
+    fn map_left(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
+        match &*self.value {
+            EitherValue::Left(v) => {
+                let result = f.call1(py, (v.clone_ref(py),))?;
+                Ok(MonadEither {
+                    value: Arc::new(EitherValue::Left(result)),
+                })
+            }
+            EitherValue::Right(_) => Ok(self.clone()),
+        }
+    }
+
+    /// Fold both sides: either.either(on_left=lambda l: ..., on_right=lambda r: ...)
+    fn either(&self, py: Python, on_left: Py<PyAny>, on_right: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        match &*self.value {
+            EitherValue::Left(v) => on_left.call1(py, (v.clone_ref(py),)),
+            EitherValue::Right(v) => on_right.call1(py, (v.clone_ref(py),)),
+        }
+    }
+
+    fn __repr__(&self, _py: Python) -> String {
+        match &*self.value {
+            EitherValue::Left(v) => format!("Either.Left({:?})", v.as_ptr()),
+            EitherValue::Right(v) => format!("Either.Right({:?})", v.as_ptr()),
+        }
+    }
+}
+
+/// Validation<T, E> applicative - Accumulates every error instead of stopping at the first
+#[pyclass(name = "Validation", module = "polars.monads")]
+#[derive(Clone)]
+pub struct MonadValidation {
+    value: Arc<ValidationValue>,
+}
+
+enum ValidationValue {
+    Valid(Py<PyAny>),
+    Invalid(Vec<Py<PyAny>>),
+}
+
+#[pymethods]
+impl MonadValidation {
+    /// Create Valid variant: Validation.valid(value)
+    #[staticmethod]
+    fn valid(value: Py<PyAny>) -> PyResult<Self> {
+        Ok(MonadValidation {
+            value: Arc::new(ValidationValue::Valid(value)),
+        })
+    }
+
+    /// Create Invalid variant: Validation.invalid(["name is required"])
+    #[staticmethod]
+    fn invalid(errors: Vec<Py<PyAny>>) -> PyResult<Self> {
+        Ok(MonadValidation {
+            value: Arc::new(ValidationValue::Invalid(errors)),
+        })
+    }
+
+    /// Check if Valid
+    fn is_valid(&self) -> bool {
+        matches!(*self.value, ValidationValue::Valid(_))
+    }
+
+    /// Check if Invalid
+    fn is_invalid(&self) -> bool {
+        matches!(*self.value, ValidationValue::Invalid(_))
+    }
+
+    /// Unwrap value or raise exception
+    fn unwrap(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match &*self.value {
+            ValidationValue::Valid(v) => Ok(v.clone_ref(py)),
+            ValidationValue::Invalid(_) => {
+                Err(PyValueError::new_err("Called unwrap() on an Invalid value"))
+            }
+        }
+    }
+
+    /// Accumulated errors (empty when Valid)
+    fn errors(&self, py: Python) -> Vec<Py<PyAny>> {
+        match &*self.value {
+            ValidationValue::Valid(_) => Vec::new(),
+            ValidationValue::Invalid(errors) => errors.iter().map(|e| e.clone_ref(py)).collect(),
+        }
+    }
+
+    /// Combine two validations: name.and_(age), or name & age
+    ///
+    /// Both Valid yields `other`; any Invalid side yields Invalid, and when
+    /// both are Invalid their error lists are concatenated.
+    #[pyo3(name = "and_")]
+    fn and(&self, py: Python, other: &MonadValidation) -> MonadValidation {
+        match (&*self.value, &*other.value) {
+            (ValidationValue::Valid(_), _) => other.clone(),
+            (ValidationValue::Invalid(_), ValidationValue::Valid(_)) => self.clone(),
+            (ValidationValue::Invalid(left), ValidationValue::Invalid(right)) => MonadValidation {
+                value: Arc::new(ValidationValue::Invalid(
+                    left.iter().chain(right).map(|e| e.clone_ref(py)).collect(),
+                )),
+            },
+        }
+    }
+
+    fn __and__(&self, py: Python, other: &MonadValidation) -> MonadValidation {
+        self.and(py, other)
+    }
+
+    /// Map function over Valid value
+    fn map(&self, py: Python, f: Py<PyAny>) -> PyResult<Self> {
+        match &*self.value {
+            ValidationValue::Valid(v) => {
+                let result = f.call1(py, (v.clone_ref(py),))?;
+                Ok(MonadValidation {
+                    value: Arc::new(ValidationValue::Valid(result)),
+                })
+            }
+            ValidationValue::Invalid(_) => Ok(self.clone()),
+        }
+    }
+
+    fn __repr__(&self, _py: Python) -> String {
+        match &*self.value {
+            ValidationValue::Valid(v) => format!("Validation.Valid({:?})", v.as_ptr()),
+            ValidationValue::Invalid(errors) => format!("Validation.Invalid({} errors)", errors.len()),
+        }
+    }
+}
+
 /// Thunk<T> - Lazy evaluation with memoization
 #[pyclass(name = "Thunk", module = "polars.monads")]
 pub struct MonadThunk {
@@ -330,6 +556,147 @@ impl MonadThunk {
 pub fn monads(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<MonadResult>()?;
     m.add_class::<MonadOption>()?;
+    m.add_class::<MonadEither>()?;
+    m.add_class::<MonadValidation>()?;
     m.add_class::<MonadThunk>()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(py: Python, v: i64) -> Py<PyAny> {
+        v.into_pyobject(py).unwrap().into_any().unbind()
+    }
+
+    fn lambda(py: Python, code: &std::ffi::CStr) -> Py<PyAny> {
+        py.eval(code, None, None).unwrap().unbind()
+    }
+
+    fn extract_i64(py: Python, obj: Py<PyAny>) -> i64 {
+        obj.extract(py).unwrap()
+    }
+
+    #[test]
+    fn test_result_map_err_and_unwrap_err() {
+        Python::initialize();
+        Python::attach(|py| {
+            let result = MonadResult::err(int(py, 4)).unwrap();
+            let mapped = result.map_err(py, lambda(py, c"lambda e: e * 100")).unwrap();
+            assert!(mapped.is_err());
+            assert_eq!(extract_i64(py, mapped.unwrap_err(py).unwrap()), 400);
+
+            // The original error is shared, not mutated
+            assert_eq!(extract_i64(py, result.unwrap_err(py).unwrap()), 4);
+
+            // Ok passes through map_err untouched and refuses unwrap_err
+            let ok = MonadResult::ok(int(py, 1)).unwrap();
+            let ok = ok.map_err(py, lambda(py, c"lambda e: e * 100")).unwrap();
+            assert_eq!(extract_i64(py, ok.unwrap(py).unwrap()), 1);
+            assert!(ok.unwrap_err(py).is_err());
+        });
+    }
+
+    #[test]
+    fn test_option_or_else() {
+        Python::initialize();
+        Python::attach(|py| {
+            let some = MonadOption::some(int(py, 1)).unwrap();
+            let fallback = py
+                .eval(c"lambda opt: (lambda: opt)", None, None)
+                .unwrap()
+                .call1((MonadOption::some(int(py, 7)).unwrap(),))
+                .unwrap()
+                .unbind();
+
+            let kept = some.or_else(py, fallback.clone_ref(py)).unwrap();
+            assert_eq!(extract_i64(py, kept.unwrap(py).unwrap()), 1);
+
+            let nothing = MonadOption::nothing().unwrap();
+            let replaced = nothing.or_else(py, fallback).unwrap();
+            assert_eq!(extract_i64(py, replaced.unwrap(py).unwrap()), 7);
+            assert!(nothing.is_none());
+        });
+    }
+
+    #[test]
+    fn test_option_get_or_insert_with() {
+        Python::initialize();
+        Python::attach(|py| {
+            let default = lambda(py, c"lambda: 42");
+
+            let some = MonadOption::some(int(py, 1)).unwrap();
+            let value = some.get_or_insert_with(py, default.clone_ref(py)).unwrap();
+            assert_eq!(extract_i64(py, value), 1);
+
+            let nothing = MonadOption::nothing().unwrap();
+            let value = nothing.get_or_insert_with(py, default).unwrap();
+            assert_eq!(extract_i64(py, value), 42);
+            assert!(nothing.is_none());
+        });
+    }
+
+    #[test]
+    fn test_either_left() {
+        Python::initialize();
+        Python::attach(|py| {
+            let either = MonadEither::left(int(py, 1)).unwrap();
+            assert!(either.is_left());
+            assert!(!either.is_right());
+
+            // map only touches Right, map_left only touches Left
+            let mapped = either.map(py, lambda(py, c"lambda x: x * 10")).unwrap();
+            assert!(mapped.is_left());
+            let mapped = mapped.map_left(py, lambda(py, c"lambda x: x + 1")).unwrap();
+
+            let folded = mapped
+                .either(py, lambda(py, c"lambda l: -l"), lambda(py, c"lambda r: r"))
+                .unwrap();
+            assert_eq!(extract_i64(py, folded), -2);
+        });
+    }
+
+    #[test]
+    fn test_either_right() {
+        Python::initialize();
+        Python::attach(|py| {
+            let either = MonadEither::right(int(py, 2)).unwrap();
+            assert!(either.is_right());
+            assert!(!either.is_left());
+
+            let mapped = either.map(py, lambda(py, c"lambda x: x * 10")).unwrap();
+            let mapped = mapped.map_left(py, lambda(py, c"lambda x: 0")).unwrap();
+            assert!(mapped.is_right());
+
+            let folded = mapped
+                .either(py, lambda(py, c"lambda l: -l"), lambda(py, c"lambda r: r"))
+                .unwrap();
+            assert_eq!(extract_i64(py, folded), 20);
+            assert!(mapped.__repr__(py).starts_with("Either.Right("));
+        });
+    }
+
+    #[test]
+    fn test_validation_accumulates_errors() {
+        Python::initialize();
+        Python::attach(|py| {
+            let name = MonadValidation::invalid(vec![int(py, 1)]).unwrap();
+            let age = MonadValidation::invalid(vec![int(py, 2)]).unwrap();
+            let ok = MonadValidation::valid(int(py, 3)).unwrap();
+
+            let combined = name.and(py, &age);
+            assert!(combined.is_invalid());
+            let errors: Vec<i64> = combined
+                .errors(py)
+                .into_iter()
+                .map(|e| extract_i64(py, e))
+                .collect();
+            assert_eq!(errors, vec![1, 2]);
+
+            // A valid side contributes no errors
+            assert_eq!(combined.and(py, &ok).errors(py).len(), 2);
+            assert_eq!(extract_i64(py, ok.and(py, &ok).unwrap(py).unwrap()), 3);
+        });
+    }
+}
@@ -1,30 +1,77 @@
 //! Adaptive streaming reader - the core of the library
 
+use crate::aggregate::{GroupedAggregator, StreamAgg};
 use crate::chunk_strategy::{AdaptiveChunkStrategy, ChunkStrategy};
+use crate::csv_reader::MmapCsvReader;
 use crate::error::{Result, StreamingError};
 use crate::memory_manager::MemoryManager;
 use crate::mmap_reader::MmapParquetReader;
+use crate::ndjson_reader::MmapNdjsonReader;
 use crate::predicate_pushdown::PredicatePushdown;
+use crate::stats::StreamingStats;
 use polars::prelude::*;
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
-/// Main adaptive streaming reader for Parquet files
+/// Position in a file from which streaming can be resumed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamCheckpoint {
+    pub file: PathBuf,
+    pub next_row_group: usize,
+    /// Rows of `next_row_group` already streamed when a chunk strategy
+    /// split it into several batches
+    pub row_offset: usize,
+}
+
+/// Progress snapshot passed to the callback set with `with_progress`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamProgress {
+    /// Rows covered so far, including row groups skipped via statistics
+    pub rows_read: usize,
+    /// Total rows in the file (0 if unknown, e.g. for CSV or NDJSON input)
+    pub total_rows: usize,
+    /// Row groups (or text batches) processed so far, including skipped ones
+    pub row_groups_read: usize,
+    /// Total row groups in the file (0 if unknown, e.g. for CSV or NDJSON input)
+    pub total_row_groups: usize,
+    /// Bytes of the file actually decoded (compressed size for parquet)
+    pub bytes_read: usize,
+}
+
+/// Callback invoked with a progress snapshot after each batch
+pub type ProgressCallback = Box<dyn Fn(StreamProgress) + Send + Sync>;
+
+/// File format specific reader behind an `AdaptiveStreamingReader`
+enum Input {
+    Parquet(MmapParquetReader),
+    Csv(MmapCsvReader),
+    Ndjson(MmapNdjsonReader),
+}
+
+/// Main adaptive streaming reader for Parquet, CSV and NDJSON files
 pub struct AdaptiveStreamingReader {
     path: PathBuf,
-    reader: MmapParquetReader,
+    input: Input,
     memory_manager: MemoryManager,
     chunk_strategy: Box<dyn ChunkStrategy>,
     predicate: Option<Box<dyn PredicatePushdown>>,
+    progress: Option<ProgressCallback>,
     current_row_group: usize,
+    row_offset: usize,
+    rows_read: usize,
+    bytes_read: usize,
+    batches_read: usize,
+    batch_time_ms: f64,
+    started_at: Option<Instant>,
+    #[cfg(feature = "async-stream")]
+    prefetch: usize,
 }
 
 impl AdaptiveStreamingReader {
-    /// Create a new adaptive streaming reader
+    /// Create a new adaptive streaming reader for a Parquet file
     pub fn new(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref().to_path_buf();
         let reader = MmapParquetReader::new(&path)?;
-        let memory_manager = MemoryManager::new()?;
-        let chunk_strategy = Box::new(AdaptiveChunkStrategy::new(memory_manager.clone()));
 
         tracing::info!(
             "AdaptiveStreamingReader created for {}: {} row groups, ~{} total rows",
@@ -33,50 +80,282 @@ impl AdaptiveStreamingReader {
             reader.total_rows()
         );
 
+        Self::from_input(path, Input::Parquet(reader))
+    }
+
+    /// Create a new adaptive streaming reader for a CSV file
+    ///
+    /// Batches hold as many complete lines as the chunk strategy allows.
+    /// The schema is inferred from the first batch and reused afterwards.
+    pub fn new_csv(path: impl AsRef<Path>, options: CsvReadOptions) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let reader = MmapCsvReader::new(&path, options)?;
+
+        tracing::info!(
+            "AdaptiveStreamingReader created for CSV {}: {} bytes",
+            path.display(),
+            reader.file_size()
+        );
+
+        Self::from_input(path, Input::Csv(reader))
+    }
+
+    /// Create a new adaptive streaming reader for a newline-delimited JSON file
+    ///
+    /// Batches hold as many JSON lines as the chunk strategy allows. Records
+    /// may have differing key sets; fields missing from a record are null.
+    pub fn new_ndjson(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let reader = MmapNdjsonReader::new(&path)?;
+
+        tracing::info!(
+            "AdaptiveStreamingReader created for NDJSON {}: {} bytes",
+            path.display(),
+            reader.file_size()
+        );
+
+        Self::from_input(path, Input::Ndjson(reader))
+    }
+
+    fn from_input(path: PathBuf, input: Input) -> Result<Self> {
+        let memory_manager = MemoryManager::new()?;
+        let chunk_strategy = Box::new(AdaptiveChunkStrategy::new(memory_manager.clone()));
+
         Ok(Self {
             path,
-            reader,
+            input,
             memory_manager,
             chunk_strategy,
             predicate: None,
+            progress: None,
             current_row_group: 0,
+            row_offset: 0,
+            rows_read: 0,
+            bytes_read: 0,
+            batches_read: 0,
+            batch_time_ms: 0.0,
+            started_at: None,
+            #[cfg(feature = "async-stream")]
+            prefetch: 2,
         })
     }
 
+    /// Create a reader that resumes streaming from a checkpoint
+    ///
+    /// Row groups before `checkpoint.next_row_group` are never read, nor are
+    /// the first `checkpoint.row_offset` rows of that row group.
+    pub fn resume_from(path: impl AsRef<Path>, checkpoint: &StreamCheckpoint) -> Result<Self> {
+        let mut reader = Self::new(path)?;
+
+        if checkpoint.file != reader.path {
+            tracing::warn!(
+                "Resuming {} from a checkpoint taken for {}",
+                reader.path.display(),
+                checkpoint.file.display()
+            );
+        }
+
+        let parquet = reader.parquet()?;
+        let num_row_groups = parquet.num_row_groups();
+        if checkpoint.next_row_group > num_row_groups {
+            return Err(StreamingError::InvalidConfig(format!(
+                "Checkpoint row group {} out of bounds (file has {} row groups)",
+                checkpoint.next_row_group, num_row_groups
+            )));
+        }
+
+        if checkpoint.row_offset > 0
+            && checkpoint.row_offset >= parquet.row_group_num_rows(checkpoint.next_row_group)?
+        {
+            return Err(StreamingError::InvalidConfig(format!(
+                "Checkpoint row offset {} out of bounds for row group {}",
+                checkpoint.row_offset, checkpoint.next_row_group
+            )));
+        }
+
+        let rows_read = (0..checkpoint.next_row_group)
+            .map(|idx| parquet.row_group_num_rows(idx))
+            .sum::<Result<usize>>()?;
+        reader.current_row_group = checkpoint.next_row_group;
+        reader.row_offset = checkpoint.row_offset;
+        reader.rows_read = rows_read + checkpoint.row_offset;
+        Ok(reader)
+    }
+
+    fn parquet(&self) -> Result<&MmapParquetReader> {
+        match &self.input {
+            Input::Parquet(reader) => Ok(reader),
+            Input::Csv(_) | Input::Ndjson(_) => Err(StreamingError::InvalidConfig(format!(
+                "{} is not a parquet input",
+                self.path.display()
+            ))),
+        }
+    }
+
+    /// Get a progress snapshot of the stream
+    pub fn progress(&self) -> StreamProgress {
+        let (total_rows, total_row_groups) = match &self.input {
+            Input::Parquet(reader) => (reader.total_rows(), reader.num_row_groups()),
+            Input::Csv(_) | Input::Ndjson(_) => (0, 0),
+        };
+
+        StreamProgress {
+            rows_read: self.rows_read,
+            total_rows,
+            row_groups_read: self.current_row_group,
+            total_row_groups,
+            bytes_read: self.bytes_read,
+        }
+    }
+
+    /// Get streaming statistics for the batches produced so far
+    pub fn stats(&self) -> StreamingStats {
+        let avg_chunk_time_ms = if self.batches_read > 0 {
+            self.batch_time_ms / self.batches_read as f64
+        } else {
+            0.0
+        };
+
+        StreamingStats {
+            bytes_read: self.bytes_read as u64,
+            records_processed: self.rows_read,
+            chunks_read: self.batches_read,
+            memory_bytes: self.memory_manager.current_usage() as u64,
+            avg_chunk_time_ms,
+            started_at: self.started_at,
+        }
+    }
+
+    /// Capture the current position so streaming can be resumed later
+    ///
+    /// Only meaningful for parquet input.
+    pub fn checkpoint(&self) -> StreamCheckpoint {
+        StreamCheckpoint {
+            file: self.path.clone(),
+            next_row_group: self.current_row_group,
+            row_offset: self.row_offset,
+        }
+    }
+
     /// Set a custom chunk strategy
-    pub fn with_chunk_strategy(mut self, strategy: Box<dyn ChunkStrategy>) -> Self {
+    ///
+    /// The strategy decides how many rows go into each batch; see
+    /// [`ChunkStrategy`] for the contract. Defaults to an
+    /// [`AdaptiveChunkStrategy`] driven by the reader's memory manager.
+    pub fn with_strategy(mut self, strategy: Box<dyn ChunkStrategy>) -> Self {
         self.chunk_strategy = strategy;
         self
     }
 
+    /// Set a custom chunk strategy
+    #[deprecated(since = "0.1.0", note = "use `with_strategy`")]
+    pub fn with_chunk_strategy(self, strategy: Box<dyn ChunkStrategy>) -> Self {
+        self.with_strategy(strategy)
+    }
+
+    /// Set a callback that receives a progress snapshot after each batch
+    pub fn with_progress(mut self, callback: ProgressCallback) -> Self {
+        self.progress = Some(callback);
+        self
+    }
+
+    /// Restrict decoding to the given columns
+    ///
+    /// The projection is applied by the parquet reader, so unselected columns
+    /// are never decoded and memory estimates only account for these columns.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Result<Self> {
+        self.input = match self.input {
+            Input::Parquet(reader) => Input::Parquet(reader.with_columns(columns)?),
+            Input::Csv(_) | Input::Ndjson(_) => {
+                return Err(StreamingError::InvalidConfig(
+                    "Column projection is only supported for parquet input".to_string(),
+                ))
+            }
+        };
+        Ok(self)
+    }
+
+    /// Fail the stream once a decoded batch would exceed `bytes` of tracked memory
+    ///
+    /// Each batch reserves its estimated size before it is decoded, so a
+    /// batch that doesn't fit is never materialized. A yielded batch stays
+    /// tracked until the next one is requested or the iterator is dropped.
+    pub fn with_hard_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_manager = self.memory_manager.with_hard_limit(bytes);
+        self
+    }
+
     /// Add a predicate for pushdown filtering
+    ///
+    /// Row groups whose statistics rule out any match are skipped without
+    /// being decoded; the predicate is then applied to the remaining batches.
     pub fn with_predicate(mut self, predicate: Box<dyn PredicatePushdown>) -> Self {
         self.predicate = Some(predicate);
         self
     }
 
+    /// Set how many batches `into_stream` may buffer ahead of the consumer
+    #[cfg(feature = "async-stream")]
+    pub fn with_prefetch(mut self, batches: usize) -> Self {
+        self.prefetch = batches.max(1);
+        self
+    }
+
     /// Collect into an iterator of DataFrames with adaptive batching
     ///
     /// This is the main entry point for streaming data
-    pub fn collect_batches_adaptive(self) -> impl Iterator<Item = Result<DataFrame>> {
+    pub fn collect_batches_adaptive(self) -> AdaptiveBatchIterator {
         AdaptiveBatchIterator {
             reader: self,
             exhausted: false,
+            reported: None,
+            in_flight: 0,
         }
     }
 
+    /// Convert into an async stream of DataFrames with adaptive batching
+    ///
+    /// Batches are read on Tokio's blocking thread pool and handed over a
+    /// bounded channel, so a slow consumer applies backpressure to the reader.
+    /// Must be called from within a Tokio runtime.
+    #[cfg(feature = "async-stream")]
+    pub fn into_stream(self) -> impl futures::Stream<Item = Result<DataFrame>> {
+        let (tx, rx) = tokio::sync::mpsc::channel(self.prefetch);
+
+        tokio::task::spawn_blocking(move || {
+            for batch in self.collect_batches_adaptive() {
+                if tx.blocking_send(batch).is_err() {
+                    // Receiver dropped - stop reading
+                    tracing::debug!("Stream receiver dropped, stopping batch production");
+                    break;
+                }
+            }
+        });
+
+        futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|batch| (batch, rx))
+        })
+    }
+
     /// Collect all batches into a single DataFrame
     ///
     /// Note: This loads all data into memory - use only for small files
     pub fn collect(self) -> Result<DataFrame> {
-        let batches: Vec<DataFrame> = self
-            .collect_batches_adaptive()
-            .collect::<Result<Vec<_>>>()?;
+        let mut iter = self.collect_batches_adaptive();
+        let mut batches: Vec<DataFrame> = iter.by_ref().collect::<Result<Vec<_>>>()?;
 
         if batches.is_empty() {
             return Err(StreamingError::NoData);
         }
 
+        // Earlier NDJSON batches lack fields first seen in later ones
+        if let Input::Ndjson(ref ndjson) = iter.reader.input {
+            batches = batches
+                .iter()
+                .map(|df| ndjson.align(df))
+                .collect::<Result<Vec<_>>>()?;
+        }
+
         // Concatenate all batches vertically
         let mut result = batches[0].clone();
         for batch in &batches[1..] {
@@ -85,11 +364,43 @@ impl AdaptiveStreamingReader {
         Ok(result)
     }
 
+    /// Group and aggregate the whole file without collecting it
+    ///
+    /// Each batch is reduced to partial aggregates and merged into a hash
+    /// map of group → accumulators, so memory use is bounded by the batch
+    /// size plus the number of groups. The result has the group columns,
+    /// in first-seen order, followed by one column per aggregation named
+    /// like `qty_sum` (see [`StreamAgg::output_name`]).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use polars_streaming_adaptive::{AdaptiveStreamingReader, StreamAgg};
+    ///
+    /// let reader = AdaptiveStreamingReader::new("trades.parquet").unwrap();
+    /// let per_symbol = reader
+    ///     .stream_aggregate(&["symbol"], &[("qty", StreamAgg::Sum), ("price", StreamAgg::Mean)])
+    ///     .unwrap();
+    /// ```
+    pub fn stream_aggregate(
+        self,
+        group_cols: &[&str],
+        aggs: &[(&str, StreamAgg)],
+    ) -> Result<DataFrame> {
+        let mut aggregator = GroupedAggregator::new(group_cols, aggs)?;
+        for batch in self.collect_batches_adaptive() {
+            aggregator.update(&batch?)?;
+        }
+        aggregator.finish()
+    }
+
     /// Estimate total memory required for full load
     pub fn estimate_memory_required(&self) -> usize {
-        let row_size = self.reader.estimate_row_size();
-        let total_rows = self.reader.total_rows();
-        row_size * total_rows
+        match &self.input {
+            Input::Parquet(reader) => reader.estimated_decompressed_size(),
+            // Decoded text formats are roughly the size of their text
+            Input::Csv(reader) => reader.file_size(),
+            Input::Ndjson(reader) => reader.file_size(),
+        }
     }
 
     /// Check if file can fit in available memory
@@ -101,79 +412,289 @@ impl AdaptiveStreamingReader {
 }
 
 /// Iterator that produces DataFrames with adaptive batching
-struct AdaptiveBatchIterator {
+pub struct AdaptiveBatchIterator {
     reader: AdaptiveStreamingReader,
     exhausted: bool,
+    /// Last snapshot passed to the progress callback
+    reported: Option<StreamProgress>,
+    /// Tracked bytes of the last yielded batch, released once the caller
+    /// asks for the next one or drops the iterator
+    in_flight: usize,
+}
+
+impl AdaptiveBatchIterator {
+    /// Capture the position after the last yielded batch
+    pub fn checkpoint(&self) -> StreamCheckpoint {
+        self.reader.checkpoint()
+    }
+
+    /// Get streaming statistics for the batches produced so far
+    pub fn stats(&self) -> StreamingStats {
+        self.reader.stats()
+    }
 }
 
 impl Iterator for AdaptiveBatchIterator {
     type Item = Result<DataFrame>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        // The caller is done with the previous batch
+        self.release_in_flight();
+
         if self.exhausted {
             return None;
         }
 
-        // Check if we've read all row groups
-        if self.reader.current_row_group >= self.reader.reader.num_row_groups() {
-            self.exhausted = true;
-            return None;
+        let start = Instant::now();
+        self.reader.started_at.get_or_insert(start);
+        #[cfg(feature = "metrics")]
+        let bytes_before = self.reader.bytes_read;
+        let result = match self.reader.input {
+            Input::Parquet(_) => self.next_parquet_batch(),
+            Input::Csv(_) | Input::Ndjson(_) => self.next_text_batch(),
+        };
+
+        match &result {
+            Some(Ok(_)) => {
+                self.reader.batches_read += 1;
+                self.reader.batch_time_ms += start.elapsed().as_secs_f64() * 1000.0;
+
+                #[cfg(feature = "metrics")]
+                {
+                    let metrics = crate::metrics::metrics();
+                    metrics.batches_read.inc();
+                    metrics
+                        .bytes_read
+                        .inc_by((self.reader.bytes_read - bytes_before) as u64);
+                    metrics
+                        .batch_decode_seconds
+                        .observe(start.elapsed().as_secs_f64());
+                }
+
+                self.report_progress();
+            }
+            Some(Err(_)) => self.exhausted = true,
+            None => {
+                self.exhausted = true;
+                // Trailing row groups skipped via statistics yield no batch
+                self.report_progress();
+            }
         }
 
-        // Read next row group
-        let row_group_idx = self.reader.current_row_group;
-        self.reader.current_row_group += 1;
+        result
+    }
+}
+
+impl AdaptiveBatchIterator {
+    /// Pass the current progress to the callback, unless already reported
+    fn report_progress(&mut self) {
+        if let Some(ref callback) = self.reader.progress {
+            let progress = self.reader.progress();
+            if self.reported != Some(progress) {
+                callback(progress);
+                self.reported = Some(progress);
+            }
+        }
+    }
+
+    fn next_parquet_batch(&mut self) -> Option<Result<DataFrame>> {
+        let Input::Parquet(ref parquet) = self.reader.input else {
+            unreachable!("next_parquet_batch called on non-parquet input");
+        };
+
+        // Find the next row group that may contain matching rows
+        let row_group_idx = loop {
+            // Check if we've read all row groups
+            if self.reader.current_row_group >= parquet.num_row_groups() {
+                return None;
+            }
+
+            let idx = self.reader.current_row_group;
+            if self.reader.row_offset > 0 {
+                // Partially streamed row group, already passed the predicate
+                break idx;
+            }
+
+            let should_read = match self.reader.predicate {
+                Some(ref predicate) => predicate.may_match_row_group(parquet, idx),
+                None => Ok(true),
+            };
+
+            match should_read {
+                Ok(true) => break idx,
+                Ok(false) => {
+                    tracing::trace!("Skipping row group {} based on statistics", idx);
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::metrics().rowgroups_skipped.inc();
+                    self.reader.current_row_group += 1;
+                    self.reader.rows_read += parquet.row_group_num_rows(idx).unwrap_or(0);
+                }
+                Err(e) => {
+                    self.reader.current_row_group += 1;
+                    return Some(Err(e));
+                }
+            }
+        };
+
+        // Let the chunk strategy size the batch within this row group
+        let row_size = parquet.estimate_row_size();
+        let available = self.reader.memory_manager.available_memory();
+        let chunk_size = self
+            .reader
+            .chunk_strategy
+            .calculate_chunk_size(available, row_size)
+            .max(1);
+        let group_rows = parquet.row_group_num_rows(row_group_idx).unwrap_or(0);
+        let offset = self.reader.row_offset;
+        let len = chunk_size.min(group_rows - offset);
+
+        // Reserve the estimated size before decoding anything
+        let estimate = row_size * len;
+        if let Err(e) = self.reader.memory_manager.try_track_usage(estimate) {
+            return Some(Err(e));
+        }
 
-        let result = self.read_row_group(row_group_idx);
+        // Read row group slice using memory-mapped reader
+        let start = Instant::now();
+        let result = parquet
+            .read_row_group_slice(row_group_idx, offset, len)
+            .and_then(|df| self.apply_predicate(df));
 
-        // Check for errors
+        let size = result.as_ref().map_or(0, DataFrame::estimated_size);
         match &result {
             Ok(df) => {
-                // Track memory usage
-                let size = df.estimated_size();
-                self.reader.memory_manager.track_usage(size);
-
                 tracing::debug!(
-                    "Read row group {}: {} rows, {}MB",
+                    "Read row group {} rows {}..{}: {} rows, {}MB",
                     row_group_idx,
+                    offset,
+                    offset + len,
                     df.height(),
                     size / 1024 / 1024
                 );
+
+                self.reader.rows_read += len;
+                if offset + len >= group_rows {
+                    self.reader.current_row_group += 1;
+                    self.reader.row_offset = 0;
+                    self.reader.bytes_read += parquet.row_group_byte_size(row_group_idx).unwrap_or(0);
+                } else {
+                    self.reader.row_offset += len;
+                }
+                self.reader
+                    .chunk_strategy
+                    .adjust(size, start.elapsed().as_millis() as u64);
             }
             Err(e) => {
                 tracing::error!("Error reading row group {}: {}", row_group_idx, e);
-                self.exhausted = true;
             }
         }
+        // Replace the reservation with the actual size
+        self.settle_usage(estimate, size);
 
         Some(result)
     }
-}
 
-impl AdaptiveBatchIterator {
-    fn read_row_group(&mut self, row_group_idx: usize) -> Result<DataFrame> {
-        // Read row group using memory-mapped reader
-        let mut df = self.reader.reader.read_row_group(row_group_idx)?;
-
-        // Apply predicate pushdown if specified
-        if let Some(ref predicate) = self.reader.predicate {
-            let mask = predicate.apply(&df)?;
-            df = df.filter(&mask)?;
-
-            tracing::trace!(
-                "Predicate filtered row group {}: {} → {} rows",
-                row_group_idx,
-                self.reader.reader.row_group_num_rows(row_group_idx)?,
-                df.height()
-            );
+    fn next_text_batch(&mut self) -> Option<Result<DataFrame>> {
+        let available = self.reader.memory_manager.available_memory();
+
+        // Decoded text is about as large as the text itself
+        let bytes_per_row = self
+            .reader
+            .bytes_read
+            .checked_div(self.reader.rows_read)
+            .unwrap_or(100);
+        let chunk_size = self
+            .reader
+            .chunk_strategy
+            .calculate_chunk_size(available, bytes_per_row)
+            .max(1);
+        let estimate = chunk_size * bytes_per_row;
+        if let Err(e) = self.reader.memory_manager.try_track_usage(estimate) {
+            return Some(Err(e));
+        }
+
+        let start = Instant::now();
+        let (batch, bytes) = match self.reader.input {
+            Input::Csv(ref mut csv) => {
+                let before = csv.bytes_read();
+                (csv.read_batch(chunk_size), csv.bytes_read() - before)
+            }
+            Input::Ndjson(ref mut ndjson) => {
+                let before = ndjson.bytes_read();
+                (ndjson.read_batch(chunk_size), ndjson.bytes_read() - before)
+            }
+            Input::Parquet(_) => unreachable!("next_text_batch called on parquet input"),
+        };
+
+        let df = match batch {
+            Ok(Some(df)) => df,
+            Ok(None) => {
+                self.settle_usage(estimate, 0);
+                return None;
+            }
+            Err(e) => {
+                self.settle_usage(estimate, 0);
+                tracing::error!("Error reading batch {}: {}", self.reader.current_row_group, e);
+                return Some(Err(e));
+            }
+        };
+
+        self.reader.current_row_group += 1;
+        self.reader.rows_read += df.height();
+        self.reader.bytes_read += bytes;
+
+        let size = df.estimated_size();
+        self.settle_usage(estimate, size);
+        self.reader
+            .chunk_strategy
+            .adjust(size, start.elapsed().as_millis() as u64);
+
+        tracing::debug!(
+            "Read batch {}: {} rows, {}MB",
+            self.reader.current_row_group - 1,
+            df.height(),
+            size / 1024 / 1024
+        );
+
+        Some(self.apply_predicate(df))
+    }
+
+    /// Swap a reservation made before decoding for the decoded size
+    ///
+    /// The decoded size stays tracked until the batch is released.
+    fn settle_usage(&mut self, reserved: usize, actual: usize) {
+        let manager = &self.reader.memory_manager;
+        if actual > reserved {
+            manager.track_usage(actual - reserved);
+        } else {
+            manager.release_usage(reserved - actual);
         }
+        self.in_flight = actual;
+    }
 
-        Ok(df)
+    fn release_in_flight(&mut self) {
+        let bytes = std::mem::take(&mut self.in_flight);
+        self.reader.memory_manager.release_usage(bytes);
+    }
+
+    fn apply_predicate(&self, df: DataFrame) -> Result<DataFrame> {
+        match self.reader.predicate {
+            Some(ref predicate) => {
+                let height = df.height();
+                let mask = predicate.apply(&df)?;
+                let df = df.filter(&mask)?;
+
+                tracing::trace!("Predicate filtered batch: {} → {} rows", height, df.height());
+                Ok(df)
+            }
+            None => Ok(df),
+        }
     }
 }
 
 impl Drop for AdaptiveBatchIterator {
     fn drop(&mut self) {
+        self.release_in_flight();
         tracing::debug!(
             "AdaptiveBatchIterator dropped for {}",
             self.reader.path.display()
@@ -188,6 +709,10 @@ mod tests {
     use uuid::Uuid;
 
     fn create_test_parquet(rows: usize) -> PathBuf {
+        create_test_parquet_with_row_groups(rows, None)
+    }
+
+    fn create_test_parquet_with_row_groups(rows: usize, row_group_size: Option<usize>) -> PathBuf {
         let df = DataFrame::new(vec![
             Series::new("id".into(), (0..rows as i32).collect::<Vec<_>>()).into(),
             Series::new(
@@ -204,6 +729,7 @@ mod tests {
             Uuid::new_v4()));
 
         ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(row_group_size)
             .finish(&mut df.clone())
             .unwrap();
 
@@ -257,4 +783,471 @@ mod tests {
 
         std::fs::remove_file(path).ok();
     }
+
+    #[test]
+    fn test_predicate_skips_row_groups() {
+        use crate::predicate_pushdown::ColumnFilterPredicate;
+
+        // Four row groups with disjoint id ranges: [0, 250), [250, 500), ...
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let reader = AdaptiveStreamingReader::new(&path).unwrap().with_predicate(Box::new(
+            ColumnFilterPredicate::new("id", ">=", AnyValue::Int32(760)),
+        ));
+
+        // Only the last row group is decoded; a post-filter would yield four batches
+        let batches: Vec<DataFrame> = reader
+            .collect_batches_adaptive()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].height(), 240);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[cfg(feature = "async-stream")]
+    #[tokio::test]
+    async fn test_into_stream() {
+        use futures::StreamExt;
+
+        let path = create_test_parquet(1000);
+        let reader = AdaptiveStreamingReader::new(&path).unwrap().with_prefetch(1);
+
+        let batches: Vec<Result<DataFrame>> = reader.into_stream().collect().await;
+        let total_rows: usize = batches
+            .into_iter()
+            .map(|batch| batch.unwrap().height())
+            .sum();
+        assert_eq!(total_rows, 1000);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_column_projection() {
+        let path = create_test_parquet(1000);
+
+        let full = AdaptiveStreamingReader::new(&path).unwrap();
+        let full_estimate = full.estimate_memory_required();
+
+        let projected = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_columns(vec!["value".to_string()])
+            .unwrap();
+        assert!(projected.estimate_memory_required() < full_estimate);
+
+        let df = projected.collect().unwrap();
+        assert_eq!(df.width(), 1);
+        assert_eq!(df.height(), 1000);
+        assert_eq!(df.get_column_names()[0].as_str(), "value");
+
+        let invalid = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_columns(vec!["missing".to_string()]);
+        assert!(matches!(invalid, Err(StreamingError::InvalidConfig(_))));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_checkpoint_resume() {
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let full = AdaptiveStreamingReader::new(&path).unwrap().collect().unwrap();
+
+        let mut batches = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .collect_batches_adaptive();
+        let mut resumed = batches.next().unwrap().unwrap();
+        resumed.vstack_mut(&batches.next().unwrap().unwrap()).unwrap();
+
+        let checkpoint = batches.checkpoint();
+        drop(batches);
+        assert_eq!(checkpoint.next_row_group, 2);
+
+        let rest = AdaptiveStreamingReader::resume_from(&path, &checkpoint)
+            .unwrap()
+            .collect()
+            .unwrap();
+        resumed.vstack_mut(&rest).unwrap();
+
+        assert!(resumed.equals(&full));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    /// Always asks for the same number of rows
+    struct FixedChunkStrategy(usize);
+
+    impl ChunkStrategy for FixedChunkStrategy {
+        fn calculate_chunk_size(&self, _available_memory: usize, _row_size: usize) -> usize {
+            self.0
+        }
+
+        fn adjust(&mut self, _actual_memory_used: usize, _processing_time_ms: u64) {}
+    }
+
+    #[test]
+    fn test_custom_strategy_sizes_batches() {
+        let path = create_test_parquet(1050);
+
+        let batches: Vec<DataFrame> = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_strategy(Box::new(FixedChunkStrategy(100)))
+            .collect_batches_adaptive()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let (last, rest) = batches.split_last().unwrap();
+        assert!(rest.iter().all(|df| df.height() == 100));
+        assert!(last.height() <= 100);
+        assert_eq!(batches.iter().map(|df| df.height()).sum::<usize>(), 1050);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_checkpoint_resume_within_row_group() {
+        let path = create_test_parquet_with_row_groups(1000, Some(500));
+
+        let full = AdaptiveStreamingReader::new(&path).unwrap().collect().unwrap();
+
+        let mut batches = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_strategy(Box::new(FixedChunkStrategy(200)))
+            .collect_batches_adaptive();
+        let mut resumed = batches.next().unwrap().unwrap();
+
+        let checkpoint = batches.checkpoint();
+        drop(batches);
+        assert_eq!(checkpoint.next_row_group, 0);
+        assert_eq!(checkpoint.row_offset, 200);
+
+        let rest = AdaptiveStreamingReader::resume_from(&path, &checkpoint)
+            .unwrap()
+            .collect()
+            .unwrap();
+        resumed.vstack_mut(&rest).unwrap();
+
+        assert!(resumed.equals(&full));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_hard_memory_limit_stops_stream() {
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let mut batches = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_hard_memory_limit(1024)
+            .collect_batches_adaptive();
+
+        assert!(matches!(
+            batches.next(),
+            Some(Err(StreamingError::MemoryExceeded { .. }))
+        ));
+        assert_eq!(batches.stats().memory_bytes, 0);
+        assert!(batches.next().is_none());
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_hard_memory_limit_bounds_batches_in_flight() {
+        let path = create_test_parquet_with_row_groups(10_000, Some(1000));
+        let limit = 64 * 1024;
+
+        let mut batches = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_strategy(Box::new(FixedChunkStrategy(1000)))
+            .with_hard_memory_limit(limit)
+            .collect_batches_adaptive();
+
+        let mut rows = 0;
+        let mut decoded = 0;
+        while let Some(batch) = batches.next() {
+            let df = batch.unwrap();
+            // Only the batch just handed out is still tracked
+            assert_eq!(batches.stats().memory_bytes, df.estimated_size() as u64);
+            rows += df.height();
+            decoded += df.estimated_size();
+        }
+
+        assert_eq!(rows, 10_000);
+        assert!(decoded > limit);
+        assert_eq!(batches.stats().memory_bytes, 0);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_stats_throughput() {
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let mut batches = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .collect_batches_adaptive();
+        assert_eq!(batches.stats().elapsed(), std::time::Duration::ZERO);
+        assert_eq!(batches.stats().records_per_sec(), 0.0);
+
+        batches.next().unwrap().unwrap();
+        batches.next().unwrap().unwrap();
+
+        let stats = batches.stats();
+        assert_eq!(stats.chunks_read, 2);
+        assert!(stats.elapsed() > std::time::Duration::ZERO);
+        assert!(stats.bytes_per_sec() > 0.0);
+        assert!(stats.records_per_sec() > 0.0);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_progress_callback() {
+        use std::sync::{Arc, Mutex};
+
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let reader = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_progress(Box::new(move |progress| sink.lock().unwrap().push(progress)));
+
+        let batches = reader
+            .collect_batches_adaptive()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), batches.len());
+        assert!(events.windows(2).all(|w| w[0].rows_read < w[1].rows_read));
+
+        let last = events.last().unwrap();
+        assert_eq!(last.rows_read, last.total_rows);
+        assert_eq!(last.total_rows, 1000);
+        assert_eq!(last.row_groups_read, last.total_row_groups);
+        assert!(last.bytes_read > 0);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_progress_completes_when_trailing_row_groups_are_skipped() {
+        use crate::predicate_pushdown::ColumnFilterPredicate;
+        use std::sync::{Arc, Mutex};
+
+        let path = create_test_parquet_with_row_groups(1000, Some(250));
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let reader = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .with_predicate(Box::new(ColumnFilterPredicate::new(
+                "id",
+                "<",
+                AnyValue::Int32(100),
+            )))
+            .with_progress(Box::new(move |progress| sink.lock().unwrap().push(progress)));
+
+        let batches = reader
+            .collect_batches_adaptive()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].rows_read, 250);
+        assert_eq!(events[1].rows_read, 1000);
+        assert_eq!(events[1].row_groups_read, events[1].total_row_groups);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_csv_batches_match_one_shot_read() {
+        let rows = 50_000;
+        let mut df = DataFrame::new(vec![
+            Series::new("id".into(), (0..rows as i64).collect::<Vec<_>>()).into(),
+            Series::new(
+                "value".into(),
+                (0..rows).map(|i| i as f64 * 1.5).collect::<Vec<_>>(),
+            )
+            .into(),
+            Series::new(
+                "label".into(),
+                (0..rows).map(|i| format!("row, {}", i)).collect::<Vec<_>>(),
+            )
+            .into(),
+        ])
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "test_adaptive_{}_{}.csv",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        CsvWriter::new(std::fs::File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+
+        let memory_manager = MemoryManager::new().unwrap();
+        let strategy = AdaptiveChunkStrategy::new(memory_manager)
+            .with_min_chunk_size(1000)
+            .with_max_chunk_size(5000);
+        let reader = AdaptiveStreamingReader::new_csv(&path, CsvReadOptions::default())
+            .unwrap()
+            .with_strategy(Box::new(strategy));
+
+        let batches = reader
+            .collect_batches_adaptive()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert!(batches.len() > 1);
+
+        let mut streamed = batches[0].clone();
+        for batch in &batches[1..] {
+            streamed.vstack_mut(batch).unwrap();
+        }
+
+        let expected = CsvReadOptions::default()
+            .try_into_reader_with_file_path(Some(path.clone()))
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        assert_eq!(streamed.height(), rows);
+        assert!(streamed.equals(&expected));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_ndjson_unifies_schema_across_batches() {
+        let path = std::env::temp_dir().join(format!(
+            "test_adaptive_{}_{}.ndjson",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        let mut lines = Vec::new();
+        for i in 0..30 {
+            lines.push(match i % 3 {
+                0 => format!(r#"{{"id": {}, "kind": "trade"}}"#, i),
+                1 => format!(r#"{{"id": {}, "price": {}.5}}"#, i, i),
+                _ if i < 15 => format!(r#"{{"id": {}}}"#, i),
+                _ => format!(r#"{{"id": {}, "venue": "X{}"}}"#, i, i),
+            });
+        }
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+        let memory_manager = MemoryManager::new().unwrap();
+        let strategy = AdaptiveChunkStrategy::new(memory_manager)
+            .with_min_chunk_size(4)
+            .with_max_chunk_size(4);
+        let reader = AdaptiveStreamingReader::new_ndjson(&path)
+            .unwrap()
+            .with_strategy(Box::new(strategy));
+
+        let df = reader.collect().unwrap();
+
+        assert_eq!(df.height(), 30);
+        assert_eq!(
+            df.get_column_names()
+                .iter()
+                .map(|name| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["id", "kind", "price", "venue"]
+        );
+
+        let ids = df.column("id").unwrap().i64().unwrap();
+        assert_eq!(ids.into_no_null_iter().collect::<Vec<_>>(), (0..30).collect::<Vec<_>>());
+        assert_eq!(df.column("kind").unwrap().null_count(), 20);
+        assert_eq!(df.column("price").unwrap().null_count(), 20);
+        assert_eq!(df.column("venue").unwrap().null_count(), 25);
+        assert_eq!(
+            df.column("price").unwrap().f64().unwrap().get(1),
+            Some(1.5)
+        );
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_stream_aggregate_matches_in_memory_group_by() {
+        let rows = 1000;
+        let df = DataFrame::new(vec![
+            Series::new(
+                "symbol".into(),
+                (0..rows).map(|i| if i % 3 == 0 { "AAPL" } else { "MSFT" }).collect::<Vec<_>>(),
+            )
+            .into(),
+            Series::new("qty".into(), (0..rows as i64).collect::<Vec<_>>()).into(),
+            Series::new(
+                "price".into(),
+                (0..rows).map(|i| 100.0 + (i % 17) as f64).collect::<Vec<_>>(),
+            )
+            .into(),
+        ])
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "test_adaptive_agg_{}_{}.parquet",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(100))
+            .finish(&mut df.clone())
+            .unwrap();
+
+        let streamed = AdaptiveStreamingReader::new(&path)
+            .unwrap()
+            .stream_aggregate(
+                &["symbol"],
+                &[
+                    ("qty", StreamAgg::Sum),
+                    ("qty", StreamAgg::Count),
+                    ("price", StreamAgg::Min),
+                    ("price", StreamAgg::Max),
+                    ("price", StreamAgg::Mean),
+                ],
+            )
+            .unwrap()
+            .sort(["symbol"], Default::default())
+            .unwrap();
+
+        let expected = df
+            .lazy()
+            .group_by([col("symbol")])
+            .agg([
+                col("qty").cast(DataType::Float64).sum().alias("qty_sum"),
+                col("qty").count().cast(DataType::UInt64).alias("qty_count"),
+                col("price").min().alias("price_min"),
+                col("price").max().alias("price_max"),
+                col("price").mean().alias("price_mean"),
+            ])
+            .sort(["symbol"], Default::default())
+            .collect()
+            .unwrap();
+
+        assert_eq!(streamed.height(), 2);
+        assert_eq!(streamed.get_column_names(), expected.get_column_names());
+        for name in ["symbol", "qty_sum", "qty_count", "price_min", "price_max"] {
+            assert!(
+                streamed.column(name).unwrap().equals(expected.column(name).unwrap()),
+                "{} differs",
+                name
+            );
+        }
+        let means = streamed.column("price_mean").unwrap().f64().unwrap();
+        let expected_means = expected.column("price_mean").unwrap().f64().unwrap();
+        for (got, want) in means.into_no_null_iter().zip(expected_means.into_no_null_iter()) {
+            assert!((got - want).abs() < 1e-9);
+        }
+
+        std::fs::remove_file(path).ok();
+    }
 }
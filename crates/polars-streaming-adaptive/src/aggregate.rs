@@ -0,0 +1,274 @@
+//! Streaming group-by aggregation across batches
+
+use crate::error::{Result, StreamingError};
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Aggregation supported by `AdaptiveStreamingReader::stream_aggregate`
+///
+/// `Sum`, `Min`, `Max` and `Mean` work on numeric columns and produce
+/// `Float64`; `Count` counts non-null values and produces `UInt64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamAgg {
+    Sum,
+    Count,
+    Min,
+    Max,
+    Mean,
+}
+
+impl StreamAgg {
+    /// Output column name for this aggregation of `column`, e.g. `qty_sum`
+    pub fn output_name(&self, column: &str) -> String {
+        format!("{}_{}", column, self)
+    }
+}
+
+impl fmt::Display for StreamAgg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StreamAgg::Sum => "sum",
+            StreamAgg::Count => "count",
+            StreamAgg::Min => "min",
+            StreamAgg::Max => "max",
+            StreamAgg::Mean => "mean",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Running state of one aggregation for one group
+#[derive(Debug, Clone, Copy)]
+enum Accumulator {
+    Sum(f64),
+    Count(u64),
+    Min(Option<f64>),
+    Max(Option<f64>),
+    Mean { sum: f64, count: u64 },
+}
+
+impl Accumulator {
+    fn new(agg: StreamAgg) -> Self {
+        match agg {
+            StreamAgg::Sum => Accumulator::Sum(0.0),
+            StreamAgg::Count => Accumulator::Count(0),
+            StreamAgg::Min => Accumulator::Min(None),
+            StreamAgg::Max => Accumulator::Max(None),
+            StreamAgg::Mean => Accumulator::Mean { sum: 0.0, count: 0 },
+        }
+    }
+}
+
+// Column name prefixes of the per-batch partial aggregates
+const PARTIAL_SUM: &str = "__partial_sum";
+const PARTIAL_COUNT: &str = "__partial_count";
+const PARTIAL_MIN: &str = "__partial_min";
+const PARTIAL_MAX: &str = "__partial_max";
+
+/// Folds batches into per-group accumulators
+///
+/// Each batch is first reduced by Polars to one row per group; only those
+/// partial rows are merged into the hash map, so the state grows with the
+/// number of groups rather than the number of rows.
+pub(crate) struct GroupedAggregator {
+    group_cols: Vec<String>,
+    aggs: Vec<(String, StreamAgg)>,
+    key_dtypes: Option<Vec<DataType>>,
+    index: HashMap<Vec<AnyValue<'static>>, usize>,
+    groups: Vec<(Vec<AnyValue<'static>>, Vec<Accumulator>)>,
+}
+
+impl GroupedAggregator {
+    pub(crate) fn new(group_cols: &[&str], aggs: &[(&str, StreamAgg)]) -> Result<Self> {
+        if group_cols.is_empty() {
+            return Err(StreamingError::InvalidConfig(
+                "stream_aggregate needs at least one group column".to_string(),
+            ));
+        }
+        if aggs.is_empty() {
+            return Err(StreamingError::InvalidConfig(
+                "stream_aggregate needs at least one aggregation".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            group_cols: group_cols.iter().map(|c| c.to_string()).collect(),
+            aggs: aggs.iter().map(|(c, agg)| (c.to_string(), *agg)).collect(),
+            key_dtypes: None,
+            index: HashMap::new(),
+            groups: Vec::new(),
+        })
+    }
+
+    /// Merge one batch into the running state
+    pub(crate) fn update(&mut self, df: &DataFrame) -> Result<()> {
+        if self.key_dtypes.is_none() {
+            let dtypes = self
+                .group_cols
+                .iter()
+                .map(|c| Ok(df.column(c)?.dtype().clone()))
+                .collect::<Result<Vec<_>>>()?;
+            self.key_dtypes = Some(dtypes);
+        }
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let partial = self.partial_aggregate(df)?;
+        let keys = self
+            .group_cols
+            .iter()
+            .map(|c| partial.column(c))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let partials = (0..self.aggs.len())
+            .map(|i| Partials::new(&partial, i))
+            .collect::<Result<Vec<_>>>()?;
+
+        for row in 0..partial.height() {
+            let key = keys
+                .iter()
+                .map(|c| Ok(c.get(row)?.into_static()))
+                .collect::<Result<Vec<_>>>()?;
+
+            let idx = match self.index.get(&key) {
+                Some(&idx) => idx,
+                None => {
+                    let accumulators = self.aggs.iter().map(|(_, agg)| Accumulator::new(*agg));
+                    self.groups.push((key.clone(), accumulators.collect()));
+                    self.index.insert(key, self.groups.len() - 1);
+                    self.groups.len() - 1
+                }
+            };
+
+            for (acc, partials) in self.groups[idx].1.iter_mut().zip(&partials) {
+                partials.merge_into(acc, row);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reduce a batch to one row per group with the partial aggregates
+    fn partial_aggregate(&self, df: &DataFrame) -> Result<DataFrame> {
+        let mut exprs = Vec::new();
+        for (i, (column, agg)) in self.aggs.iter().enumerate() {
+            let value = col(column.as_str()).cast(DataType::Float64);
+            let partial = |name: &str| format!("{}_{}", name, i);
+            match agg {
+                StreamAgg::Sum => exprs.push(value.sum().alias(partial(PARTIAL_SUM))),
+                StreamAgg::Count => exprs.push(value.count().alias(partial(PARTIAL_COUNT))),
+                StreamAgg::Min => exprs.push(value.min().alias(partial(PARTIAL_MIN))),
+                StreamAgg::Max => exprs.push(value.max().alias(partial(PARTIAL_MAX))),
+                StreamAgg::Mean => {
+                    exprs.push(value.clone().sum().alias(partial(PARTIAL_SUM)));
+                    exprs.push(value.count().alias(partial(PARTIAL_COUNT)));
+                }
+            }
+        }
+
+        let keys: Vec<Expr> = self.group_cols.iter().map(|c| col(c.as_str())).collect();
+        Ok(df.clone().lazy().group_by(keys).agg(exprs).collect()?)
+    }
+
+    /// Build the result: group columns followed by one column per aggregation
+    ///
+    /// Groups appear in the order they were first seen.
+    pub(crate) fn finish(self) -> Result<DataFrame> {
+        let key_dtypes = self.key_dtypes.ok_or(StreamingError::NoData)?;
+
+        let mut columns = Vec::with_capacity(self.group_cols.len() + self.aggs.len());
+        for (i, (name, dtype)) in self.group_cols.iter().zip(&key_dtypes).enumerate() {
+            let values: Vec<AnyValue> = self.groups.iter().map(|(key, _)| key[i].clone()).collect();
+            let series = Series::from_any_values_and_dtype(name.into(), &values, dtype, true)?;
+            columns.push(series.into());
+        }
+
+        for (i, (column, agg)) in self.aggs.iter().enumerate() {
+            let name = agg.output_name(column);
+            let accumulators = self.groups.iter().map(|(_, accs)| accs[i]);
+            let series = match agg {
+                StreamAgg::Count => Series::new(
+                    name.into(),
+                    accumulators
+                        .map(|acc| match acc {
+                            Accumulator::Count(count) => count,
+                            _ => unreachable!("count aggregation with {:?}", acc),
+                        })
+                        .collect::<Vec<u64>>(),
+                ),
+                _ => Series::new(
+                    name.into(),
+                    accumulators
+                        .map(|acc| match acc {
+                            Accumulator::Sum(sum) => Some(sum),
+                            Accumulator::Min(min) => min,
+                            Accumulator::Max(max) => max,
+                            Accumulator::Mean { sum, count } => {
+                                (count > 0).then(|| sum / count as f64)
+                            }
+                            Accumulator::Count(_) => unreachable!("numeric aggregation with count"),
+                        })
+                        .collect::<Vec<Option<f64>>>(),
+                ),
+            };
+            columns.push(series.into());
+        }
+
+        Ok(DataFrame::new(columns)?)
+    }
+}
+
+/// Partial aggregate columns of one aggregation within a batch
+struct Partials {
+    sum: Option<Float64Chunked>,
+    count: Option<UInt64Chunked>,
+    min: Option<Float64Chunked>,
+    max: Option<Float64Chunked>,
+}
+
+impl Partials {
+    fn new(partial: &DataFrame, i: usize) -> Result<Self> {
+        let f64_column = |name: &str| -> Result<Option<Float64Chunked>> {
+            match partial.column(&format!("{}_{}", name, i)) {
+                Ok(c) => Ok(Some(c.f64()?.clone())),
+                Err(_) => Ok(None),
+            }
+        };
+        let count = match partial.column(&format!("{}_{}", PARTIAL_COUNT, i)) {
+            Ok(c) => Some(c.cast(&DataType::UInt64)?.u64()?.clone()),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            sum: f64_column(PARTIAL_SUM)?,
+            count,
+            min: f64_column(PARTIAL_MIN)?,
+            max: f64_column(PARTIAL_MAX)?,
+        })
+    }
+
+    fn merge_into(&self, acc: &mut Accumulator, row: usize) {
+        let get_f64 = |ca: &Option<Float64Chunked>| ca.as_ref().and_then(|ca| ca.get(row));
+        let get_count = || self.count.as_ref().and_then(|ca| ca.get(row)).unwrap_or(0);
+
+        match acc {
+            Accumulator::Sum(sum) => *sum += get_f64(&self.sum).unwrap_or(0.0),
+            Accumulator::Count(count) => *count += get_count(),
+            Accumulator::Min(min) => {
+                if let Some(value) = get_f64(&self.min) {
+                    *min = Some(min.map_or(value, |m| m.min(value)));
+                }
+            }
+            Accumulator::Max(max) => {
+                if let Some(value) = get_f64(&self.max) {
+                    *max = Some(max.map_or(value, |m| m.max(value)));
+                }
+            }
+            Accumulator::Mean { sum, count } => {
+                *sum += get_f64(&self.sum).unwrap_or(0.0);
+                *count += get_count();
+            }
+        }
+    }
+}
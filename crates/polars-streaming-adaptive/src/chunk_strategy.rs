@@ -3,9 +3,20 @@
 use crate::memory_manager::MemoryManager;
 
 /// Trait for chunk sizing strategies
+///
+/// The reader asks the strategy for a batch size before every batch and
+/// reports back after decoding it:
+///
+/// - `calculate_chunk_size` receives the memory currently available to the
+///   stream and the estimated decoded size of one row, both in bytes, and
+///   returns the number of rows to put in the next batch. Parquet batches
+///   never span row groups, so a row group smaller than the returned size
+///   becomes a shorter batch. A return value of 0 is treated as 1.
+/// - `adjust` receives the decoded size of that batch in bytes and how long
+///   it took to read, so stateful strategies can adapt.
 pub trait ChunkStrategy: Send + Sync {
-    /// Calculate optimal chunk size based on available memory
-    fn calculate_chunk_size(&self, available_memory: usize) -> usize;
+    /// Calculate optimal chunk size in rows based on available memory
+    fn calculate_chunk_size(&self, available_memory: usize, row_size: usize) -> usize;
 
     /// Adjust chunk size based on performance feedback
     fn adjust(&mut self, actual_memory_used: usize, processing_time_ms: u64);
@@ -52,13 +63,11 @@ impl AdaptiveChunkStrategy {
 }
 
 impl ChunkStrategy for AdaptiveChunkStrategy {
-    fn calculate_chunk_size(&self, available_memory: usize) -> usize {
+    fn calculate_chunk_size(&self, available_memory: usize, row_size: usize) -> usize {
         let target_memory = (available_memory as f64 * self.target_memory_ratio) as usize;
         
         // Estimate rows that fit in target memory
-        // Assume average 100 bytes per row for OHLCV data
-        let estimated_row_size = 100;
-        let rows = target_memory / estimated_row_size;
+        let rows = target_memory / row_size.max(1);
 
         rows.clamp(self.min_chunk_size, self.max_chunk_size)
     }
@@ -93,7 +102,7 @@ mod tests {
         let strategy = AdaptiveChunkStrategy::new(memory_manager.clone());
 
         let available = memory_manager.available_memory();
-        let chunk_size = strategy.calculate_chunk_size(available);
+        let chunk_size = strategy.calculate_chunk_size(available, 100);
 
         assert!(chunk_size >= strategy.min_chunk_size);
         assert!(chunk_size <= strategy.max_chunk_size);
@@ -0,0 +1,172 @@
+//! Memory-mapped CSV reader that yields batches on line boundaries
+
+use crate::error::{Result, StreamingError};
+use memmap2::Mmap;
+use polars::prelude::*;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Memory-mapped CSV reader for streaming large files in row batches
+pub struct MmapCsvReader {
+    path: PathBuf,
+    mmap: Arc<Mmap>,
+    options: CsvReadOptions,
+    schema: Option<SchemaRef>,
+    offset: usize,
+}
+
+impl MmapCsvReader {
+    /// Create a new memory-mapped CSV reader
+    ///
+    /// `options` are applied to the first batch; the schema inferred from it
+    /// is reused for every following batch.
+    pub fn new(path: impl AsRef<Path>, options: CsvReadOptions) -> Result<Self> {
+        let path_buf = path.as_ref().to_path_buf();
+        let file = File::open(&path_buf)?;
+
+        // Safety: We trust that the file won't be modified while mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Ok(Self {
+            path: path_buf,
+            mmap: Arc::new(mmap),
+            options: options.with_path(None::<PathBuf>).with_n_rows(None),
+            schema: None,
+            offset: 0,
+        })
+    }
+
+    /// Read the next batch of up to `n_rows` complete lines
+    ///
+    /// Returns `None` once the whole file has been consumed.
+    pub fn read_batch(&mut self, n_rows: usize) -> Result<Option<DataFrame>> {
+        if self.is_exhausted() {
+            return Ok(None);
+        }
+
+        let options = match &self.schema {
+            Some(schema) => self
+                .options
+                .clone()
+                .with_has_header(false)
+                .with_skip_rows(0)
+                .with_skip_rows_after_header(0)
+                .with_schema(Some(schema.clone())),
+            None => self.options.clone(),
+        };
+
+        // The first batch also has to cover the header and skipped lines
+        let n_lines = match self.schema {
+            Some(_) => n_rows,
+            None => {
+                n_rows
+                    + usize::from(self.options.has_header)
+                    + self.options.skip_rows
+                    + self.options.skip_rows_after_header
+            }
+        };
+
+        let start = self.offset;
+        let end = self.find_batch_end(start, n_lines.max(1));
+        let chunk = &self.mmap[start..end];
+
+        let df = options
+            .into_reader_with_file_handle(std::io::Cursor::new(chunk))
+            .finish()
+            .map_err(|e| {
+                StreamingError::Compute(format!(
+                    "Failed to parse CSV bytes {}..{} of {}: {}",
+                    start,
+                    end,
+                    self.path.display(),
+                    e
+                ))
+            })?;
+
+        if self.schema.is_none() {
+            self.schema = Some(Arc::new(df.schema()));
+        }
+        self.offset = end;
+
+        Ok(Some(df))
+    }
+
+    /// Find the byte offset just past the `n_lines`-th line ending after `start`
+    ///
+    /// Line endings inside quoted fields are not counted.
+    fn find_batch_end(&self, start: usize, n_lines: usize) -> usize {
+        let quote_char = self.options.parse_options.quote_char;
+        let eol_char = self.options.parse_options.eol_char;
+
+        let mut in_quotes = false;
+        let mut lines = 0;
+        for (i, &byte) in self.mmap[start..].iter().enumerate() {
+            if Some(byte) == quote_char {
+                in_quotes = !in_quotes;
+            } else if byte == eol_char && !in_quotes {
+                lines += 1;
+                if lines == n_lines {
+                    return start + i + 1;
+                }
+            }
+        }
+
+        self.mmap.len()
+    }
+
+    /// Check whether all bytes have been consumed
+    pub fn is_exhausted(&self) -> bool {
+        self.mmap[self.offset..]
+            .iter()
+            .all(|b| b.is_ascii_whitespace())
+    }
+
+    /// Get schema, available once the first batch has been read
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.schema.as_ref()
+    }
+
+    /// Get number of bytes consumed so far
+    pub fn bytes_read(&self) -> usize {
+        self.offset
+    }
+
+    /// Get file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get file size in bytes
+    pub fn file_size(&self) -> usize {
+        self.mmap.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_batches_end_on_line_boundaries() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "id,name").unwrap();
+        for i in 0..10 {
+            writeln!(file, "{},\"line\nbreak {}\"", i, i).unwrap();
+        }
+        file.flush().unwrap();
+
+        let mut reader = MmapCsvReader::new(file.path(), CsvReadOptions::default()).unwrap();
+
+        let mut heights = Vec::new();
+        while let Some(df) = reader.read_batch(4).unwrap() {
+            assert_eq!(df.get_column_names()[1].as_str(), "name");
+            heights.push(df.height());
+        }
+
+        assert_eq!(heights, vec![4, 4, 2]);
+        assert!(reader.is_exhausted());
+    }
+}
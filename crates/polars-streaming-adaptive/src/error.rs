@@ -21,9 +21,25 @@ pub enum StreamingError {
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
 
+    #[error("Memory limit exceeded: {requested} bytes requested with {current} of {limit} in use")]
+    MemoryExceeded {
+        requested: usize,
+        current: usize,
+        limit: usize,
+    },
+
     #[error("No data available")]
     NoData,
 
+    #[error("Read cancelled")]
+    Cancelled,
+
+    #[error("Schema mismatch in {}: {detail}", path.display())]
+    SchemaMismatch {
+        path: std::path::PathBuf,
+        detail: String,
+    },
+
     #[error("Computation error: {0}")]
     Compute(String),
 }
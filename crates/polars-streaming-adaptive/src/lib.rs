@@ -6,10 +6,16 @@
 //! ## Features
 //!
 //! - **Memory-mapped I/O**: Zero-copy parquet reads using `memmap2`
+//! - **CSV and NDJSON input**: Line-aligned batches from memory-mapped text files
 //! - **Adaptive batching**: Automatically adjusts batch sizes based on available memory
 //! - **Parallel streaming**: Multi-file processing with Rayon work stealing
 //! - **Predicate pushdown**: Filter data before loading into memory
+//! - **Streaming aggregation**: Group-by sums, counts, min/max and means without collecting
+//! - **Parquet sink**: Write batches to disk incrementally
 //! - **Python bindings**: Optional `pyo3` integration for use from Python
+//! - **Metrics**: Optional Prometheus counters and histograms (`metrics` feature)
+//! - **Data sources**: Optional CSV, HTTP, filesystem, S3, DynamoDB and Postgres
+//!   sources (`sources` feature)
 //!
 //! ## Example
 //!
@@ -25,23 +31,49 @@
 
 pub mod error;
 pub mod mmap_reader;
+pub mod csv_reader;
+pub mod ndjson_reader;
 pub mod memory_manager;
 pub mod chunk_strategy;
 pub mod adaptive_reader;
+pub mod aggregate;
 pub mod parallel_stream;
 pub mod predicate_pushdown;
+pub mod sink;
+mod schema;
+pub mod stats;
 
 #[cfg(feature = "python")]
 pub mod python;
 
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "sources")]
+pub mod sources;
+
 // Re-exports
 pub use error::{Result, StreamingError};
-pub use mmap_reader::MmapParquetReader;
-pub use memory_manager::MemoryManager;
+pub use mmap_reader::{ColumnStatistics, MmapParquetReader, RowGroupIter};
+pub use csv_reader::MmapCsvReader;
+pub use ndjson_reader::MmapNdjsonReader;
+pub use memory_manager::{MemoryManager, PressureCallback};
 pub use chunk_strategy::{AdaptiveChunkStrategy, ChunkStrategy};
-pub use adaptive_reader::AdaptiveStreamingReader;
-pub use parallel_stream::{ParallelStreamReader, from_glob};
-pub use predicate_pushdown::{PredicatePushdown, ColumnFilterPredicate, AndPredicate};
+pub use adaptive_reader::{
+    AdaptiveBatchIterator, AdaptiveStreamingReader, ProgressCallback, StreamCheckpoint,
+    StreamProgress,
+};
+pub use aggregate::StreamAgg;
+pub use parallel_stream::{ErrorMode, ParallelStreamReader, from_glob};
+pub use sink::ParquetSink;
+pub use stats::StreamingStats;
+pub use predicate_pushdown::{
+    PredicatePushdown, ColumnFilterPredicate, AndPredicate, NotPredicate, InListPredicate,
+    NullFilterPredicate, StringMatchPredicate, StringMatchMode,
+};
 
 #[cfg(feature = "python")]
 pub use python::*;
+
+#[cfg(feature = "metrics")]
+pub use metrics::metrics_registry;
@@ -1,10 +1,22 @@
 //! Memory management and tracking for adaptive streaming
+//!
+//! Inside a container, the cgroup memory limit is usually far below the
+//! host RAM that `sysinfo` reports, so limits from cgroup v1 or v2 take
+//! precedence when they are tighter.
 
-use crate::error::Result;
+use crate::error::{Result, StreamingError};
 use parking_lot::RwLock;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use sysinfo::System;
 
+/// Callback invoked when memory pressure crosses a threshold
+pub type PressureCallback = Box<dyn Fn() + Send + Sync>;
+
+/// Default mount point of the cgroup filesystem
+const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
 /// Memory manager for tracking and managing available memory
 #[derive(Clone)]
 pub struct MemoryManager {
@@ -13,8 +25,65 @@ pub struct MemoryManager {
 
 struct MemoryManagerInner {
     system: System,
+    cgroup: Option<CgroupMemory>,
     current_usage: usize,
     peak_usage: usize,
+    hard_limit: Option<usize>,
+    pressure_hooks: Vec<PressureHook>,
+}
+
+/// Registered threshold and whether it has fired since the last crossing
+struct PressureHook {
+    threshold: f64,
+    callback: Arc<dyn Fn() + Send + Sync>,
+    above: bool,
+}
+
+/// Memory controller files of the cgroup this process runs in
+#[derive(Debug, Clone)]
+struct CgroupMemory {
+    limit_file: PathBuf,
+    usage_file: PathBuf,
+}
+
+impl CgroupMemory {
+    /// Find the v2 or v1 memory controller under `root`
+    fn detect(root: &Path) -> Option<Self> {
+        let v2 = root.join("memory.max");
+        if v2.is_file() {
+            return Some(Self {
+                limit_file: v2,
+                usage_file: root.join("memory.current"),
+            });
+        }
+
+        let v1 = root.join("memory").join("memory.limit_in_bytes");
+        if v1.is_file() {
+            return Some(Self {
+                limit_file: v1,
+                usage_file: root.join("memory").join("memory.usage_in_bytes"),
+            });
+        }
+
+        None
+    }
+
+    /// Memory limit in bytes, `None` when unlimited
+    ///
+    /// v2 writes `max` for no limit; v1 uses a huge page-aligned value,
+    /// which the caller clamps against system memory anyway.
+    fn limit(&self) -> Option<usize> {
+        read_bytes(&self.limit_file)
+    }
+
+    /// Memory currently charged to the cgroup
+    fn usage(&self) -> Option<usize> {
+        read_bytes(&self.usage_file)
+    }
+}
+
+fn read_bytes(path: &Path) -> Option<usize> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
 }
 
 impl MemoryManager {
@@ -26,24 +95,66 @@ impl MemoryManager {
         Ok(Self {
             inner: Arc::new(RwLock::new(MemoryManagerInner {
                 system,
+                cgroup: CgroupMemory::detect(Path::new(CGROUP_ROOT)),
                 current_usage: 0,
                 peak_usage: 0,
+                hard_limit: None,
+                pressure_hooks: Vec::new(),
             })),
         })
     }
 
+    /// Read cgroup memory limits from `root` instead of `/sys/fs/cgroup`
+    pub fn with_cgroup_root(self, root: impl AsRef<Path>) -> Self {
+        self.inner.write().cgroup = CgroupMemory::detect(root.as_ref());
+        self
+    }
+
+    /// Refuse tracked allocations that would take usage above `bytes`
+    ///
+    /// Only enforced by `try_track_usage`.
+    pub fn with_hard_limit(self, bytes: usize) -> Self {
+        self.inner.write().hard_limit = Some(bytes);
+        self
+    }
+
+    /// Get the hard ceiling on tracked usage, if any
+    pub fn hard_limit(&self) -> Option<usize> {
+        self.inner.read().hard_limit
+    }
+
+    /// Check whether a cgroup memory limit applies to this process
+    pub fn in_container(&self) -> bool {
+        let inner = self.inner.read();
+        inner.cgroup.as_ref().and_then(CgroupMemory::limit).is_some()
+    }
+
     /// Get available memory in bytes
+    ///
+    /// Bounded by the headroom left under the cgroup limit, if any.
     pub fn available_memory(&self) -> usize {
         let mut inner = self.inner.write();
         inner.system.refresh_memory();
-        inner.system.available_memory() as usize
+        let available = inner.system.available_memory() as usize;
+
+        match inner.cgroup.as_ref().and_then(|cg| Some((cg.limit()?, cg.usage().unwrap_or(0)))) {
+            Some((limit, usage)) => available.min(limit.saturating_sub(usage)),
+            None => available,
+        }
     }
 
-    /// Get total system memory in bytes
+    /// Get total memory in bytes
+    ///
+    /// The cgroup limit if one is set and below system memory.
     pub fn total_memory(&self) -> usize {
         let mut inner = self.inner.write();
         inner.system.refresh_memory();
-        inner.system.total_memory() as usize
+        let total = inner.system.total_memory() as usize;
+
+        match inner.cgroup.as_ref().and_then(CgroupMemory::limit) {
+            Some(limit) => total.min(limit),
+            None => total,
+        }
     }
 
     /// Get current memory usage tracked by this manager
@@ -57,11 +168,81 @@ impl MemoryManager {
     }
 
     /// Track memory allocation
+    ///
+    /// Fires any pressure callbacks whose threshold is now exceeded.
     pub fn track_usage(&self, bytes: usize) {
-        let mut inner = self.inner.write();
-        inner.current_usage += bytes;
-        if inner.current_usage > inner.peak_usage {
-            inner.peak_usage = inner.current_usage;
+        {
+            let mut inner = self.inner.write();
+            inner.current_usage += bytes;
+            if inner.current_usage > inner.peak_usage {
+                inner.peak_usage = inner.current_usage;
+            }
+        }
+
+        self.check_pressure();
+    }
+
+    /// Track memory allocation unless it would exceed the hard limit
+    ///
+    /// On failure nothing is recorded. Tracked bytes count against the limit
+    /// until they are returned with [`release_usage`](Self::release_usage).
+    pub fn try_track_usage(&self, bytes: usize) -> Result<()> {
+        {
+            let mut inner = self.inner.write();
+            let new_usage = inner.current_usage.saturating_add(bytes);
+            if let Some(limit) = inner.hard_limit {
+                if new_usage > limit {
+                    return Err(StreamingError::MemoryExceeded {
+                        requested: bytes,
+                        current: inner.current_usage,
+                        limit,
+                    });
+                }
+            }
+
+            inner.current_usage = new_usage;
+            if inner.current_usage > inner.peak_usage {
+                inner.peak_usage = inner.current_usage;
+            }
+        }
+
+        self.check_pressure();
+        Ok(())
+    }
+
+    /// Register a callback for when `memory_ratio()` rises above `threshold_ratio`
+    ///
+    /// The callback fires once per crossing; it is re-armed after the ratio
+    /// drops back to or below the threshold.
+    pub fn on_pressure(&self, threshold_ratio: f64, callback: PressureCallback) {
+        self.inner.write().pressure_hooks.push(PressureHook {
+            threshold: threshold_ratio,
+            callback: Arc::from(callback),
+            above: false,
+        });
+    }
+
+    fn check_pressure(&self) {
+        if self.inner.read().pressure_hooks.is_empty() {
+            return;
+        }
+
+        let ratio = self.memory_ratio();
+        let fired: Vec<_> = {
+            let mut inner = self.inner.write();
+            inner
+                .pressure_hooks
+                .iter_mut()
+                .filter_map(|hook| {
+                    let was_above = std::mem::replace(&mut hook.above, ratio > hook.threshold);
+                    (hook.above && !was_above).then(|| hook.callback.clone())
+                })
+                .collect()
+        };
+
+        // Run outside the lock so callbacks may use the manager
+        for callback in fired {
+            callback();
         }
     }
 
@@ -73,11 +254,9 @@ impl MemoryManager {
 
     /// Get memory ratio (used / total)
     pub fn memory_ratio(&self) -> f64 {
-        let mut inner = self.inner.write();
-        inner.system.refresh_memory();
-        let total = inner.system.total_memory() as f64;
-        let available = inner.system.available_memory() as f64;
-        (total - available) / total
+        let total = self.total_memory() as f64;
+        let available = self.available_memory() as f64;
+        ((total - available) / total).clamp(0.0, 1.0)
     }
 
     /// Check if we can safely allocate `bytes` more memory
@@ -125,4 +304,75 @@ mod tests {
         let ratio = manager.memory_ratio();
         assert!(ratio >= 0.0 && ratio <= 1.0);
     }
+
+    #[test]
+    fn test_cgroup_v2_limit_clamps_memory() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("memory.max"), "536870912\n").unwrap();
+        std::fs::write(root.path().join("memory.current"), "134217728\n").unwrap();
+
+        let manager = MemoryManager::new().unwrap().with_cgroup_root(root.path());
+        assert!(manager.in_container());
+        assert_eq!(manager.total_memory(), 512 * 1024 * 1024);
+        assert!(manager.available_memory() <= 384 * 1024 * 1024);
+        assert!(!manager.can_allocate(400 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_cgroup_v1_and_unlimited() {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir(root.path().join("memory")).unwrap();
+        std::fs::write(root.path().join("memory/memory.limit_in_bytes"), "268435456").unwrap();
+        std::fs::write(root.path().join("memory/memory.usage_in_bytes"), "0").unwrap();
+
+        let manager = MemoryManager::new().unwrap().with_cgroup_root(root.path());
+        assert_eq!(manager.total_memory(), 256 * 1024 * 1024);
+
+        let unlimited = tempfile::tempdir().unwrap();
+        std::fs::write(unlimited.path().join("memory.max"), "max\n").unwrap();
+        let manager = manager.with_cgroup_root(unlimited.path());
+        assert!(!manager.in_container());
+        assert!(manager.total_memory() > 256 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_pressure_callback_fires_once_per_crossing() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let manager = MemoryManager::new().unwrap();
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = fired.clone();
+        manager.on_pressure(0.0, Box::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+        manager.on_pressure(1.0, Box::new(|| panic!("ratio never exceeds 1.0")));
+
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+        manager.track_usage(1024);
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        // Still above the threshold, so no second call
+        manager.track_usage(1024);
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_hard_limit_rejects_without_tracking() {
+        let manager = MemoryManager::new().unwrap().with_hard_limit(1000);
+
+        manager.try_track_usage(800).unwrap();
+        assert_eq!(manager.current_usage(), 800);
+
+        match manager.try_track_usage(300) {
+            Err(StreamingError::MemoryExceeded { requested, current, limit }) => {
+                assert_eq!((requested, current, limit), (300, 800, 1000));
+            }
+            other => panic!("expected MemoryExceeded, got {:?}", other),
+        }
+        assert_eq!(manager.current_usage(), 800);
+        assert_eq!(manager.peak_usage(), 800);
+
+        manager.release_usage(800);
+        manager.try_track_usage(1000).unwrap();
+    }
 }
@@ -0,0 +1,105 @@
+//! Prometheus metrics for streaming reads
+//!
+//! Metrics are process-wide and updated by every `AdaptiveStreamingReader`:
+//!
+//! - `polars_streaming_batches_read_total`: batches yielded
+//! - `polars_streaming_bytes_read_total`: file bytes decoded (compressed size for parquet)
+//! - `polars_streaming_batch_decode_seconds`: time to read and decode one batch
+//! - `polars_streaming_rowgroups_skipped_total`: row groups pruned by predicate statistics
+//!
+//! Serve them by encoding [`metrics_registry`] with `prometheus::TextEncoder`.
+
+use prometheus::{Histogram, HistogramOpts, IntCounter, Registry};
+use std::sync::OnceLock;
+
+/// Collectors updated by the streaming readers
+pub(crate) struct StreamingMetrics {
+    pub(crate) batches_read: IntCounter,
+    pub(crate) bytes_read: IntCounter,
+    pub(crate) batch_decode_seconds: Histogram,
+    pub(crate) rowgroups_skipped: IntCounter,
+    registry: Registry,
+}
+
+impl StreamingMetrics {
+    fn new() -> Self {
+        let registry = Registry::new();
+
+        let batches_read =
+            IntCounter::new("polars_streaming_batches_read_total", "Batches read").unwrap();
+        registry.register(Box::new(batches_read.clone())).unwrap();
+
+        let bytes_read =
+            IntCounter::new("polars_streaming_bytes_read_total", "File bytes decoded").unwrap();
+        registry.register(Box::new(bytes_read.clone())).unwrap();
+
+        let batch_decode_seconds = Histogram::with_opts(HistogramOpts::new(
+            "polars_streaming_batch_decode_seconds",
+            "Time to read and decode one batch",
+        ))
+        .unwrap();
+        registry.register(Box::new(batch_decode_seconds.clone())).unwrap();
+
+        let rowgroups_skipped = IntCounter::new(
+            "polars_streaming_rowgroups_skipped_total",
+            "Row groups skipped based on statistics",
+        )
+        .unwrap();
+        registry.register(Box::new(rowgroups_skipped.clone())).unwrap();
+
+        Self {
+            batches_read,
+            bytes_read,
+            batch_decode_seconds,
+            rowgroups_skipped,
+            registry,
+        }
+    }
+}
+
+/// Process-wide metrics, created on first use
+pub(crate) fn metrics() -> &'static StreamingMetrics {
+    static METRICS: OnceLock<StreamingMetrics> = OnceLock::new();
+    METRICS.get_or_init(StreamingMetrics::new)
+}
+
+/// Registry holding the streaming metrics
+///
+/// The returned handle shares state with the readers, so it can be
+/// gathered at any time or merged into a service's own registry.
+pub fn metrics_registry() -> Registry {
+    metrics().registry.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adaptive_reader::AdaptiveStreamingReader;
+    use polars::prelude::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_batches_are_counted() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("metrics.parquet");
+        let mut df = DataFrame::new(vec![
+            Series::new("id".into(), (0..1000i32).collect::<Vec<_>>()).into(),
+        ])
+        .unwrap();
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(250))
+            .finish(&mut df)
+            .unwrap();
+
+        AdaptiveStreamingReader::new(&path).unwrap().collect().unwrap();
+
+        let families = metrics_registry().gather();
+        let batches = families
+            .iter()
+            .find(|family| family.get_name() == "polars_streaming_batches_read_total")
+            .unwrap();
+        assert!(batches.get_metric()[0].get_counter().get_value() > 0.0);
+        assert!(metrics().bytes_read.get() > 0);
+        assert!(metrics().batch_decode_seconds.get_sample_count() > 0);
+    }
+}
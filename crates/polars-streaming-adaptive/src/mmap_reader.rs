@@ -2,6 +2,8 @@
 
 use crate::error::{Result, StreamingError};
 use memmap2::Mmap;
+use polars::export::arrow::datatypes::ArrowSchemaRef;
+use polars::io::parquet::metadata::{deserialize, FileMetadataRef};
 use polars::prelude::*;
 use std::fs::File;
 use std::path::Path;
@@ -12,8 +14,16 @@ pub struct MmapParquetReader {
     path: std::path::PathBuf,
     mmap: Arc<Mmap>,
     schema: Arc<Schema>,
-    #[allow(dead_code)]
-    num_rows: Option<usize>,
+    arrow_schema: ArrowSchemaRef,
+    metadata: FileMetadataRef,
+    columns: Option<Vec<String>>,
+}
+
+/// Min/max statistics of a single column within one row group
+#[derive(Debug, Clone)]
+pub struct ColumnStatistics {
+    pub min: Option<AnyValue<'static>>,
+    pub max: Option<AnyValue<'static>>,
 }
 
 impl MmapParquetReader {
@@ -41,10 +51,14 @@ impl MmapParquetReader {
         let cursor = std::io::Cursor::new(mmap.as_ref());
         let mut parquet_reader = polars::prelude::ParquetReader::new(cursor);
         
-        // Get schema without reading data
+        // Get schema and row group metadata without reading data
         let arrow_schema = parquet_reader
             .schema()
             .map_err(|e| StreamingError::Compute(format!("Failed to read schema: {}", e)))?;
+        let metadata = parquet_reader
+            .get_metadata()
+            .map_err(|e| StreamingError::Compute(format!("Failed to read metadata: {}", e)))?
+            .clone();
 
         // Convert Arrow schema to Polars schema
         let polars_schema = Schema::from_iter(
@@ -60,37 +74,87 @@ impl MmapParquetReader {
             path: path_buf,
             mmap,
             schema: Arc::new(polars_schema),
-            num_rows: None,
+            arrow_schema,
+            metadata,
+            columns: None,
         })
     }
 
+    /// Restrict decoding to the given columns
+    ///
+    /// Returns `StreamingError::InvalidConfig` if a column is not in the file schema.
+    pub fn with_columns(mut self, columns: Vec<String>) -> Result<Self> {
+        if let Some(missing) = columns.iter().find(|c| self.schema.get(c.as_str()).is_none()) {
+            return Err(StreamingError::InvalidConfig(format!(
+                "Column '{}' not found in {}",
+                missing,
+                self.path.display()
+            )));
+        }
+
+        self.columns = Some(columns);
+        Ok(self)
+    }
+
+    /// Get the projected columns, if any
+    pub fn columns(&self) -> Option<&[String]> {
+        self.columns.as_deref()
+    }
+
     /// Get number of row groups in the file
     pub fn num_row_groups(&self) -> usize {
-        // Parse row group count from parquet metadata
-        // For now, estimate based on file size (actual implementation would read metadata)
-        let file_size = self.mmap.len();
-        let estimated_row_group_size = 64 * 1024 * 1024; // 64MB per row group typical
-        (file_size / estimated_row_group_size).max(1)
+        self.metadata.row_groups.len()
     }
 
     /// Get total rows across all row groups
     pub fn total_rows(&self) -> usize {
-        // Estimate based on file size and typical row density
-        // Actual implementation would read from Parquet metadata
-        let file_size_mb = self.mmap.len() / (1024 * 1024);
-        let rows_per_mb = 10_000; // Conservative estimate for OHLCV data
-        file_size_mb * rows_per_mb
+        self.metadata.num_rows
     }
 
-    /// Estimate average row size in bytes
+    /// Estimate average decoded row size in bytes
+    ///
+    /// Only the projected columns are counted when a projection is set.
     pub fn estimate_row_size(&self) -> usize {
-        let total_bytes = self.mmap.len();
-        let estimated_rows = self.total_rows();
-        if estimated_rows > 0 {
-            total_bytes / estimated_rows
-        } else {
-            100 // Default estimate
-        }
+        self.estimated_decompressed_size()
+            .checked_div(self.total_rows())
+            .map_or(100, |size| size.max(1)) // Default estimate for empty files
+    }
+
+    /// Estimate the in-memory size of the (projected) columns once decoded
+    ///
+    /// Starts from each column chunk's uncompressed size, so every column's
+    /// own compression ratio is already undone. Parquet's uncompressed size
+    /// still counts dictionary- and RLE-encoded pages, which expand further
+    /// when decoded. Fixed-width columns are sized by their Arrow width,
+    /// string and binary columns by their views plus data, and anything
+    /// else falls back to the uncompressed size.
+    pub fn estimated_decompressed_size(&self) -> usize {
+        let columns: Vec<(&str, &DataType)> = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .filter_map(|c| self.schema.get(c).map(|dtype| (c.as_str(), dtype)))
+                .collect(),
+            None => self.schema.iter().map(|(name, dtype)| (name.as_str(), dtype)).collect(),
+        };
+
+        self.metadata
+            .row_groups
+            .iter()
+            .map(|rg| {
+                columns
+                    .iter()
+                    .map(|(name, dtype)| {
+                        let uncompressed: usize = rg
+                            .columns_under_root_iter(name)
+                            .into_iter()
+                            .flatten()
+                            .map(|chunk| chunk.uncompressed_size() as usize)
+                            .sum();
+                        decoded_column_size(dtype, rg.num_rows(), uncompressed)
+                    })
+                    .sum::<usize>()
+            })
+            .sum()
     }
 
     /// Get number of rows in a specific row group
@@ -103,10 +167,60 @@ impl MmapParquetReader {
             )));
         }
 
-        // Estimate rows per row group
-        let total = self.total_rows();
-        let num_groups = self.num_row_groups();
-        Ok(total / num_groups)
+        Ok(self.metadata.row_groups[idx].num_rows())
+    }
+
+    /// Get the compressed on-disk size of a specific row group in bytes
+    pub fn row_group_byte_size(&self, idx: usize) -> Result<usize> {
+        if idx >= self.num_row_groups() {
+            return Err(StreamingError::InvalidConfig(format!(
+                "Row group index {} out of bounds (max: {})",
+                idx,
+                self.num_row_groups()
+            )));
+        }
+
+        Ok(self.metadata.row_groups[idx].compressed_size())
+    }
+
+    /// Get min/max statistics of `column` in a specific row group
+    ///
+    /// Returns `None` if the column does not exist or the writer did not
+    /// record statistics for it.
+    pub fn column_statistics(&self, idx: usize, column: &str) -> Result<Option<ColumnStatistics>> {
+        if idx >= self.num_row_groups() {
+            return Err(StreamingError::InvalidConfig(format!(
+                "Row group index {} out of bounds",
+                idx
+            )));
+        }
+
+        let Some(field) = self.arrow_schema.get(column) else {
+            return Ok(None);
+        };
+        let Some(columns) = self.metadata.row_groups[idx].columns_under_root_iter(column) else {
+            return Ok(None);
+        };
+        if columns.len() == 0 {
+            return Ok(None);
+        }
+
+        let stats = deserialize(field, columns)?;
+        let min = Series::try_from((PlSmallStr::EMPTY, stats.min_value))?;
+        let max = Series::try_from((PlSmallStr::EMPTY, stats.max_value))?;
+
+        let first_non_null = |s: &Series| -> Result<Option<AnyValue<'static>>> {
+            if s.is_empty() {
+                return Ok(None);
+            }
+            let value = s.get(0)?.into_static();
+            Ok((!value.is_null()).then_some(value))
+        };
+
+        Ok(Some(ColumnStatistics {
+            min: first_non_null(&min)?,
+            max: first_non_null(&max)?,
+        }))
     }
 
     /// Read a specific row group into a DataFrame
@@ -117,32 +231,70 @@ impl MmapParquetReader {
     /// # Returns
     /// DataFrame containing the row group data
     pub fn read_row_group(&self, idx: usize) -> Result<DataFrame> {
-        if idx >= self.num_row_groups() {
+        let len = self.row_group_num_rows(idx)?;
+        self.read_row_group_slice(idx, 0, len)
+    }
+
+    /// Read `len` rows of a specific row group, starting `offset` rows into it
+    ///
+    /// The slice is clamped to the end of the row group.
+    pub fn read_row_group_slice(&self, idx: usize, offset: usize, len: usize) -> Result<DataFrame> {
+        let num_rows = self.row_group_num_rows(idx)?;
+        if offset > num_rows {
             return Err(StreamingError::InvalidConfig(format!(
-                "Row group index {} out of bounds",
-                idx
+                "Row offset {} out of bounds for row group {} ({} rows)",
+                offset, idx, num_rows
             )));
         }
+        let len = len.min(num_rows - offset);
+
+        // Row offset of the slice within the file
+        let offset = offset
+            + self.metadata.row_groups[..idx]
+                .iter()
+                .map(|rg| rg.num_rows())
+                .sum::<usize>();
 
-        // Create a cursor over the memory-mapped region
+        // Create a cursor over the memory-mapped region; row groups outside
+        // the slice are never decoded
         let cursor = std::io::Cursor::new(self.mmap.as_ref());
-        
-        // Read the full file (in production, would read specific row group)
-        let parquet_reader = ParquetReader::new(cursor);
+        let mut parquet_reader = ParquetReader::new(cursor);
+        parquet_reader.set_metadata(self.metadata.clone());
+
         let df = parquet_reader
+            .with_columns(self.columns.clone())
+            .with_slice(Some((offset, len)))
             .finish()
-            .map_err(|e| StreamingError::Polars(e))?;
+            .map_err(StreamingError::Polars)?;
 
-        // For now, split into chunks (actual impl would use row group offsets)
-        let rows_per_group = self.row_group_num_rows(idx)?;
-        let start = idx * rows_per_group;
-        let end = ((idx + 1) * rows_per_group).min(df.height());
+        Ok(df)
+    }
 
-        if start >= df.height() {
-            return Ok(DataFrame::default());
+    /// Iterate over the row groups in order, one DataFrame each
+    ///
+    /// The iterator borrows the mapping, so several passes over the same
+    /// file (or a [`RowGroupIter::reset`]) never re-open or re-map it.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use polars_streaming_adaptive::MmapParquetReader;
+    ///
+    /// let reader = MmapParquetReader::new("data.parquet").unwrap();
+    /// let mut groups = reader.row_group_iter();
+    ///
+    /// // First pass: compute statistics
+    /// let rows: usize = groups.by_ref().map(|df| df.unwrap().height()).sum();
+    ///
+    /// // Second pass: filter using them
+    /// for df in groups.reset() {
+    ///     let df = df.unwrap();
+    /// }
+    /// ```
+    pub fn row_group_iter(&self) -> RowGroupIter<'_> {
+        RowGroupIter {
+            reader: self,
+            next: 0,
         }
-
-        Ok(df.slice(start as i64, end - start))
     }
 
     /// Check if the entire file can fit in available memory
@@ -169,6 +321,64 @@ impl MmapParquetReader {
     }
 }
 
+/// Decoded size of `rows` values of `dtype` stored in `uncompressed` bytes
+fn decoded_column_size(dtype: &DataType, rows: usize, uncompressed: usize) -> usize {
+    // Validity bitmaps add a bit per row
+    let validity = rows.div_ceil(8);
+    let width = match dtype.to_physical() {
+        DataType::Boolean => return validity * 2,
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 => 2,
+        DataType::Int32 | DataType::UInt32 | DataType::Float32 => 4,
+        DataType::Int64 | DataType::UInt64 | DataType::Float64 => 8,
+        // 16-byte views plus the string data itself
+        DataType::String | DataType::Binary => return rows * 16 + uncompressed + validity,
+        _ => return uncompressed,
+    };
+
+    rows * width + validity
+}
+
+/// Iterator over the row groups of a [`MmapParquetReader`]
+pub struct RowGroupIter<'a> {
+    reader: &'a MmapParquetReader,
+    next: usize,
+}
+
+impl RowGroupIter<'_> {
+    /// Rewind to the first row group
+    pub fn reset(&mut self) -> &mut Self {
+        self.next = 0;
+        self
+    }
+
+    /// Index of the row group the next call to `next` will decode
+    pub fn position(&self) -> usize {
+        self.next
+    }
+}
+
+impl Iterator for RowGroupIter<'_> {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next >= self.reader.num_row_groups() {
+            return None;
+        }
+
+        let result = self.reader.read_row_group(self.next);
+        self.next += 1;
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.reader.num_row_groups().saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for RowGroupIter<'_> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,4 +438,106 @@ mod tests {
 
         std::fs::remove_file(path).ok();
     }
+
+    #[test]
+    fn test_row_group_statistics() {
+        let df = DataFrame::new(vec![
+            Series::new("id".into(), (0..1000i32).collect::<Vec<_>>()).into(),
+        ])
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "test_mmap_stats_{}_{}.parquet",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(250))
+            .finish(&mut df.clone())
+            .unwrap();
+
+        let reader = MmapParquetReader::new(&path).unwrap();
+        assert_eq!(reader.num_row_groups(), 4);
+        assert_eq!(reader.total_rows(), 1000);
+        assert_eq!(reader.row_group_num_rows(1).unwrap(), 250);
+
+        let stats = reader.column_statistics(1, "id").unwrap().unwrap();
+        assert_eq!(stats.min, Some(AnyValue::Int32(250)));
+        assert_eq!(stats.max, Some(AnyValue::Int32(499)));
+        assert!(reader.column_statistics(1, "missing").unwrap().is_none());
+
+        let df = reader.read_row_group(2).unwrap();
+        assert_eq!(df.height(), 250);
+        assert_eq!(df.column("id").unwrap().i32().unwrap().get(0), Some(500));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_row_group_iter_reset() {
+        let df = DataFrame::new(vec![
+            Series::new("id".into(), (0..1000i32).collect::<Vec<_>>()).into(),
+        ])
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "test_mmap_iter_{}_{}.parquet",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(250))
+            .finish(&mut df.clone())
+            .unwrap();
+
+        let reader = MmapParquetReader::new(&path).unwrap();
+        let mut groups = reader.row_group_iter();
+        assert_eq!(groups.len(), 4);
+
+        let first_pass: usize = groups.by_ref().map(|df| df.unwrap().height()).sum();
+        assert_eq!(first_pass, 1000);
+        assert!(groups.next().is_none());
+
+        let second_pass: usize = groups.reset().map(|df| df.unwrap().height()).sum();
+        assert_eq!(second_pass, first_pass);
+        assert_eq!(groups.position(), 4);
+
+        std::fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_decompressed_estimate_exceeds_compressed_file() {
+        // Highly repetitive values compress to a tiny file
+        let rows = 100_000;
+        let mut df = DataFrame::new(vec![
+            Series::new("id".into(), (0..rows as i64).map(|i| i % 10).collect::<Vec<_>>()).into(),
+            Series::new("price".into(), vec![101.25f64; rows]).into(),
+            Series::new("venue".into(), vec!["XNAS"; rows]).into(),
+        ])
+        .unwrap();
+
+        let path = std::env::temp_dir().join(format!(
+            "test_mmap_zstd_{}_{}.parquet",
+            std::process::id(),
+            Uuid::new_v4()
+        ));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_compression(ParquetCompression::Zstd(None))
+            .finish(&mut df)
+            .unwrap();
+
+        let reader = MmapParquetReader::new(&path).unwrap();
+        let estimate = reader.estimated_decompressed_size();
+        assert!(estimate > reader.file_size());
+        // At least the fixed-width columns, 8 bytes per value each
+        assert!(estimate >= rows * 16);
+
+        let projected = MmapParquetReader::new(&path)
+            .unwrap()
+            .with_columns(vec!["price".to_string()])
+            .unwrap();
+        assert!(projected.estimated_decompressed_size() < estimate);
+
+        std::fs::remove_file(path).ok();
+    }
 }
@@ -0,0 +1,193 @@
+//! Memory-mapped NDJSON reader that yields batches of complete JSON lines
+
+use crate::error::{Result, StreamingError};
+use memmap2::Mmap;
+use polars::prelude::*;
+use std::fs::File;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Memory-mapped newline-delimited JSON reader
+///
+/// Records may have differing key sets. The reader keeps the union of all
+/// fields seen so far and every batch is returned with that schema, with
+/// missing fields filled with nulls.
+pub struct MmapNdjsonReader {
+    path: PathBuf,
+    mmap: Arc<Mmap>,
+    schema: Schema,
+    offset: usize,
+    lines_read: usize,
+}
+
+impl MmapNdjsonReader {
+    /// Create a new memory-mapped NDJSON reader
+    pub fn new(path: impl AsRef<Path>) -> Result<Self> {
+        let path_buf = path.as_ref().to_path_buf();
+        let file = File::open(&path_buf)?;
+
+        // Safety: We trust that the file won't be modified while mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Ok(Self {
+            path: path_buf,
+            mmap: Arc::new(mmap),
+            schema: Schema::default(),
+            offset: 0,
+            lines_read: 0,
+        })
+    }
+
+    /// Read the next batch of up to `n_lines` JSON lines
+    ///
+    /// Returns `None` once the whole file has been consumed.
+    pub fn read_batch(&mut self, n_lines: usize) -> Result<Option<DataFrame>> {
+        if self.is_exhausted() {
+            return Ok(None);
+        }
+
+        let start = self.offset;
+        let end = self.find_batch_end(start, n_lines.max(1));
+        let chunk = &self.mmap[start..end];
+        let n_read = chunk.iter().filter(|&&b| b == b'\n').count();
+
+        let df = JsonLineReader::new(Cursor::new(chunk))
+            .infer_schema_len(None)
+            .finish()
+            .map_err(|e| self.locate_error(chunk, e))?;
+
+        self.merge_schema(&df.schema())?;
+        let df = self.align(&df)?;
+
+        self.lines_read += n_read;
+        self.offset = end;
+
+        Ok(Some(df))
+    }
+
+    /// Bring a batch to the current unified schema
+    ///
+    /// Fields missing from `df` are added as null columns and existing
+    /// columns are cast to the unified dtype.
+    pub fn align(&self, df: &DataFrame) -> Result<DataFrame> {
+        let height = df.height();
+        let columns = self
+            .schema
+            .iter()
+            .map(|(name, dtype)| match df.column(name) {
+                Ok(column) if column.dtype() == dtype => Ok(column.clone()),
+                Ok(column) => Ok(column.cast(dtype)?),
+                Err(_) => Ok(Series::full_null(name.clone(), height, dtype).into()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DataFrame::new(columns)?)
+    }
+
+    fn merge_schema(&mut self, batch: &Schema) -> Result<()> {
+        for (name, dtype) in batch.iter() {
+            match self.schema.get(name) {
+                None => {
+                    self.schema.with_column(name.clone(), dtype.clone());
+                }
+                Some(current) if current != dtype => {
+                    let mut merged = Schema::from_iter([(name.clone(), current.clone())]);
+                    merged.to_supertype(&Schema::from_iter([(name.clone(), dtype.clone())]))?;
+                    let supertype = merged.get(name).cloned().unwrap_or_else(|| dtype.clone());
+                    self.schema.with_column(name.clone(), supertype);
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Find the line responsible for a failed batch parse
+    fn locate_error(&self, chunk: &[u8], batch_error: PolarsError) -> StreamingError {
+        for (idx, line) in chunk.split(|&b| b == b'\n').enumerate() {
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+
+            if let Err(e) = JsonLineReader::new(Cursor::new(line)).finish() {
+                return StreamingError::Compute(format!(
+                    "Malformed JSON on line {} of {}: {}",
+                    self.lines_read + idx + 1,
+                    self.path.display(),
+                    e
+                ));
+            }
+        }
+
+        StreamingError::Compute(format!(
+            "Failed to parse JSON lines {}.. of {}: {}",
+            self.lines_read + 1,
+            self.path.display(),
+            batch_error
+        ))
+    }
+
+    /// Find the byte offset just past the `n_lines`-th newline after `start`
+    fn find_batch_end(&self, start: usize, n_lines: usize) -> usize {
+        self.mmap[start..]
+            .iter()
+            .enumerate()
+            .filter(|(_, &b)| b == b'\n')
+            .nth(n_lines - 1)
+            .map(|(i, _)| start + i + 1)
+            .unwrap_or(self.mmap.len())
+    }
+
+    /// Check whether all bytes have been consumed
+    pub fn is_exhausted(&self) -> bool {
+        self.mmap[self.offset..]
+            .iter()
+            .all(|b| b.is_ascii_whitespace())
+    }
+
+    /// Get the union of all fields seen so far
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Get number of bytes consumed so far
+    pub fn bytes_read(&self) -> usize {
+        self.offset
+    }
+
+    /// Get file path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get file size in bytes
+    pub fn file_size(&self) -> usize {
+        self.mmap.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_malformed_line_reports_line_number() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"id": 1}}"#).unwrap();
+        writeln!(file, r#"{{"id": 2}}"#).unwrap();
+        writeln!(file, r#"{{"id": 3}}"#).unwrap();
+        writeln!(file, r#"{{"id": 4"#).unwrap();
+        file.flush().unwrap();
+
+        let mut reader = MmapNdjsonReader::new(file.path()).unwrap();
+        assert_eq!(reader.read_batch(2).unwrap().unwrap().height(), 2);
+
+        match reader.read_batch(2) {
+            Err(StreamingError::Compute(msg)) => assert!(msg.contains("line 4"), "{}", msg),
+            other => panic!("expected compute error, got {:?}", other.map(|_| ())),
+        }
+    }
+}
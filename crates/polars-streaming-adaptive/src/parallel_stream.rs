@@ -1,21 +1,43 @@
 //! Parallel streaming for multiple files
 
-use crate::adaptive_reader::AdaptiveStreamingReader;
-use crate::error::Result;
+use crate::adaptive_reader::{AdaptiveBatchIterator, AdaptiveStreamingReader};
+use crate::error::{Result, StreamingError};
+use crate::mmap_reader::MmapParquetReader;
+use crate::schema::schema_difference;
+use crate::stats::StreamingStats;
 use crossbeam_channel::{bounded, Receiver, Sender};
 use polars::prelude::*;
 use rayon::prelude::*;
-use std::path::PathBuf;
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// How `ParallelStreamReader` handles files that fail to read
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorMode {
+    /// Yield the error, which aborts `collect_concatenated`
+    #[default]
+    FailFast,
+    /// Log and omit unreadable files, optionally recording their errors
+    SkipBad { collect_errors: bool },
+}
 
 /// Parallel streaming reader for multiple Parquet files
 pub struct ParallelStreamReader {
     paths: Vec<PathBuf>,
     max_concurrent: usize,
     buffer_size: usize,
+    error_mode: ErrorMode,
+    errors: Arc<Mutex<Vec<(PathBuf, String)>>>,
+    stats: Arc<Mutex<Vec<StreamingStats>>>,
+    sorted_paths: bool,
+    cancel: Option<Arc<AtomicBool>>,
+    schema_check: bool,
 }
 
+/// Batch tagged with the index of the file it was read from
+type IndexedBatch = (usize, Result<DataFrame>);
+
 impl ParallelStreamReader {
     /// Create a new parallel stream reader
     pub fn new(paths: Vec<PathBuf>) -> Self {
@@ -24,6 +46,12 @@ impl ParallelStreamReader {
             paths,
             max_concurrent,
             buffer_size: max_concurrent * 2,
+            error_mode: ErrorMode::default(),
+            errors: Arc::new(Mutex::new(Vec::new())),
+            stats: Arc::new(Mutex::new(Vec::new())),
+            sorted_paths: false,
+            cancel: None,
+            schema_check: true,
         }
     }
 
@@ -39,29 +67,181 @@ impl ParallelStreamReader {
         self
     }
 
+    /// Set how unreadable files are handled
+    ///
+    /// In `SkipBad` mode a file that fails part way through is dropped from
+    /// that point on; batches already yielded from it are kept.
+    pub fn with_error_mode(mut self, mode: ErrorMode) -> Self {
+        self.error_mode = mode;
+        self
+    }
+
+    /// Read files in lexicographic path order
+    ///
+    /// Files are still read in parallel, but `collect_concatenated` emits
+    /// their rows in sorted path order so output is reproducible across
+    /// machines.
+    pub fn with_sorted_paths(mut self, sorted: bool) -> Self {
+        self.sorted_paths = sorted;
+        self
+    }
+
+    /// Stop reading once `flag` is set
+    ///
+    /// Workers check the flag before each file and each batch, so a read
+    /// stops within one batch per worker. A cancelled `collect_parallel`
+    /// yields a final `StreamingError::Cancelled` and then ends;
+    /// `collect_concatenated` returns that error instead of a DataFrame.
+    pub fn with_cancel(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancel = Some(flag);
+        self
+    }
+
+    /// Check that all files share the first file's schema before reading (default: on)
+    ///
+    /// Only Parquet footers are read. A mismatch makes `collect_parallel`
+    /// yield a single `StreamingError::SchemaMismatch` naming the file and
+    /// column, and `collect_concatenated` return it. Files that cannot be
+    /// opened are left to the error mode.
+    pub fn with_schema_check(mut self, enabled: bool) -> Self {
+        self.schema_check = enabled;
+        self
+    }
+
+    /// Compare every file's schema with the first readable one
+    pub fn validate_schemas(&self) -> Result<()> {
+        let mut reference: Option<(PathBuf, Arc<Schema>)> = None;
+
+        for path in &self.paths {
+            let schema = match MmapParquetReader::new(path) {
+                Ok(reader) => reader.schema().clone(),
+                Err(e) => {
+                    tracing::debug!("Schema check skipping unreadable file {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+
+            match &reference {
+                Some((first, expected)) => {
+                    if let Some(detail) = schema_difference(expected, &schema) {
+                        return Err(StreamingError::SchemaMismatch {
+                            path: path.clone(),
+                            detail: format!("{} (compared to {})", detail, first.display()),
+                        });
+                    }
+                }
+                None => reference = Some((path.clone(), schema)),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_cancelled(cancel: &Option<Arc<AtomicBool>>) -> bool {
+        cancel
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    /// Per-file failures recorded in `SkipBad { collect_errors: true }` mode
+    ///
+    /// Complete once the iterator from `collect_parallel` has been drained.
+    pub fn errors(&self) -> Vec<(PathBuf, String)> {
+        self.errors.lock().unwrap().clone()
+    }
+
+    /// Combined statistics of all files read by the last collection
+    ///
+    /// Complete once the iterator from `collect_parallel` has been drained.
+    pub fn aggregated_stats(&self) -> StreamingStats {
+        StreamingStats::aggregate(self.stats.lock().unwrap().iter())
+    }
+
     /// Stream all files in parallel with backpressure
     ///
     /// Returns an iterator that yields DataFrames from all files
-    pub fn collect_parallel(self) -> impl Iterator<Item = Result<DataFrame>> {
-        let (tx, rx): (Sender<Result<DataFrame>>, Receiver<_>) = bounded(self.buffer_size);
+    pub fn collect_parallel(&self) -> impl Iterator<Item = Result<DataFrame>> {
+        let cancel = self.cancel.clone();
+        let (mut batches, mut schema_error) = match self.check_schemas() {
+            Ok(()) => (Some(self.spawn_readers().into_iter()), None),
+            Err(e) => (None, Some(e)),
+        };
+
+        std::iter::from_fn(move || {
+            if let Some(e) = schema_error.take() {
+                return Some(Err(e));
+            }
+
+            let next = batches.as_mut()?.next();
+            if Self::is_cancelled(&cancel) {
+                // Dropping the receiver unblocks workers waiting to send
+                batches = None;
+                return Some(Err(StreamingError::Cancelled));
+            }
+
+            match next {
+                Some((_, batch)) => Some(batch),
+                None => {
+                    batches = None;
+                    None
+                }
+            }
+        })
+    }
+
+    fn check_schemas(&self) -> Result<()> {
+        if self.schema_check {
+            self.validate_schemas()
+        } else {
+            Ok(())
+        }
+    }
+
+    fn spawn_readers(&self) -> Receiver<IndexedBatch> {
+        let (tx, rx): (Sender<IndexedBatch>, Receiver<_>) = bounded(self.buffer_size);
 
-        let paths = self.paths.clone();
+        let mut paths = self.paths.clone();
+        if self.sorted_paths {
+            paths.sort();
+        }
         let max_concurrent = self.max_concurrent;
+        let error_mode = self.error_mode;
+        let errors = self.errors.clone();
+        errors.lock().unwrap().clear();
+        let stats = self.stats.clone();
+        stats.lock().unwrap().clear();
+        let cancel = self.cancel.clone();
 
         // Spawn parallel readers in background
         rayon::spawn(move || {
-            Self::parallel_read_worker(paths, tx, max_concurrent);
+            Self::parallel_read_worker(paths, tx, max_concurrent, error_mode, errors, stats, cancel);
         });
 
-        rx.into_iter()
+        rx
     }
 
     /// Collect all files and concatenate into a single DataFrame
-    pub fn collect_concatenated(self) -> Result<DataFrame> {
-        let batches: Vec<DataFrame> = self.collect_parallel().collect::<Result<Vec<_>>>()?;
+    pub fn collect_concatenated(&self) -> Result<DataFrame> {
+        self.check_schemas()?;
+
+        let mut indexed = self
+            .spawn_readers()
+            .into_iter()
+            .map(|(idx, batch)| batch.map(|df| (idx, df)))
+            .collect::<Result<Vec<_>>>()?;
+
+        if Self::is_cancelled(&self.cancel) {
+            return Err(StreamingError::Cancelled);
+        }
+
+        // Batches of one file arrive in order, so a stable sort keeps them so
+        if self.sorted_paths {
+            indexed.sort_by_key(|(idx, _)| *idx);
+        }
+        let batches: Vec<DataFrame> = indexed.into_iter().map(|(_, df)| df).collect();
 
         if batches.is_empty() {
-            return Err(crate::error::StreamingError::NoData);
+            return Err(StreamingError::NoData);
         }
 
         // Concatenate all batches vertically
@@ -73,7 +253,15 @@ impl ParallelStreamReader {
     }
 
     /// Worker function for parallel file reading
-    fn parallel_read_worker(paths: Vec<PathBuf>, tx: Sender<Result<DataFrame>>, max_concurrent: usize) {
+    fn parallel_read_worker(
+        paths: Vec<PathBuf>,
+        tx: Sender<IndexedBatch>,
+        max_concurrent: usize,
+        error_mode: ErrorMode,
+        errors: Arc<Mutex<Vec<(PathBuf, String)>>>,
+        stats: Arc<Mutex<Vec<StreamingStats>>>,
+        cancel: Option<Arc<AtomicBool>>,
+    ) {
         let files_processed = Arc::new(AtomicUsize::new(0));
         let total_files = paths.len();
 
@@ -84,27 +272,59 @@ impl ParallelStreamReader {
         );
 
         // Use Rayon's parallel iterator with work stealing
-        paths.par_iter().for_each_with(
+        paths.par_iter().enumerate().for_each_with(
             (tx.clone(), files_processed.clone()),
-            |(tx, counter), path| {
+            |(tx, counter), (idx, path)| {
+                let report = |e: StreamingError| match error_mode {
+                    ErrorMode::FailFast => {
+                        let _ = tx.send((idx, Err(e)));
+                    }
+                    ErrorMode::SkipBad { collect_errors } => {
+                        tracing::warn!("Skipping unreadable file {}: {}", path.display(), e);
+                        if collect_errors {
+                            errors.lock().unwrap().push((path.clone(), e.to_string()));
+                        }
+                    }
+                };
+
+                if Self::is_cancelled(&cancel) {
+                    return;
+                }
+
                 // Create reader for this file
                 let reader = match AdaptiveStreamingReader::new(path) {
                     Ok(r) => r,
                     Err(e) => {
-                        let _ = tx.send(Err(e));
+                        report(e);
                         return;
                     }
                 };
 
                 // Stream batches from this file
-                for batch in reader.collect_batches_adaptive() {
-                    if tx.send(batch).is_err() {
+                let mut batches = reader.collect_batches_adaptive();
+                for batch in batches.by_ref() {
+                    if Self::is_cancelled(&cancel) {
+                        tracing::debug!("Read cancelled, stopping {}", path.display());
+                        break;
+                    }
+
+                    let sent = match batch {
+                        Ok(df) => tx.send((idx, Ok(df))).is_ok(),
+                        Err(e) => {
+                            report(e);
+                            break;
+                        }
+                    };
+
+                    if !sent {
                         // Receiver dropped - stop processing
                         tracing::warn!("Receiver dropped, stopping file processing");
                         break;
                     }
                 }
 
+                stats.lock().unwrap().push(batches.stats());
+
                 // Update progress
                 let processed = counter.fetch_add(1, Ordering::Relaxed) + 1;
                 tracing::debug!("Completed file {}/{}: {}", processed, total_files, path.display());
@@ -114,25 +334,225 @@ impl ParallelStreamReader {
         tracing::info!("Parallel read completed: {} files", total_files);
     }
 
+    /// Merge files that are each sorted by `column` into one sorted stream
+    ///
+    /// Performs a k-way merge holding one batch per file: every step emits
+    /// all buffered rows up to the smallest last value among the files'
+    /// current batches, so the concatenated output is sorted ascending by
+    /// `column`. Rows with equal keys keep file order.
+    ///
+    /// Files are read on the calling thread. Each batch is checked to be
+    /// sorted, null-free and to continue where the previous batch of its
+    /// file left off; a violation yields `StreamingError::Compute` and ends
+    /// the stream. Read errors also end the stream regardless of the error
+    /// mode. The cancel flag is honored between batches.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use polars_streaming_adaptive::ParallelStreamReader;
+    ///
+    /// let reader = ParallelStreamReader::new(vec![
+    ///     "venue_a.parquet".into(),
+    ///     "venue_b.parquet".into(),
+    /// ]);
+    /// for batch in reader.merge_sorted_by("timestamp") {
+    ///     let df = batch.unwrap();
+    ///     println!("Merged batch: {} rows", df.height());
+    /// }
+    /// ```
+    pub fn merge_sorted_by(&self, column: &str) -> impl Iterator<Item = Result<DataFrame>> {
+        SortedMerge {
+            column: column.to_string(),
+            pending: self.paths.clone(),
+            sources: Vec::new(),
+            cancel: self.cancel.clone(),
+            done: false,
+        }
+    }
+
     /// Get number of files to be processed
     pub fn num_files(&self) -> usize {
         self.paths.len()
     }
 }
 
+/// One input of a `SortedMerge`
+struct MergeSource {
+    path: PathBuf,
+    batches: AdaptiveBatchIterator,
+    /// Rows read but not yet emitted
+    current: Option<DataFrame>,
+    /// Last key of the previous batch, to validate ordering across batches
+    last_key: Option<Series>,
+}
+
+/// K-way merge of per-file sorted streams, see `merge_sorted_by`
+struct SortedMerge {
+    column: String,
+    /// Files not opened yet
+    pending: Vec<PathBuf>,
+    sources: Vec<MergeSource>,
+    cancel: Option<Arc<AtomicBool>>,
+    done: bool,
+}
+
+impl SortedMerge {
+    fn open_pending(&mut self) -> Result<()> {
+        for path in self.pending.drain(..) {
+            let batches = AdaptiveStreamingReader::new(&path)?.collect_batches_adaptive();
+            self.sources.push(MergeSource {
+                path,
+                batches,
+                current: None,
+                last_key: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Read the next non-empty batch of every source that has run dry
+    fn refill(&mut self) -> Result<()> {
+        let column = &self.column;
+        for source in &mut self.sources {
+            while source.current.is_none() {
+                let Some(batch) = source.batches.next() else {
+                    break;
+                };
+                let df = batch?;
+                if df.height() == 0 {
+                    continue;
+                }
+
+                let keys = df.column(column)?.as_materialized_series().clone();
+                Self::validate(&source.path, column, &keys, source.last_key.as_ref())?;
+                source.last_key = Some(keys.tail(Some(1)));
+                source.current = Some(df);
+            }
+        }
+
+        // Exhausted sources no longer hold back the watermark
+        self.sources.retain(|source| source.current.is_some());
+        Ok(())
+    }
+
+    fn validate(path: &Path, column: &str, keys: &Series, previous: Option<&Series>) -> Result<()> {
+        let unsorted = |reason: &str| {
+            StreamingError::Compute(format!(
+                "{} is not sorted by '{}': {}",
+                path.display(),
+                column,
+                reason
+            ))
+        };
+
+        if keys.null_count() > 0 {
+            return Err(unsorted("contains nulls"));
+        }
+        let n = keys.len();
+        if n > 1 && keys.slice(1, n - 1).lt(&keys.slice(0, n - 1))?.any() {
+            return Err(unsorted("batch is out of order"));
+        }
+        if let Some(previous) = previous {
+            if keys.head(Some(1)).lt(previous)?.any() {
+                return Err(unsorted("batch starts before the end of the previous one"));
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_step(&mut self) -> Result<Option<DataFrame>> {
+        self.open_pending()?;
+        self.refill()?;
+        if self.sources.is_empty() {
+            return Ok(None);
+        }
+
+        // Every unread row of a source is >= its buffered last key, so rows
+        // up to the smallest buffered last key can be emitted
+        let column = &self.column;
+        let mut last_keys: Option<Series> = None;
+        for source in &self.sources {
+            let keys = source.current.as_ref().unwrap().column(column)?;
+            let last = keys.as_materialized_series().tail(Some(1));
+            match last_keys.as_mut() {
+                Some(all) => {
+                    all.append(&last)?;
+                }
+                None => last_keys = Some(last),
+            }
+        }
+        let watermark = last_keys
+            .unwrap()
+            .sort(SortOptions::default())?
+            .head(Some(1));
+
+        let mut merged: Option<DataFrame> = None;
+        for source in &mut self.sources {
+            let df = source.current.take().unwrap();
+            let keys = df.column(column)?.as_materialized_series();
+            let take = keys.lt_eq(&watermark)?.sum().unwrap_or(0) as usize;
+
+            let (head, rest) = df.split_at(take as i64);
+            if rest.height() > 0 {
+                source.current = Some(rest);
+            }
+            match merged.as_mut() {
+                Some(merged) => {
+                    merged.vstack_mut(&head)?;
+                }
+                None => merged = Some(head),
+            }
+        }
+
+        let merged = merged.unwrap().sort(
+            [column.as_str()],
+            SortMultipleOptions::default().with_maintain_order(true),
+        )?;
+        Ok(Some(merged))
+    }
+}
+
+impl Iterator for SortedMerge {
+    type Item = Result<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if ParallelStreamReader::is_cancelled(&self.cancel) {
+            self.done = true;
+            return Some(Err(StreamingError::Cancelled));
+        }
+
+        match self.merge_step() {
+            Ok(Some(df)) => Some(Ok(df)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
 /// Helper to create ParallelStreamReader from glob pattern
+///
+/// Chain `with_sorted_paths(true)` for deterministic output ordering.
 pub fn from_glob(pattern: &str) -> Result<ParallelStreamReader> {
     use glob::glob;
 
     let paths: Vec<PathBuf> = glob(pattern)
         .map_err(|e| {
-            crate::error::StreamingError::InvalidConfig(format!("Invalid glob pattern: {}", e))
+            StreamingError::InvalidConfig(format!("Invalid glob pattern: {}", e))
         })?
         .filter_map(|entry: std::result::Result<PathBuf, glob::GlobError>| entry.ok())
         .collect();
 
     if paths.is_empty() {
-        return Err(crate::error::StreamingError::NoData);
+        return Err(StreamingError::NoData);
     }
 
     Ok(ParallelStreamReader::new(paths))
@@ -207,4 +627,220 @@ mod tests {
         let df = reader.collect_concatenated().unwrap();
         assert_eq!(df.height(), 10 * 50);
     }
+
+    #[test]
+    fn test_skip_bad_files() {
+        let (_temp, paths) = create_test_files(4, 100);
+
+        // Truncate one file so its footer is missing
+        let bytes = std::fs::read(&paths[2]).unwrap();
+        std::fs::write(&paths[2], &bytes[..bytes.len() / 2]).unwrap();
+
+        let reader = ParallelStreamReader::new(paths.clone())
+            .with_error_mode(ErrorMode::SkipBad { collect_errors: true });
+
+        let df = reader.collect_concatenated().unwrap();
+        assert_eq!(df.height(), 3 * 100);
+
+        let errors = reader.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, paths[2]);
+
+        let fail_fast = ParallelStreamReader::new(paths);
+        assert!(fail_fast.collect_concatenated().is_err());
+    }
+
+    #[test]
+    fn test_sorted_paths_order() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // Write files out of lexicographic order, each tagged by its name
+        for name in ["c", "a", "b"] {
+            let df = DataFrame::new(vec![
+                Series::new("name".into(), vec![name; 50]).into(),
+                Series::new("row_id".into(), (0..50).collect::<Vec<i32>>()).into(),
+            ])
+            .unwrap();
+
+            let path = temp_dir.path().join(format!("{}.parquet", name));
+            ParquetWriter::new(std::fs::File::create(&path).unwrap())
+                .finish(&mut df.clone())
+                .unwrap();
+        }
+
+        let pattern = temp_dir.path().join("*.parquet");
+        let df = from_glob(pattern.to_str().unwrap())
+            .unwrap()
+            .with_sorted_paths(true)
+            .collect_concatenated()
+            .unwrap();
+
+        let names: Vec<&str> = df
+            .column("name")
+            .unwrap()
+            .str()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        let expected: Vec<&str> = ["a", "b", "c"]
+            .iter()
+            .flat_map(|name| std::iter::repeat_n(*name, 50))
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn test_aggregated_stats() {
+        let (_temp, paths) = create_test_files(3, 120);
+        let reader = ParallelStreamReader::new(paths);
+
+        let total_rows: usize = reader
+            .collect_parallel()
+            .map(|batch| batch.unwrap().height())
+            .sum();
+
+        let stats = reader.aggregated_stats();
+        assert_eq!(stats.records_processed, total_rows);
+        assert_eq!(stats.records_processed, 3 * 120);
+        assert!(stats.chunks_read >= 3);
+        assert!(stats.bytes_read > 0);
+    }
+
+    #[test]
+    fn test_cancel_stops_read_early() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut paths = Vec::new();
+        for i in 0..8 {
+            let df = DataFrame::new(vec![
+                Series::new("row_id".into(), (0..2000).collect::<Vec<i32>>()).into(),
+            ])
+            .unwrap();
+            let path = temp_dir.path().join(format!("file_{}.parquet", i));
+            ParquetWriter::new(std::fs::File::create(&path).unwrap())
+                .with_row_group_size(Some(100))
+                .finish(&mut df.clone())
+                .unwrap();
+            paths.push(path);
+        }
+
+        let cancel = Arc::new(AtomicBool::new(false));
+        let reader = ParallelStreamReader::new(paths)
+            .with_buffer_size(1)
+            .with_cancel(cancel.clone());
+
+        let mut rows = 0;
+        let mut cancelled = false;
+        for batch in reader.collect_parallel() {
+            match batch {
+                Ok(df) => {
+                    rows += df.height();
+                    cancel.store(true, Ordering::Relaxed);
+                }
+                Err(StreamingError::Cancelled) => cancelled = true,
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+
+        assert!(cancelled);
+        // Only the first batch gets through out of 8 * 2000 rows
+        assert_eq!(rows, 100);
+        assert!(matches!(
+            reader.collect_concatenated(),
+            Err(StreamingError::Cancelled)
+        ));
+    }
+
+    fn write_sorted_file(dir: &TempDir, name: &str, ts: Vec<i64>) -> PathBuf {
+        let venue = vec![name; ts.len()];
+        let df = DataFrame::new(vec![
+            Series::new("ts".into(), ts).into(),
+            Series::new("venue".into(), venue).into(),
+        ])
+        .unwrap();
+
+        let path = dir.path().join(format!("{}.parquet", name));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(25))
+            .finish(&mut df.clone())
+            .unwrap();
+        path
+    }
+
+    #[test]
+    fn test_merge_sorted_by_interleaves_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = vec![
+            write_sorted_file(&temp_dir, "a", (0..100).map(|i| i * 2).collect()),
+            // Denser at the start so batch boundaries don't line up
+            write_sorted_file(&temp_dir, "b", (0..100).map(|i| i * i / 25 + 1).collect()),
+        ];
+
+        let batches = ParallelStreamReader::new(paths)
+            .merge_sorted_by("ts")
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert!(batches.len() > 1);
+
+        let ts: Vec<i64> = batches
+            .iter()
+            .flat_map(|df| df.column("ts").unwrap().i64().unwrap().into_no_null_iter().collect::<Vec<_>>())
+            .collect();
+        assert_eq!(ts.len(), 200);
+        assert!(ts.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_merge_sorted_by_rejects_unsorted_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let paths = vec![
+            write_sorted_file(&temp_dir, "sorted", (0..50).collect()),
+            write_sorted_file(&temp_dir, "unsorted", (0..50).rev().collect()),
+        ];
+
+        let result = ParallelStreamReader::new(paths)
+            .merge_sorted_by("ts")
+            .collect::<Result<Vec<_>>>();
+        assert!(matches!(
+            result,
+            Err(StreamingError::Compute(msg)) if msg.contains("is not sorted") && msg.contains("out of order")
+        ));
+    }
+
+    #[test]
+    fn test_schema_mismatch_names_file_and_column() {
+        let (temp_dir, mut paths) = create_test_files(3, 100);
+
+        // Same columns, but `value` as integers
+        let df = DataFrame::new(vec![
+            Series::new("file_id".into(), vec![3i32; 100]).into(),
+            Series::new("row_id".into(), (0..100).collect::<Vec<i32>>()).into(),
+            Series::new("value".into(), (0..100).collect::<Vec<i64>>()).into(),
+        ])
+        .unwrap();
+        let bad = temp_dir.path().join("file_3.parquet");
+        ParquetWriter::new(std::fs::File::create(&bad).unwrap())
+            .finish(&mut df.clone())
+            .unwrap();
+        paths.push(bad.clone());
+
+        let reader = ParallelStreamReader::new(paths.clone());
+        match reader.collect_concatenated() {
+            Err(StreamingError::SchemaMismatch { path, detail }) => {
+                assert_eq!(path, bad);
+                assert!(detail.contains("'value'"), "{}", detail);
+                assert!(detail.contains("i64"), "{}", detail);
+            }
+            other => panic!("expected schema mismatch, got {:?}", other.map(|df| df.shape())),
+        }
+
+        let mut batches = reader.collect_parallel();
+        assert!(matches!(batches.next(), Some(Err(StreamingError::SchemaMismatch { .. }))));
+        assert!(batches.next().is_none());
+
+        let unchecked = ParallelStreamReader::new(paths).with_schema_check(false);
+        assert!(!matches!(
+            unchecked.collect_concatenated(),
+            Err(StreamingError::SchemaMismatch { .. })
+        ));
+    }
 }
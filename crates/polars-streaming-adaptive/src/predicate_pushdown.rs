@@ -1,13 +1,25 @@
 //! Predicate pushdown optimization for efficient filtering
 
-use crate::error::Result;
+use crate::error::{Result, StreamingError};
+use crate::mmap_reader::MmapParquetReader;
 use polars::prelude::*;
-use std::ops::BitAnd;
+use std::cmp::Ordering;
+use std::ops::{BitAnd, Not};
 
 /// Predicate that can be pushed down to file reading
 pub trait PredicatePushdown: Send + Sync {
     /// Apply predicate to a DataFrame
     fn apply(&self, df: &DataFrame) -> Result<BooleanChunked>;
+
+    /// Check whether a row group may contain matching rows, based on its
+    /// column statistics
+    ///
+    /// Returning `false` lets the reader skip the row group without decoding it.
+    /// The default keeps every row group, so predicates without statistics
+    /// support are only applied after the batch is materialized.
+    fn may_match_row_group(&self, _reader: &MmapParquetReader, _row_group: usize) -> Result<bool> {
+        Ok(true)
+    }
 }
 
 /// Filter by column value
@@ -64,6 +76,193 @@ impl PredicatePushdown for ColumnFilterPredicate {
 
         Ok(mask)
     }
+
+    fn may_match_row_group(&self, reader: &MmapParquetReader, row_group: usize) -> Result<bool> {
+        let Some(stats) = reader.column_statistics(row_group, &self.column)? else {
+            return Ok(true);
+        };
+        let (Some(min), Some(max)) = (stats.min, stats.max) else {
+            return Ok(true);
+        };
+
+        // Unknown orderings (e.g. incomparable dtypes) must keep the row group
+        let holds = |lhs: &AnyValue, allowed: &[Ordering]| {
+            compare_values(lhs, &self.value).is_none_or(|ord| allowed.contains(&ord))
+        };
+
+        let may_match = match &self.op {
+            FilterOp::Eq => {
+                holds(&min, &[Ordering::Less, Ordering::Equal])
+                    && holds(&max, &[Ordering::Greater, Ordering::Equal])
+            }
+            FilterOp::Neq => true,
+            FilterOp::Lt => holds(&min, &[Ordering::Less]),
+            FilterOp::Le => holds(&min, &[Ordering::Less, Ordering::Equal]),
+            FilterOp::Gt => holds(&max, &[Ordering::Greater]),
+            FilterOp::Ge => holds(&max, &[Ordering::Greater, Ordering::Equal]),
+        };
+
+        Ok(may_match)
+    }
+}
+
+/// Compare two scalars, casting numeric values so that e.g. an `Int32` literal
+/// can be checked against `Int64` statistics
+///
+/// Integers are widened to `i128` so large `Int64`/`UInt64` values keep
+/// their exact order; `f64` is only used when one side is a float.
+fn compare_values(lhs: &AnyValue, rhs: &AnyValue) -> Option<Ordering> {
+    if lhs.is_numeric() && rhs.is_numeric() {
+        if !lhs.is_float() && !rhs.is_float() {
+            let lhs = lhs.extract::<i128>()?;
+            let rhs = rhs.extract::<i128>()?;
+            return Some(lhs.cmp(&rhs));
+        }
+        let lhs = lhs.extract::<f64>()?;
+        let rhs = rhs.extract::<f64>()?;
+        lhs.partial_cmp(&rhs)
+    } else {
+        lhs.partial_cmp(rhs)
+    }
+}
+
+/// Filter by membership in a list of values
+#[derive(Clone)]
+pub struct InListPredicate {
+    column: String,
+    values: Vec<AnyValue<'static>>,
+}
+
+impl InListPredicate {
+    pub fn new(column: impl Into<String>, values: Vec<AnyValue<'static>>) -> Self {
+        Self {
+            column: column.into(),
+            values,
+        }
+    }
+}
+
+impl PredicatePushdown for InListPredicate {
+    fn apply(&self, df: &DataFrame) -> Result<BooleanChunked> {
+        let column = df.column(&self.column)?;
+        let series = column.as_materialized_series();
+
+        let values = Series::from_any_values("_tmp".into(), &self.values, true)?;
+        if !values.is_empty() && values.dtype() != series.dtype() {
+            return Err(StreamingError::Compute(format!(
+                "IN-list for column '{}' has dtype {}, expected {}",
+                self.column,
+                values.dtype(),
+                series.dtype()
+            )));
+        }
+
+        Ok(is_in(series, &values)?)
+    }
+}
+
+/// Filter on whether a column value is null
+#[derive(Clone)]
+pub struct NullFilterPredicate {
+    column: String,
+    keep_nulls: bool,
+}
+
+impl NullFilterPredicate {
+    pub fn new(column: impl Into<String>, keep_nulls: bool) -> Self {
+        Self {
+            column: column.into(),
+            keep_nulls,
+        }
+    }
+
+    /// Keep only rows where the column is null
+    pub fn is_null(column: impl Into<String>) -> Self {
+        Self::new(column, true)
+    }
+
+    /// Keep only rows where the column is not null
+    pub fn is_not_null(column: impl Into<String>) -> Self {
+        Self::new(column, false)
+    }
+}
+
+impl PredicatePushdown for NullFilterPredicate {
+    fn apply(&self, df: &DataFrame) -> Result<BooleanChunked> {
+        let column = df.column(&self.column)?;
+        let series = column.as_materialized_series();
+
+        let mask = if self.keep_nulls {
+            series.is_null()
+        } else {
+            series.is_not_null()
+        };
+
+        Ok(mask)
+    }
+}
+
+/// String matching mode for [`StringMatchPredicate`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringMatchMode {
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+/// Filter a string column by literal substring, prefix or suffix
+#[derive(Clone)]
+pub struct StringMatchPredicate {
+    column: String,
+    mode: StringMatchMode,
+    pattern: String,
+}
+
+impl StringMatchPredicate {
+    pub fn new(column: impl Into<String>, mode: StringMatchMode, pattern: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            mode,
+            pattern: pattern.into(),
+        }
+    }
+
+    pub fn contains(column: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self::new(column, StringMatchMode::Contains, pattern)
+    }
+
+    pub fn starts_with(column: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self::new(column, StringMatchMode::StartsWith, pattern)
+    }
+
+    pub fn ends_with(column: impl Into<String>, pattern: impl Into<String>) -> Self {
+        Self::new(column, StringMatchMode::EndsWith, pattern)
+    }
+}
+
+impl PredicatePushdown for StringMatchPredicate {
+    fn apply(&self, df: &DataFrame) -> Result<BooleanChunked> {
+        let column = df.column(&self.column)?;
+        let series = column.as_materialized_series();
+
+        if series.dtype() != &DataType::String {
+            return Err(StreamingError::Compute(format!(
+                "String match on column '{}' requires a string column, got {}",
+                self.column,
+                series.dtype()
+            )));
+        }
+        let ca = series.str()?;
+
+        let mask = match self.mode {
+            StringMatchMode::Contains => ca.contains_literal(&self.pattern)?,
+            StringMatchMode::StartsWith => ca.starts_with(&self.pattern),
+            // No string specialization of ends_with; binary view of the same data
+            StringMatchMode::EndsWith => ca.as_binary().ends_with(self.pattern.as_bytes()),
+        };
+
+        Ok(mask)
+    }
 }
 
 /// Combine multiple predicates with AND
@@ -90,9 +289,39 @@ impl PredicatePushdown for AndPredicate {
         }
 
         result.ok_or_else(|| {
-            crate::error::StreamingError::InvalidConfig("No predicates provided".to_string())
+            StreamingError::InvalidConfig("No predicates provided".to_string())
         })
     }
+
+    fn may_match_row_group(&self, reader: &MmapParquetReader, row_group: usize) -> Result<bool> {
+        for predicate in &self.predicates {
+            if !predicate.may_match_row_group(reader, row_group)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Negate another predicate
+///
+/// Null mask entries stay null rather than becoming `true`, so rows with
+/// missing values are dropped by both a predicate and its negation.
+pub struct NotPredicate {
+    predicate: Box<dyn PredicatePushdown>,
+}
+
+impl NotPredicate {
+    pub fn new(predicate: Box<dyn PredicatePushdown>) -> Self {
+        Self { predicate }
+    }
+}
+
+impl PredicatePushdown for NotPredicate {
+    fn apply(&self, df: &DataFrame) -> Result<BooleanChunked> {
+        let mask = self.predicate.apply(df)?;
+        Ok((&mask).not())
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +342,33 @@ mod tests {
         assert_eq!(mask.sum().unwrap(), 3); // 3, 4, 5 are > 2
     }
 
+    #[test]
+    fn test_large_integer_statistics_compare_exactly() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("large.parquet");
+        let mut df = DataFrame::new(vec![
+            Series::new("id".into(), vec![i64::MAX - 1, i64::MAX - 1]).into(),
+        ])
+        .unwrap();
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .finish(&mut df)
+            .unwrap();
+        let reader = MmapParquetReader::new(&path).unwrap();
+
+        // Both bounds round to the same f64 as i64::MAX
+        let matching = ColumnFilterPredicate::new("id", "==", AnyValue::Int64(i64::MAX - 1));
+        assert!(matching.may_match_row_group(&reader, 0).unwrap());
+        let above = ColumnFilterPredicate::new("id", "==", AnyValue::Int64(i64::MAX));
+        assert!(!above.may_match_row_group(&reader, 0).unwrap());
+        let unsigned = ColumnFilterPredicate::new("id", ">=", AnyValue::UInt64(u64::MAX));
+        assert!(!unsigned.may_match_row_group(&reader, 0).unwrap());
+
+        assert_eq!(
+            compare_values(&AnyValue::Int64(i64::MAX - 1), &AnyValue::Float64(1.5)),
+            Some(Ordering::Greater)
+        );
+    }
+
     #[test]
     fn test_and_predicate() {
         let df = DataFrame::new(vec![
@@ -131,4 +387,129 @@ mod tests {
 
         assert_eq!(mask.sum().unwrap(), 2); // 3,4 satisfy both conditions
     }
+
+    #[test]
+    fn test_not_predicate() {
+        let df = DataFrame::new(vec![
+            Series::new("volume".into(), vec![500, 2_000_000, 750, 3_000_000, 1_000_000]).into(),
+        ])
+        .unwrap();
+
+        let inner: Box<dyn PredicatePushdown> = Box::new(ColumnFilterPredicate::new(
+            "volume",
+            ">",
+            AnyValue::Int32(1_000_000),
+        ));
+        let not_pred = NotPredicate::new(inner);
+        let filtered = df.filter(&not_pred.apply(&df).unwrap()).unwrap();
+
+        let volumes: Vec<i32> = filtered
+            .column("volume")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(volumes, vec![500, 750, 1_000_000]);
+    }
+
+    #[test]
+    fn test_in_list_predicate() {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), vec![1, 2, 3, 4, 5]).into(),
+        ])
+        .unwrap();
+
+        let predicate =
+            InListPredicate::new("a", vec![AnyValue::Int32(2), AnyValue::Int32(4)]);
+        let filtered = df.filter(&predicate.apply(&df).unwrap()).unwrap();
+
+        let values: Vec<i32> = filtered
+            .column("a")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_no_null_iter()
+            .collect();
+        assert_eq!(values, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_in_list_dtype_mismatch() {
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), vec![1, 2, 3]).into(),
+        ])
+        .unwrap();
+
+        let predicate = InListPredicate::new("a", vec![AnyValue::StringOwned("2".into())]);
+        assert!(matches!(
+            predicate.apply(&df),
+            Err(StreamingError::Compute(_))
+        ));
+    }
+
+    #[test]
+    fn test_null_filter_predicate() {
+        let df = DataFrame::new(vec![
+            Series::new("bid".into(), vec![Some(1.0), None, Some(3.0), None, Some(5.0)]).into(),
+        ])
+        .unwrap();
+
+        let keep = NullFilterPredicate::is_null("bid").apply(&df).unwrap();
+        assert_eq!(keep.into_no_null_iter().collect::<Vec<_>>(), vec![false, true, false, true, false]);
+
+        let drop = NullFilterPredicate::is_not_null("bid").apply(&df).unwrap();
+        let filtered = df.filter(&drop).unwrap();
+        assert_eq!(filtered.height(), 3);
+        assert_eq!(filtered.column("bid").unwrap().null_count(), 0);
+    }
+
+    fn log_frame() -> DataFrame {
+        DataFrame::new(vec![
+            Series::new(
+                "message".into(),
+                vec!["ERROR disk full", "INFO started", "WARN slow ERROR", "INFO done"],
+            )
+            .into(),
+            Series::new("code".into(), vec![1, 2, 3, 4]).into(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_string_contains() {
+        let df = log_frame();
+        let mask = StringMatchPredicate::contains("message", "ERROR").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 2);
+
+        let mask = StringMatchPredicate::contains("message", "FATAL").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_string_starts_with() {
+        let df = log_frame();
+        let mask = StringMatchPredicate::starts_with("message", "INFO").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 2);
+
+        let mask = StringMatchPredicate::starts_with("message", "full").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_string_ends_with() {
+        let df = log_frame();
+        let mask = StringMatchPredicate::ends_with("message", "ERROR").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 1);
+
+        let mask = StringMatchPredicate::ends_with("message", "INFO").apply(&df).unwrap();
+        assert_eq!(mask.sum().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_string_match_non_string_column() {
+        let df = log_frame();
+        let result = StringMatchPredicate::contains("code", "1").apply(&df);
+        assert!(matches!(result, Err(StreamingError::Compute(_))));
+    }
 }
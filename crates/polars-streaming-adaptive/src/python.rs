@@ -0,0 +1,172 @@
+//! Python bindings for the adaptive streaming reader
+//!
+//! Batches are handed to Python as `polars.DataFrame` objects. Each column
+//! is exported through the Arrow C data interface, imported by `pyarrow`
+//! and assembled with `polars.from_arrow`. Columns are rechunked into one
+//! array first, so data is only copied for columns split across chunks;
+//! batches decoded from a single row group are already contiguous.
+//!
+//! ```python
+//! from polars_streaming_adaptive import AdaptiveReader
+//!
+//! for df in AdaptiveReader("large_file.parquet"):
+//!     print(df.height)
+//! ```
+
+use std::path::PathBuf;
+
+use polars::export::arrow::ffi::{export_array_to_c, export_field_to_c};
+use polars::prelude::*;
+use pyo3::exceptions::PyIOError;
+use pyo3::prelude::*;
+
+use crate::adaptive_reader::{AdaptiveBatchIterator, AdaptiveStreamingReader};
+use crate::error::StreamingError;
+
+impl From<StreamingError> for PyErr {
+    fn from(err: StreamingError) -> Self {
+        PyIOError::new_err(err.to_string())
+    }
+}
+
+/// Convert a Rust DataFrame into a Python `polars.DataFrame`
+pub fn dataframe_to_py(py: Python<'_>, df: &DataFrame) -> PyResult<Py<PyAny>> {
+    let pyarrow = py.import("pyarrow")?;
+    let array_type = pyarrow.getattr("Array")?;
+
+    let mut names = Vec::with_capacity(df.width());
+    let mut arrays = Vec::with_capacity(df.width());
+    for column in df.get_columns() {
+        let series = column.as_materialized_series().rechunk();
+        let field = series.field().to_arrow(CompatLevel::newest());
+
+        // pyarrow takes ownership of both structs by moving their release
+        // callbacks, so they only need to live until the call returns
+        let array = Box::new(export_array_to_c(series.to_arrow(0, CompatLevel::newest())));
+        let schema = Box::new(export_field_to_c(&field));
+        let array_ptr = &*array as *const _ as usize;
+        let schema_ptr = &*schema as *const _ as usize;
+
+        arrays.push(array_type.call_method1("_import_from_c", (array_ptr, schema_ptr))?);
+        names.push(series.name().to_string());
+    }
+
+    let table = pyarrow
+        .getattr("Table")?
+        .call_method1("from_arrays", (arrays, names))?;
+    Ok(py.import("polars")?.call_method1("from_arrow", (table,))?.unbind())
+}
+
+/// Python iterator over the batches of a Parquet file
+///
+/// Yields one `polars.DataFrame` per batch, sized by the adaptive chunk
+/// strategy like [`AdaptiveStreamingReader::collect_batches_adaptive`].
+#[pyclass(name = "AdaptiveReader", module = "polars_streaming_adaptive")]
+pub struct PyAdaptiveReader {
+    path: PathBuf,
+    num_row_groups: usize,
+    batches: Option<AdaptiveBatchIterator>,
+}
+
+impl PyAdaptiveReader {
+    /// Next batch as a Rust DataFrame, or `None` once the file is exhausted
+    pub fn next_batch(&mut self) -> PyResult<Option<DataFrame>> {
+        match self.batches.as_mut().and_then(Iterator::next) {
+            Some(batch) => Ok(Some(batch?)),
+            None => {
+                self.batches = None;
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl PyAdaptiveReader {
+    /// Open a Parquet file: AdaptiveReader(path)
+    #[new]
+    pub fn new(path: PathBuf) -> PyResult<Self> {
+        let reader = AdaptiveStreamingReader::new(&path)?;
+        let num_row_groups = reader.progress().total_row_groups;
+
+        Ok(Self {
+            path,
+            num_row_groups,
+            batches: Some(reader.collect_batches_adaptive()),
+        })
+    }
+
+    /// Number of row groups in the file
+    #[getter]
+    pub fn num_row_groups(&self) -> usize {
+        self.num_row_groups
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        match self.next_batch()? {
+            Some(df) => dataframe_to_py(py, &df).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Read the whole file into one DataFrame
+    ///
+    /// Independent of iteration progress. Loads everything into memory.
+    fn collect(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let df = AdaptiveStreamingReader::new(&self.path)?.collect()?;
+        dataframe_to_py(py, &df)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AdaptiveReader({}, row_groups={})",
+            self.path.display(),
+            self.num_row_groups
+        )
+    }
+}
+
+/// Python module entry point
+#[pymodule]
+fn polars_streaming_adaptive(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyAdaptiveReader>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_reader_yields_one_batch_per_row_group() {
+        let df = DataFrame::new(vec![
+            Series::new("id".into(), (0..1000i32).collect::<Vec<_>>()).into(),
+        ])
+        .unwrap();
+        let path = std::env::temp_dir().join(format!("test_py_reader_{}.parquet", Uuid::new_v4()));
+        ParquetWriter::new(std::fs::File::create(&path).unwrap())
+            .with_row_group_size(Some(250))
+            .finish(&mut df.clone())
+            .unwrap();
+
+        let mut reader = PyAdaptiveReader::new(path.clone()).unwrap();
+        assert_eq!(reader.num_row_groups(), 4);
+
+        let mut batches = 0;
+        let mut rows = 0;
+        while let Some(batch) = reader.next_batch().unwrap() {
+            batches += 1;
+            rows += batch.height();
+        }
+        assert_eq!(batches, reader.num_row_groups());
+        assert_eq!(rows, 1000);
+        assert!(reader.next_batch().unwrap().is_none());
+
+        std::fs::remove_file(path).ok();
+    }
+}
@@ -0,0 +1,33 @@
+//! Schema comparison shared by the sink and multi-file readers
+
+use polars::prelude::*;
+
+/// Describe the first difference between two schemas, if any
+///
+/// Columns are compared by position, so a reordered schema is reported
+/// as a mismatch too.
+pub(crate) fn schema_difference(expected: &Schema, found: &Schema) -> Option<String> {
+    for (idx, (name, dtype)) in expected.iter().enumerate() {
+        match found.get_at_index(idx) {
+            Some((found_name, _)) if found_name != name => {
+                return Some(match found.get(name) {
+                    Some(_) => format!("column '{}' is at a different position", name),
+                    None => format!("expected column '{}', found '{}'", name, found_name),
+                });
+            }
+            Some((_, found_dtype)) if found_dtype != dtype => {
+                return Some(format!(
+                    "column '{}' has type {}, expected {}",
+                    name, found_dtype, dtype
+                ));
+            }
+            Some(_) => {}
+            None => return Some(format!("missing column '{}'", name)),
+        }
+    }
+
+    found
+        .iter_names()
+        .nth(expected.len())
+        .map(|extra| format!("unexpected column '{}'", extra))
+}
@@ -0,0 +1,159 @@
+//! Incremental Parquet writer for streamed batches
+
+use crate::error::{Result, StreamingError};
+use crate::schema::schema_difference;
+use polars::io::parquet::write::BatchedWriter;
+use polars::prelude::*;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Writes DataFrame batches to a Parquet file as they arrive
+///
+/// Every batch becomes one or more row groups, so memory use is bounded by
+/// the batch size rather than the size of the output.
+///
+/// # Example
+/// ```rust,no_run
+/// use polars_streaming_adaptive::{AdaptiveStreamingReader, MmapParquetReader, ParquetSink};
+///
+/// let schema = MmapParquetReader::new("large_file.parquet").unwrap().schema().clone();
+/// let mut sink = ParquetSink::new("filtered.parquet", &schema).unwrap();
+///
+/// let reader = AdaptiveStreamingReader::new("large_file.parquet").unwrap();
+/// for batch in reader.collect_batches_adaptive() {
+///     sink.write_batch(&batch.unwrap()).unwrap();
+/// }
+/// let rows = sink.finish().unwrap();
+/// ```
+pub struct ParquetSink {
+    path: PathBuf,
+    schema: Schema,
+    writer: BatchedWriter<File>,
+    rows_written: usize,
+}
+
+impl ParquetSink {
+    /// Create (or truncate) `path` and prepare to write batches of `schema`
+    pub fn new(path: impl AsRef<Path>, schema: &Schema) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::create(&path)?;
+        let writer = ParquetWriter::new(file).batched(schema)?;
+
+        Ok(Self {
+            path,
+            schema: schema.clone(),
+            writer,
+            rows_written: 0,
+        })
+    }
+
+    /// Append a batch
+    ///
+    /// Returns `StreamingError::SchemaMismatch` if the batch's column names,
+    /// order or types differ from the sink schema. Empty batches are skipped.
+    pub fn write_batch(&mut self, df: &DataFrame) -> Result<()> {
+        if let Some(detail) = schema_difference(&self.schema, &df.schema()) {
+            return Err(StreamingError::SchemaMismatch {
+                path: self.path.clone(),
+                detail,
+            });
+        }
+        if df.height() == 0 {
+            return Ok(());
+        }
+
+        let mut df = df.clone();
+        df.align_chunks_par();
+        self.writer.write_batch(&df)?;
+        self.rows_written += df.height();
+        Ok(())
+    }
+
+    /// Rows written so far
+    pub fn rows_written(&self) -> usize {
+        self.rows_written
+    }
+
+    /// Get output path
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write the Parquet footer and return the number of rows written
+    ///
+    /// A sink dropped without calling `finish` leaves an unreadable file.
+    pub fn finish(self) -> Result<usize> {
+        let size = self.writer.finish()?;
+
+        tracing::debug!(
+            "ParquetSink finished {}: {} rows, {} bytes",
+            self.path.display(),
+            self.rows_written,
+            size
+        );
+
+        Ok(self.rows_written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adaptive_reader::AdaptiveStreamingReader;
+    use crate::mmap_reader::MmapParquetReader;
+    use crate::predicate_pushdown::ColumnFilterPredicate;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_stream_filtered_batches_to_parquet() {
+        let temp_dir = TempDir::new().unwrap();
+        let input = temp_dir.path().join("input.parquet");
+        let output = temp_dir.path().join("output.parquet");
+
+        let mut df = DataFrame::new(vec![
+            Series::new("id".into(), (0..1000i32).collect::<Vec<_>>()).into(),
+            Series::new("value".into(), (0..1000).map(|i| i as f64 * 0.5).collect::<Vec<_>>()).into(),
+        ])
+        .unwrap();
+        ParquetWriter::new(File::create(&input).unwrap())
+            .with_row_group_size(Some(100))
+            .finish(&mut df)
+            .unwrap();
+
+        let schema = MmapParquetReader::new(&input).unwrap().schema().clone();
+        let mut sink = ParquetSink::new(&output, &schema).unwrap();
+
+        let reader = AdaptiveStreamingReader::new(&input).unwrap().with_predicate(Box::new(
+            ColumnFilterPredicate::new("id", ">=", AnyValue::Int32(250)),
+        ));
+        for batch in reader.collect_batches_adaptive() {
+            sink.write_batch(&batch.unwrap()).unwrap();
+        }
+        assert_eq!(sink.finish().unwrap(), 750);
+
+        let written = MmapParquetReader::new(&output).unwrap();
+        assert_eq!(written.total_rows(), 750);
+        assert!(written.num_row_groups() > 1);
+    }
+
+    #[test]
+    fn test_schema_mismatch_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema = Schema::from_iter([Field::new("id".into(), DataType::Int32)]);
+        let mut sink = ParquetSink::new(temp_dir.path().join("out.parquet"), &schema).unwrap();
+
+        let wrong_type = DataFrame::new(vec![Series::new("id".into(), vec![1i64]).into()]).unwrap();
+        assert!(matches!(
+            sink.write_batch(&wrong_type),
+            Err(StreamingError::SchemaMismatch { detail, .. }) if detail.contains("'id'")
+        ));
+
+        let extra = DataFrame::new(vec![
+            Series::new("id".into(), vec![1i32]).into(),
+            Series::new("venue".into(), vec!["X"]).into(),
+        ])
+        .unwrap();
+        assert!(sink.write_batch(&extra).is_err());
+        assert_eq!(sink.rows_written(), 0);
+    }
+}
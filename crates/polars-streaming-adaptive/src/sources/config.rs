@@ -2,7 +2,6 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
 
 /// Generic source configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
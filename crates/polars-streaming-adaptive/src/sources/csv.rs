@@ -6,7 +6,6 @@ use std::fs::File;
 use std::io::{BufReader, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::sync::Arc;
-use tokio::io::AsyncBufReadExt;
 
 use super::{
     CsvConfig, SourceConfig, SourceError, SourceFactory, SourceMetadata, SourceResult,
@@ -54,7 +53,7 @@ impl CsvSource {
             .try_into_reader_with_file_path(Some(self.path.clone()))?
             .finish()?;
         
-        let schema = df.schema();
+        let schema = Arc::new(df.schema());
         self.schema = Some(schema.clone());
         
         // Reset reader
@@ -79,6 +78,7 @@ impl StreamingSource for CsvSource {
     }
     
     async fn read_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
+        self.stats.mark_started();
         if self.schema.is_none() {
             self.infer_schema()?;
         }
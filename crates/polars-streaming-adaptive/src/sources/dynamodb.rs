@@ -11,15 +11,24 @@ use super::{
     error::{SourceError, SourceResult},
     traits::{SourceMetadata, StreamingSource, StreamingStats},
     config::{SourceConfig, Credentials},
+    telemetry::{chunk_span, record_chunk},
 };
 use async_trait::async_trait;
+use tracing::Instrument;
 use polars::prelude::*;
 use aws_config::BehaviorVersion;
 use aws_sdk_dynamodb::{Client, types::AttributeValue};
+use aws_sdk_dynamodb::operation::scan::builders::ScanFluentBuilder;
 use std::collections::HashMap;
+use std::future::Future;
 use std::time::Instant;
 use serde_json::Value;
 
+type Item = HashMap<String, AttributeValue>;
+
+/// One page of a scan: the items and the key to resume from, if any
+type ScanPage = (Vec<Item>, Option<Item>);
+
 #[derive(Debug)]
 pub struct DynamoDbSource {
     client: Client,
@@ -60,14 +69,13 @@ impl DynamoDbSource {
             .or_else(|| config.location.strip_prefix("dynamo://"))
             .ok_or_else(|| SourceError::Config("Invalid DynamoDB URI".to_string()))?;
         
-        let (table_name, query_params) = if let Some(pos) = dynamodb_uri.find('?') {
-            (dynamodb_uri[..pos].to_string(), Some(&dynamodb_uri[pos+1..]))
-        } else {
-            (dynamodb_uri.to_string(), None)
+        let table_name = match dynamodb_uri.split_once('?') {
+            Some((table, _)) => table.to_string(),
+            None => dynamodb_uri.to_string(),
         };
         
         // Build AWS config
-        let aws_config = if let Some(Credentials::DynamoDb { 
+        let aws_config = if let Some(Credentials::DynamoDB { 
             access_key_id, 
             secret_access_key, 
             region 
@@ -80,14 +88,11 @@ impl DynamoDbSource {
                 "polarway"
             );
             
-            let mut builder = aws_config::defaults(BehaviorVersion::latest())
-                .credentials_provider(credentials);
-            
-            if let Some(region) = region {
-                builder = builder.region(aws_config::Region::new(region.clone()));
-            }
-            
-            builder.load().await
+            aws_config::defaults(BehaviorVersion::latest())
+                .credentials_provider(credentials)
+                .region(aws_config::Region::new(region.clone()))
+                .load()
+                .await
         } else {
             aws_config::defaults(BehaviorVersion::latest()).load().await
         };
@@ -130,10 +135,10 @@ impl DynamoDbSource {
         
         let start = Instant::now();
         
-        let items = match &self.operation {
+        let items = match self.operation.clone() {
             Operation::Scan => self.scan().await?,
             Operation::Query { key_condition, index_name } => {
-                self.query(key_condition, index_name.as_deref()).await?
+                self.query(&key_condition, index_name.as_deref()).await?
             },
         };
         
@@ -153,16 +158,86 @@ impl DynamoDbSource {
                 + start.elapsed().as_millis() as f64) / self.stats.chunks_read as f64;
             
             if self.schema.is_none() {
-                self.schema = Some(df.schema());
+                self.schema = Some(Arc::new(df.schema()));
             }
             
-            self.stats.memory_bytes = df.estimated_size();
+            self.stats.memory_bytes = df.estimated_size() as u64;
         }
         
         Ok(df)
     }
     
-    async fn scan(&mut self) -> SourceResult<Vec<HashMap<String, AttributeValue>>> {
+    /// Scan the whole table as `total_segments` concurrent segment scans
+    ///
+    /// Items from all segments are merged into batches of `chunk_size` rows.
+    /// The source is exhausted afterwards.
+    pub async fn parallel_scan(&mut self, total_segments: usize) -> SourceResult<Vec<DataFrame>> {
+        if !matches!(self.operation, Operation::Scan) {
+            return Err(SourceError::UnsupportedOperation(
+                "Parallel scan of a Query operation".to_string()
+            ));
+        }
+        if total_segments == 0 {
+            return Err(SourceError::Config("total_segments must be at least 1".to_string()));
+        }
+        
+        let start = Instant::now();
+        let total = total_segments as i32;
+        
+        let items = scan_segments(total, |segment, start_key| {
+            self.scan_segment_page(segment, total, start_key)
+        }).await?;
+        
+        let mut batches = Vec::new();
+        for chunk in items.chunks(self.chunk_size.max(1)) {
+            if let Some(df) = self.items_to_dataframe(chunk.to_vec())? {
+                batches.push(df);
+            }
+        }
+        
+        let chunks_before = self.stats.chunks_read;
+        for df in &batches {
+            self.stats.records_processed += df.height();
+            self.stats.chunks_read += 1;
+            self.stats.memory_bytes = self.stats.memory_bytes.max(df.estimated_size() as u64);
+        }
+        if !batches.is_empty() {
+            let elapsed = start.elapsed().as_millis() as f64;
+            self.stats.avg_chunk_time_ms = 
+                (self.stats.avg_chunk_time_ms * chunks_before as f64 + elapsed)
+                / self.stats.chunks_read as f64;
+        }
+        
+        if self.schema.is_none() {
+            self.schema = batches.first().map(|df| Arc::new(df.schema()));
+        }
+        self.exhausted = true;
+        
+        Ok(batches)
+    }
+    
+    /// Fetch one page of a single scan segment
+    async fn scan_segment_page(
+        &self,
+        segment: i32,
+        total_segments: i32,
+        start_key: Option<Item>,
+    ) -> SourceResult<ScanPage> {
+        let response = self.scan_request()
+            .segment(segment)
+            .total_segments(total_segments)
+            .set_exclusive_start_key(start_key)
+            .send()
+            .await
+            .map_err(|e| SourceError::DatabaseError(format!(
+                "DynamoDB Scan of segment {}/{} failed: {}", segment, total_segments, e
+            )))?;
+        
+        Ok((response.items.unwrap_or_default(), response.last_evaluated_key))
+    }
+    
+    /// Scan request with the configured limit, projection and filter
+    fn scan_request(&self) -> ScanFluentBuilder {
         let mut request = self.client.scan()
             .table_name(&self.table_name)
             .limit(self.chunk_size as i32);
@@ -175,6 +250,12 @@ impl DynamoDbSource {
             request = request.filter_expression(filter);
         }
         
+        request
+    }
+    
+    async fn scan(&mut self) -> SourceResult<Vec<HashMap<String, AttributeValue>>> {
+        let mut request = self.scan_request();
+        
         if let Some(key) = &self.last_evaluated_key {
             request = request.set_exclusive_start_key(Some(key.clone()));
         }
@@ -260,6 +341,36 @@ impl DynamoDbSource {
     }
 }
 
+/// Run every segment's paginated scan concurrently and gather all items
+///
+/// Each segment resumes from its own last evaluated key until DynamoDB
+/// stops returning one.
+async fn scan_segments<F, Fut>(total_segments: i32, fetch_page: F) -> SourceResult<Vec<Item>>
+where
+    F: Fn(i32, Option<Item>) -> Fut,
+    Fut: Future<Output = SourceResult<ScanPage>>,
+{
+    let segments = (0..total_segments).map(|segment| {
+        let fetch_page = &fetch_page;
+        async move {
+            let mut items = Vec::new();
+            let mut last_evaluated_key = None;
+            loop {
+                let (page, next_key) = fetch_page(segment, last_evaluated_key).await?;
+                items.extend(page);
+                match next_key {
+                    Some(key) => last_evaluated_key = Some(key),
+                    None => break,
+                }
+            }
+            Ok::<_, SourceError>(items)
+        }
+    });
+    
+    let per_segment = futures::future::try_join_all(segments).await?;
+    Ok(per_segment.into_iter().flatten().collect())
+}
+
 fn attribute_value_to_json(value: &AttributeValue) -> Value {
     match value {
         AttributeValue::S(s) => Value::String(s.clone()),
@@ -307,7 +418,12 @@ impl StreamingSource for DynamoDbSource {
     }
     
     async fn read_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
-        self.fetch_page().await
+        self.stats.mark_started();
+        let span = chunk_span("dynamodb");
+        let bytes_before = self.stats.bytes_read;
+        let result = self.fetch_page().instrument(span.clone()).await;
+        record_chunk(&span, &result, self.stats.bytes_read - bytes_before);
+        result
     }
     
     fn stats(&self) -> StreamingStats {
@@ -336,10 +452,17 @@ pub struct DynamoDbSourceFactory;
 
 impl super::SourceFactory for DynamoDbSourceFactory {
     fn create(&self, config: super::SourceConfig) -> super::SourceResult<Box<dyn super::StreamingSource>> {
-        // DynamoDbSource::new is async, need runtime
-        let rt = tokio::runtime::Runtime::new()
-            .map_err(|e| super::SourceError::Config(format!("Failed to create runtime: {}", e)))?;
-        Ok(Box::new(rt.block_on(DynamoDbSource::new(config))?))
+        // DynamoDbSource::new is async, drive it on the shared runtime
+        Ok(Box::new(super::block_on(DynamoDbSource::new(config))??))
+    }
+    
+    fn create_async(
+        &self,
+        config: super::SourceConfig,
+    ) -> futures::future::BoxFuture<'_, super::SourceResult<Box<dyn super::StreamingSource>>> {
+        Box::pin(async move {
+            Ok(Box::new(DynamoDbSource::new(config).await?) as Box<dyn super::StreamingSource>)
+        })
     }
 }
 
@@ -352,4 +475,54 @@ mod tests {
         let config = SourceConfig::new("dynamodb://my-table");
         assert!(config.location.contains("my-table"));
     }
+    
+    #[cfg(feature = "mock-sources")]
+    #[tokio::test]
+    async fn test_parallel_scan_returns_each_item_once() {
+        const TOTAL_SEGMENTS: i32 = 4;
+        const PAGE_SIZE: usize = 7;
+        
+        // In-memory table: item `id` belongs to segment `id % TOTAL_SEGMENTS`
+        let table: Vec<Item> = (0..95)
+            .map(|id| HashMap::from([("id".to_string(), AttributeValue::N(id.to_string()))]))
+            .collect();
+        let id_of = |item: &Item| match &item["id"] {
+            AttributeValue::N(n) => n.parse::<i32>().unwrap(),
+            _ => unreachable!(),
+        };
+        
+        let items = scan_segments(TOTAL_SEGMENTS, |segment, start_key| {
+            let table = &table;
+            async move {
+                let after = start_key.as_ref().map(id_of);
+                let page: Vec<Item> = table.iter()
+                    .filter(|item| id_of(item) % TOTAL_SEGMENTS == segment)
+                    .filter(|item| after.is_none_or(|after| id_of(item) > after))
+                    .take(PAGE_SIZE)
+                    .cloned()
+                    .collect();
+                tokio::task::yield_now().await;
+                
+                let next_key = (page.len() == PAGE_SIZE).then(|| page[PAGE_SIZE - 1].clone());
+                Ok((page, next_key))
+            }
+        }).await.unwrap();
+        
+        let mut ids: Vec<i32> = items.iter().map(id_of).collect();
+        ids.sort();
+        assert_eq!(ids, (0..95).collect::<Vec<_>>());
+    }
+    
+    #[tokio::test]
+    async fn test_factory_inside_runtime() {
+        use crate::sources::SourceFactory;
+        
+        // The blocking path reports an error instead of panicking
+        let config = SourceConfig::new("dynamodb://my-table");
+        assert!(DynamoDbSourceFactory.create(config).is_err());
+        
+        let config = SourceConfig::new("dynamodb://my-table");
+        let source = DynamoDbSourceFactory.create_async(config).await.unwrap();
+        assert!(source.has_more());
+    }
 }
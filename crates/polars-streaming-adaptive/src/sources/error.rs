@@ -2,6 +2,8 @@
 
 use std::fmt;
 
+use polars::prelude::PolarsError;
+
 #[derive(Debug)]
 pub enum SourceError {
     /// IO error
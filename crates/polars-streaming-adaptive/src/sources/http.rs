@@ -1,22 +1,25 @@
 //! HTTP/REST API streaming source with pagination, retry logic, and authentication
 //!
 //! Supports:
-//! - Automatic pagination (offset, page, cursor-based)
-//! - Retry with exponential backoff
+//! - Automatic pagination (offset, page, cursor-based, RFC 5988 Link header)
+//! - Retry with exponential backoff, honoring `Retry-After` on 429
 //! - Multiple authentication methods (Bearer, API key, Basic)
 //! - Rate limiting
-//! - JSON and CSV response parsing
+//! - JSON, NDJSON and CSV response parsing, chosen by `Content-Type`
+//! - Transparent gzip/deflate decoding of compressed responses
 
 use super::{
     error::{SourceError, SourceResult},
     traits::{SourceMetadata, StreamingSource, StreamingStats},
     config::{SourceConfig, Credentials},
+    telemetry::{chunk_span, record_chunk},
 };
 use async_trait::async_trait;
+use tracing::Instrument;
 use polars::prelude::*;
-use reqwest::{Client, Method, Response};
+use reqwest::{header::HeaderMap, Client, Method, Response};
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::time::sleep;
 
 #[derive(Debug)]
@@ -27,25 +30,25 @@ pub struct HttpSource {
     headers: Vec<(String, String)>,
     auth: Option<Credentials>,
     
+    // Request body, re-rendered for every page
+    body_template: Option<String>,
+    content_type: String,
+    
     // Pagination
     pagination_type: PaginationType,
     current_page: usize,
     page_size: usize,
-    total_pages: Option<usize>,
     cursor: Option<String>,
-    
-    // Memory management
-    memory_limit: usize,
-    chunk_size: usize,
+    next_url: Option<String>,
     
     // Retry configuration
     max_retries: usize,
     retry_delay_ms: u64,
-    timeout_secs: u64,
     
     // State
     buffer: Vec<DataFrame>,
     exhausted: bool,
+    schema: Option<SchemaRef>,
     
     // Statistics
     stats: StreamingStats,
@@ -59,6 +62,8 @@ pub enum PaginationType {
     Offset { param_name: String },
     Page { param_name: String },
     Cursor { param_name: String, cursor_field: String },
+    /// Follow the `rel="next"` URL of the `Link` response header
+    LinkHeader,
 }
 
 impl HttpSource {
@@ -69,6 +74,9 @@ impl HttpSource {
                     .and_then(|v| v.parse().ok())
                     .unwrap_or(30)
             ))
+            // Advertise and transparently decode compressed responses
+            .gzip(true)
+            .deflate(true)
             .build()
             .map_err(|e| SourceError::Network(e.to_string()))?;
         
@@ -92,6 +100,7 @@ impl HttpSource {
                     .cloned()
                     .unwrap_or_else(|| "next_cursor".to_string()),
             },
+            Some("link") => PaginationType::LinkHeader,
             _ => PaginationType::None,
         };
         
@@ -108,22 +117,22 @@ impl HttpSource {
             method,
             headers: vec![],
             auth: config.credentials,
+            body_template: config.options.get("body").cloned(),
+            content_type: config.options.get("content_type")
+                .cloned()
+                .unwrap_or_else(|| "application/json".to_string()),
             pagination_type,
             current_page: 0,
             page_size: config.chunk_size.unwrap_or(100),
-            total_pages: None,
             cursor: None,
-            memory_limit: config.memory_limit.unwrap_or(2_000_000_000),
-            chunk_size: config.chunk_size.unwrap_or(100),
+            next_url: None,
             max_retries: config.options.get("max_retries")
                 .and_then(|v| v.parse().ok())
                 .unwrap_or(3),
             retry_delay_ms: 1000,
-            timeout_secs: config.options.get("timeout")
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(30),
             buffer: Vec::new(),
             exhausted: false,
+            schema: None,
             stats: StreamingStats::default(),
             last_request: None,
             rate_limit_delay_ms: config.options.get("rate_limit_ms")
@@ -132,6 +141,15 @@ impl HttpSource {
         })
     }
     
+    /// Coerce every page to a fixed schema
+    ///
+    /// Keeps column types stable across pages whose inferred types differ.
+    /// Missing columns are filled with typed nulls; extra columns are dropped.
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+    
     async fn fetch_page(&mut self) -> SourceResult<Option<DataFrame>> {
         if self.exhausted {
             return Ok(None);
@@ -153,21 +171,31 @@ impl HttpSource {
         let url = self.build_url();
         
         // Make request with retries
-        let response = self.request_with_retry(&url).await?;
+        let body = self.render_body();
+        let response = self.request_with_retry(&url, body.as_deref()).await?;
         
         self.last_request = Some(Instant::now());
         
+        let link_exhausted = if let PaginationType::LinkHeader = self.pagination_type {
+            self.next_url = next_link_url(response.headers());
+            self.next_url.is_none()
+        } else {
+            false
+        };
+        
         // Parse response
+        let format = BodyFormat::from_headers(response.headers());
         let text = response.text().await
             .map_err(|e| SourceError::Network(e.to_string()))?;
         
+        // Decoded size, so throughput matches the records parsed
         self.stats.bytes_read += text.len() as u64;
         
-        // Try JSON first, then CSV
-        let df = if let Ok(json) = serde_json::from_str::<Value>(&text) {
-            self.parse_json_response(json)?
-        } else {
-            self.parse_csv_response(&text)?
+        let df = self.parse_body(format, &text)?;
+        
+        let df = match (df, &self.schema) {
+            (Some(df), Some(schema)) => Some(coerce_to_schema(&df, schema)?),
+            (df, _) => df,
         };
         
         if let Some(df) = &df {
@@ -179,13 +207,16 @@ impl HttpSource {
             
             self.current_page += 1;
             
-            // Check if exhausted
-            if df.height() < self.page_size {
+            // Check if exhausted; a Link header is authoritative when used
+            if link_exhausted
+                || (!matches!(self.pagination_type, PaginationType::LinkHeader)
+                    && df.height() < self.page_size)
+            {
                 self.exhausted = true;
             }
             
             // Update memory usage
-            self.stats.memory_bytes = df.estimated_size();
+            self.stats.memory_bytes = df.estimated_size() as u64;
         } else {
             self.exhausted = true;
         }
@@ -194,6 +225,11 @@ impl HttpSource {
     }
     
     fn build_url(&self) -> String {
+        // Link header URLs are absolute and already carry their parameters
+        if let (PaginationType::LinkHeader, Some(next)) = (&self.pagination_type, &self.next_url) {
+            return next.clone();
+        }
+        
         let mut url = self.base_url.clone();
         
         let separator = if url.contains('?') { "&" } else { "?" };
@@ -216,13 +252,38 @@ impl HttpSource {
                     url.push_str(&format!("{}limit={}", separator, self.page_size));
                 }
             },
-            PaginationType::None => {},
+            PaginationType::LinkHeader | PaginationType::None => {},
         }
         
         url
     }
     
-    async fn request_with_retry(&self, url: &str) -> SourceResult<Response> {
+    /// Offset and page numbers can address any page directly; cursors and
+    /// Link headers only lead to the next one
+    fn is_seekable(&self) -> bool {
+        matches!(self.pagination_type, PaginationType::Offset { .. } | PaginationType::Page { .. })
+    }
+    
+    /// Render the request body template for the current page
+    ///
+    /// Placeholders are substituted with JSON values: `{{page}}` (1-based),
+    /// `{{offset}}`, `{{limit}}` and `{{cursor}}` (a string, or `null`
+    /// before the first cursor is known).
+    fn render_body(&self) -> Option<String> {
+        let template = self.body_template.as_ref()?;
+        let cursor = match &self.cursor {
+            Some(cursor) => Value::String(cursor.clone()).to_string(),
+            None => "null".to_string(),
+        };
+        
+        Some(template
+            .replace("{{page}}", &(self.current_page + 1).to_string())
+            .replace("{{offset}}", &(self.current_page * self.page_size).to_string())
+            .replace("{{limit}}", &self.page_size.to_string())
+            .replace("{{cursor}}", &cursor))
+    }
+    
+    async fn request_with_retry(&self, url: &str, body: Option<&str>) -> SourceResult<Response> {
         let mut attempts = 0;
         let mut delay = self.retry_delay_ms;
         
@@ -253,6 +314,12 @@ impl HttpSource {
                 request = request.header(name, value);
             }
             
+            if let Some(body) = body {
+                request = request
+                    .header(reqwest::header::CONTENT_TYPE, &self.content_type)
+                    .body(body.to_string());
+            }
+            
             match request.send().await {
                 Ok(response) => {
                     if response.status().is_success() {
@@ -265,7 +332,10 @@ impl HttpSource {
                                 format!("Rate limited after {} retries", attempts)
                             ));
                         }
-                        sleep(Duration::from_millis(delay)).await;
+                        // Wait as long as the server asks, else back off
+                        let wait = retry_after(response.headers(), SystemTime::now())
+                            .unwrap_or(Duration::from_millis(delay));
+                        sleep(wait).await;
                         delay *= 2; // Exponential backoff
                     } else {
                         return Err(SourceError::Network(
@@ -288,6 +358,25 @@ impl HttpSource {
         }
     }
     
+    /// Parse a response body in the declared format
+    ///
+    /// Without a recognized `Content-Type`, JSON is tried first, then CSV.
+    fn parse_body(&mut self, format: Option<BodyFormat>, text: &str) -> SourceResult<Option<DataFrame>> {
+        match format {
+            Some(BodyFormat::Json) => {
+                let json = serde_json::from_str::<Value>(text)
+                    .map_err(|e| SourceError::ParseError(e.to_string()))?;
+                self.parse_json_response(json)
+            },
+            Some(BodyFormat::Ndjson) => self.parse_ndjson_response(text),
+            Some(BodyFormat::Csv) => self.parse_csv_response(text),
+            None => match serde_json::from_str::<Value>(text) {
+                Ok(json) => self.parse_json_response(json),
+                Err(_) => self.parse_csv_response(text),
+            },
+        }
+    }
+    
     fn parse_json_response(&mut self, json: Value) -> SourceResult<Option<DataFrame>> {
         // Handle different JSON structures
         let data = if let Some(array) = json.as_array() {
@@ -331,12 +420,26 @@ impl HttpSource {
         Ok(Some(df))
     }
     
+    fn parse_ndjson_response(&self, text: &str) -> SourceResult<Option<DataFrame>> {
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        
+        let df = JsonReader::new(std::io::Cursor::new(text.as_bytes()))
+            .with_json_format(JsonFormat::JsonLines)
+            .finish()
+            .map_err(|e| SourceError::PolarsError(e.to_string()))?;
+        
+        Ok(Some(df))
+    }
+    
     fn parse_csv_response(&self, text: &str) -> SourceResult<Option<DataFrame>> {
         if text.trim().is_empty() {
             return Ok(None);
         }
         
-        let df = CsvReader::new(std::io::Cursor::new(text.as_bytes()))
+        let df = CsvReadOptions::default()
+            .into_reader_with_file_handle(std::io::Cursor::new(text.as_bytes()))
             .finish()
             .map_err(|e| SourceError::PolarsError(e.to_string()))?;
         
@@ -344,20 +447,99 @@ impl HttpSource {
     }
 }
 
+/// Response body formats, as declared by `Content-Type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyFormat {
+    Json,
+    Ndjson,
+    Csv,
+}
+
+impl BodyFormat {
+    /// Format of the `Content-Type` header, ignoring parameters like `charset`
+    ///
+    /// `None` when the header is absent or names an unknown type.
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let value = headers.get(reqwest::header::CONTENT_TYPE)?.to_str().ok()?;
+        let mime = value.split(';').next()?.trim().to_ascii_lowercase();
+        match mime.as_str() {
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => Some(Self::Ndjson),
+            "text/csv" | "application/csv" => Some(Self::Csv),
+            "application/json" | "text/json" => Some(Self::Json),
+            mime if mime.ends_with("+json") => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// Select, cast and null-fill the columns of `df` to match `schema` exactly
+fn coerce_to_schema(df: &DataFrame, schema: &Schema) -> SourceResult<DataFrame> {
+    let height = df.height();
+    let columns = schema.iter()
+        .map(|(name, dtype)| match df.column(name) {
+            Ok(column) => column.cast(dtype)
+                .map_err(|e| SourceError::PolarsError(format!("Column '{}': {}", name, e))),
+            Err(_) => Ok(Series::full_null(name.clone(), height, dtype).into()),
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
+    
+    DataFrame::new(columns).map_err(|e| SourceError::PolarsError(e.to_string()))
+}
+
+/// Extract the `rel="next"` target from an RFC 5988 `Link` header
+fn next_link_url(headers: &HeaderMap) -> Option<String> {
+    headers.get_all(reqwest::header::LINK)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|link| {
+            let mut parts = link.split(';');
+            let target = parts.next()?.trim();
+            let url = target.strip_prefix('<')?.strip_suffix('>')?;
+            
+            let is_next = parts.any(|param| {
+                let param = param.trim();
+                param.strip_prefix("rel=").is_some_and(|rel| {
+                    rel.trim_matches('"').split_whitespace().any(|r| r.eq_ignore_ascii_case("next"))
+                })
+            });
+            
+            is_next.then(|| url.to_string())
+        })
+}
+
+/// Parse a `Retry-After` header, given as delay seconds or an HTTP-date
+///
+/// Dates in the past yield a zero delay.
+fn retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    
+    let at = httpdate::parse_http_date(value).ok()?;
+    Some(at.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
 #[async_trait]
 impl StreamingSource for HttpSource {
     async fn metadata(&self) -> SourceResult<SourceMetadata> {
         Ok(SourceMetadata {
             size_bytes: None, // Unknown for HTTP
             num_records: None,
-            schema: None, // Will be inferred from first chunk
-            seekable: false,
+            schema: self.schema.clone(), // Otherwise inferred from first chunk
+            seekable: self.is_seekable(),
             parallelizable: false,
         })
     }
     
     async fn read_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
-        self.fetch_page().await
+        self.stats.mark_started();
+        let span = chunk_span("http");
+        let bytes_before = self.stats.bytes_read;
+        let result = self.fetch_page().instrument(span.clone()).await;
+        record_chunk(&span, &result, self.stats.bytes_read - bytes_before);
+        result
     }
     
     fn stats(&self) -> StreamingStats {
@@ -365,11 +547,24 @@ impl StreamingSource for HttpSource {
     }
     
     async fn reset(&mut self) -> SourceResult<()> {
-        Err(SourceError::UnsupportedOperation("HTTP sources are not seekable".to_string()))
+        self.seek(0).await
     }
     
-    async fn seek(&mut self, _position: u64) -> SourceResult<()> {
-        Err(SourceError::UnsupportedOperation("HTTP sources are not seekable".to_string()))
+    /// Resume at the page holding row `position`
+    ///
+    /// Reading restarts at the beginning of that page, so rows before
+    /// `position` on the same page are returned again.
+    async fn seek(&mut self, position: u64) -> SourceResult<()> {
+        if !self.is_seekable() {
+            return Err(SourceError::UnsupportedOperation(
+                "Only offset and page paginated HTTP sources are seekable".to_string()
+            ));
+        }
+        
+        self.current_page = position as usize / self.page_size.max(1);
+        self.buffer.clear();
+        self.exhausted = false;
+        Ok(())
     }
     
     async fn close(&mut self) -> SourceResult<()> {
@@ -427,5 +622,232 @@ mod tests {
             .with_option("pagination_type", "cursor");
         let source = HttpSource::new(config).unwrap();
         assert!(matches!(source.pagination_type, PaginationType::Cursor { .. }));
+        
+        // Link header pagination
+        let config = SourceConfig::new("https://api.example.com/data")
+            .with_option("pagination_type", "link");
+        let source = HttpSource::new(config).unwrap();
+        assert!(matches!(source.pagination_type, PaginationType::LinkHeader));
+    }
+    
+    #[tokio::test]
+    async fn test_seek_resumes_at_page() {
+        let config = SourceConfig::new("https://api.example.com/data")
+            .with_chunk_size(50)
+            .with_option("pagination_type", "offset");
+        let mut source = HttpSource::new(config).unwrap();
+        source.exhausted = true;
+        
+        // Row 120 is on page 2, which starts at offset 100
+        source.seek(120).await.unwrap();
+        assert!(!source.exhausted);
+        assert_eq!(source.build_url(), "https://api.example.com/data?offset=100&limit=50");
+        
+        source.reset().await.unwrap();
+        assert_eq!(source.build_url(), "https://api.example.com/data?offset=0&limit=50");
+        
+        let config = SourceConfig::new("https://api.example.com/data")
+            .with_option("pagination_type", "cursor");
+        let mut source = HttpSource::new(config).unwrap();
+        assert!(matches!(source.seek(100).await, Err(SourceError::UnsupportedOperation(_))));
+    }
+    
+    #[test]
+    fn test_body_rendered_per_page() {
+        let config = SourceConfig::new("https://api.example.com/search")
+            .with_chunk_size(25)
+            .with_option("method", "POST")
+            .with_option("pagination_type", "page")
+            .with_option("body", r#"{"query": "btc", "page": {{page}}, "size": {{limit}}, "after": {{cursor}}}"#);
+        
+        let mut source = HttpSource::new(config).unwrap();
+        assert_eq!(source.method, Method::POST);
+        assert_eq!(source.content_type, "application/json");
+        
+        let first: Value = serde_json::from_str(&source.render_body().unwrap()).unwrap();
+        assert_eq!(first["page"], 1);
+        assert_eq!(first["size"], 25);
+        assert!(first["after"].is_null());
+        
+        source.current_page += 1;
+        source.cursor = Some("abc".to_string());
+        let second: Value = serde_json::from_str(&source.render_body().unwrap()).unwrap();
+        assert_eq!(second["page"], 2);
+        assert_eq!(second["after"], "abc");
+    }
+    
+    #[test]
+    fn test_schema_override_stabilizes_types() {
+        let schema = Arc::new(Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("price".into(), DataType::Float64),
+            Field::new("venue".into(), DataType::String),
+        ]));
+        let config = SourceConfig::new("https://api.example.com/data");
+        let mut source = HttpSource::new(config).unwrap().with_schema(schema.clone());
+        
+        // Page 1 infers Int64 prices, page 2 Float64 and an extra column
+        let page1 = source.parse_json_response(serde_json::json!([
+            {"id": 1, "price": 100},
+            {"id": 2, "price": 101},
+        ])).unwrap().unwrap();
+        let page2 = source.parse_json_response(serde_json::json!([
+            {"id": 3, "price": 99.5, "extra": true},
+        ])).unwrap().unwrap();
+        assert!(page1.vstack(&page2).is_err());
+        
+        let mut combined = coerce_to_schema(&page1, &schema).unwrap();
+        combined.vstack_mut(&coerce_to_schema(&page2, &schema).unwrap()).unwrap();
+        
+        assert_eq!(combined.schema(), *schema);
+        assert_eq!(combined.height(), 3);
+        assert_eq!(combined.column("venue").unwrap().null_count(), 3);
+    }
+    
+    #[test]
+    fn test_content_type_selects_parser() {
+        let content_type = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(reqwest::header::CONTENT_TYPE, value.parse().unwrap());
+            BodyFormat::from_headers(&headers)
+        };
+        assert_eq!(content_type("text/csv; charset=utf-8"), Some(BodyFormat::Csv));
+        assert_eq!(content_type("application/x-ndjson"), Some(BodyFormat::Ndjson));
+        assert_eq!(content_type("application/vnd.api+json"), Some(BodyFormat::Json));
+        assert_eq!(content_type("text/plain"), None);
+        assert_eq!(BodyFormat::from_headers(&HeaderMap::new()), None);
+        
+        let config = SourceConfig::new("https://api.example.com/export");
+        let mut source = HttpSource::new(config).unwrap();
+        
+        // CSV whose first field starts with a brace
+        let csv = "{tag},value\n{a},1\n{b},2\n";
+        let df = source.parse_body(Some(BodyFormat::Csv), csv).unwrap().unwrap();
+        assert_eq!(df.get_column_names(), &["{tag}", "value"]);
+        assert_eq!(df.height(), 2);
+        
+        let ndjson = "{\"id\": 1, \"price\": 10.5}\n{\"id\": 2, \"price\": 11.0}\n";
+        let df = source.parse_body(Some(BodyFormat::Ndjson), ndjson).unwrap().unwrap();
+        assert_eq!(df.get_column_names(), &["id", "price"]);
+        assert_eq!(df.height(), 2);
+        
+        // Declared JSON that doesn't parse is an error, not a CSV fallback
+        assert!(matches!(
+            source.parse_body(Some(BodyFormat::Json), csv),
+            Err(SourceError::ParseError(_))
+        ));
+    }
+    
+    #[test]
+    fn test_link_header_next_url() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::LINK,
+            r#"<https://api.example.com/data?page=1>; rel="prev", <https://api.example.com/data?page=3>; rel="next", <https://api.example.com/data?page=9>; rel="last""#
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(
+            next_link_url(&headers).as_deref(),
+            Some("https://api.example.com/data?page=3")
+        );
+        
+        let mut last_page = HeaderMap::new();
+        last_page.insert(
+            reqwest::header::LINK,
+            r#"<https://api.example.com/data?page=1>; rel="first""#.parse().unwrap(),
+        );
+        assert_eq!(next_link_url(&last_page), None);
+        assert_eq!(next_link_url(&HeaderMap::new()), None);
+        
+        // The next URL replaces the base URL for the following request
+        let config = SourceConfig::new("https://api.example.com/data")
+            .with_option("pagination_type", "link");
+        let mut source = HttpSource::new(config).unwrap();
+        assert_eq!(source.build_url(), "https://api.example.com/data");
+        source.next_url = next_link_url(&headers);
+        assert_eq!(source.build_url(), "https://api.example.com/data?page=3");
+    }
+    
+    #[test]
+    fn test_retry_after_parsing() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480);
+        let header = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(reqwest::header::RETRY_AFTER, value.parse().unwrap());
+            headers
+        };
+        
+        assert_eq!(retry_after(&header("120"), now), Some(Duration::from_secs(120)));
+        // 2015-10-21T07:28:00Z, 30s after `now`
+        assert_eq!(
+            retry_after(&header("Wed, 21 Oct 2015 07:28:30 GMT"), now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            retry_after(&header("Wed, 21 Oct 2015 07:27:00 GMT"), now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(retry_after(&header("soon"), now), None);
+        assert_eq!(retry_after(&HeaderMap::new(), now), None);
+    }
+    
+    /// Serve the canned HTTP responses in order, one per connection
+    #[cfg(feature = "mock-sources")]
+    fn mock_server(responses: Vec<Vec<u8>>) -> String {
+        use std::io::{Read, Write};
+        
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = [0u8; 4096];
+                let _ = stream.read(&mut request).unwrap();
+                stream.write_all(&response).unwrap();
+            }
+        });
+        format!("http://{}/data", addr)
+    }
+    
+    #[cfg(feature = "mock-sources")]
+    #[tokio::test]
+    async fn test_429_waits_for_retry_after() {
+        let url = mock_server(vec![
+            b"HTTP/1.1 429 Too Many Requests\r\nRetry-After: 2\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec(),
+            b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]".to_vec(),
+        ]);
+        let source = HttpSource::new(SourceConfig::new(&url)).unwrap();
+        
+        // The default backoff starts at 1s, so only Retry-After explains 2s
+        let started = Instant::now();
+        let response = source.request_with_retry(&url, None).await.unwrap();
+        assert!(response.status().is_success());
+        assert!(started.elapsed() >= Duration::from_secs(2));
+    }
+    
+    #[cfg(feature = "mock-sources")]
+    #[tokio::test]
+    async fn test_gzip_response_is_decoded() {
+        use std::io::Write;
+        
+        let json = r#"[{"id": 1, "price": 10.5}, {"id": 2, "price": 11.0}, {"id": 3, "price": 9.75}]"#;
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(json.as_bytes()).unwrap();
+        let body = encoder.finish().unwrap();
+        
+        let mut response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        ).into_bytes();
+        response.extend(&body);
+        
+        let url = mock_server(vec![response]);
+        let mut source = HttpSource::new(SourceConfig::new(&url)).unwrap();
+        
+        let df = source.read_chunk().await.unwrap().unwrap();
+        assert_eq!(df.get_column_names(), &["id", "price"]);
+        assert_eq!(df.height(), 3);
+        assert_eq!(source.stats().bytes_read, json.len() as u64);
     }
 }
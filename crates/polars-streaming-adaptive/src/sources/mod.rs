@@ -4,17 +4,19 @@
 //! with adaptive streaming capabilities. All sources implement the `StreamingSource`
 //! trait, allowing consistent API and behavior across different backends.
 
-use std::sync::Arc;
-use polars::prelude::*;
+use std::future::Future;
+use std::sync::OnceLock;
 
 pub mod csv;
 pub mod http;
 pub mod filesystem;
 pub mod s3;
 pub mod dynamodb;
+pub mod postgres;
 
 mod config;
 mod error;
+mod telemetry;
 mod traits;
 
 pub use config::*;
@@ -25,6 +27,7 @@ pub use http::HttpSource;
 pub use filesystem::FilesystemSource;
 pub use s3::S3Source;
 pub use dynamodb::DynamoDbSource;
+pub use postgres::PostgresSource;
 
 /// Registry for creating sources by type
 pub struct SourceRegistry {
@@ -46,6 +49,8 @@ impl SourceRegistry {
         registry.register("s3", Box::new(s3::S3SourceFactory));
         registry.register("dynamodb", Box::new(dynamodb::DynamoDbSourceFactory));
         registry.register("dynamo", Box::new(dynamodb::DynamoDbSourceFactory));
+        registry.register("postgres", Box::new(postgres::PostgresSourceFactory));
+        registry.register("postgresql", Box::new(postgres::PostgresSourceFactory));
         
         registry
     }
@@ -60,6 +65,41 @@ impl SourceRegistry {
             .ok_or_else(|| SourceError::UnsupportedSource(source_type.to_string()))?
             .create(config)
     }
+    
+    /// Create a source without blocking the calling async context
+    pub async fn create_async(&self, source_type: &str, config: SourceConfig) -> SourceResult<Box<dyn StreamingSource>> {
+        self.factories
+            .get(source_type)
+            .ok_or_else(|| SourceError::UnsupportedSource(source_type.to_string()))?
+            .create_async(config)
+            .await
+    }
+}
+
+/// Drive an async source constructor from a synchronous `SourceFactory::create`
+///
+/// All factories share one runtime. Blocking from inside a Tokio context
+/// would panic, so that case is reported as an error pointing at
+/// `create_async` instead.
+pub(crate) fn block_on<F: Future>(future: F) -> SourceResult<F::Output> {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(SourceError::Config(
+            "Cannot block inside an async runtime, use create_async instead".to_string()
+        ));
+    }
+    
+    let runtime = match RUNTIME.get() {
+        Some(runtime) => runtime,
+        None => {
+            let runtime = tokio::runtime::Runtime::new()
+                .map_err(|e| SourceError::Config(format!("Failed to create runtime: {}", e)))?;
+            RUNTIME.get_or_init(|| runtime)
+        }
+    };
+    
+    Ok(runtime.block_on(future))
 }
 
 impl Default for SourceRegistry {
@@ -77,6 +117,6 @@ mod tests {
         let registry = SourceRegistry::new();
         assert!(registry.factories.contains_key("csv"));
         assert!(registry.factories.contains_key("s3"));
-        assert!(registry.factories.contains_key("kafka"));
+        assert!(registry.factories.contains_key("postgres"));
     }
 }
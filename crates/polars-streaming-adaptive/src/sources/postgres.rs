@@ -0,0 +1,393 @@
+//! PostgreSQL streaming source backed by a server-side cursor
+//!
+//! Supports:
+//! - Any query, or a whole table via the `table` option
+//! - `chunk_size` rows fetched per chunk with `FETCH FORWARD`
+//! - Column types mapped from Postgres OIDs
+//! - Row count estimates via a time-boxed `COUNT(*)`
+
+use super::{
+    error::{SourceError, SourceResult},
+    traits::{SourceMetadata, StreamingSource, StreamingStats},
+    config::SourceConfig,
+};
+use async_trait::async_trait;
+use polars::prelude::*;
+use tokio_postgres::{Client, NoTls, Row, types::Type};
+use std::time::Instant;
+
+const CURSOR_NAME: &str = "polarway_cursor";
+
+pub struct PostgresSource {
+    client: Client,
+    query: String,
+
+    // Column names and types of the query result
+    columns: Vec<(String, Type)>,
+
+    // Configuration
+    chunk_size: usize,
+
+    // State
+    exhausted: bool,
+    num_records: Option<usize>,
+
+    // Statistics
+    stats: StreamingStats,
+
+    // Schema
+    schema: SchemaRef,
+}
+
+impl std::fmt::Debug for PostgresSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PostgresSource")
+            .field("query", &self.query)
+            .field("chunk_size", &self.chunk_size)
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
+}
+
+impl PostgresSource {
+    /// Connect and declare a cursor over the configured query
+    ///
+    /// `config.location` is a `postgres://` connection URL. The query comes
+    /// from the `query` option, or `SELECT * FROM <table>` for `table`, where
+    /// each dot-separated part of the table name is quoted as written.
+    /// Set `count_timeout_ms` to bound the row count estimate (default 1000,
+    /// 0 disables it).
+    pub async fn new(config: SourceConfig) -> SourceResult<Self> {
+        if !config.location.starts_with("postgres://") && !config.location.starts_with("postgresql://") {
+            return Err(SourceError::Config("Invalid PostgreSQL URL".to_string()));
+        }
+
+        let query = match (config.options.get("query"), config.options.get("table")) {
+            (Some(query), _) => query.trim().trim_end_matches(';').to_string(),
+            (None, Some(table)) => format!("SELECT * FROM {}", quote_table(table)?),
+            (None, None) => {
+                return Err(SourceError::Config(
+                    "PostgreSQL source needs a `query` or `table` option".to_string()
+                ));
+            }
+        };
+
+        let (client, connection) = tokio_postgres::connect(&config.location, NoTls).await
+            .map_err(|e| SourceError::DatabaseError(format!("PostgreSQL connect failed: {}", e)))?;
+
+        // The connection drives the socket until the client is dropped
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::warn!("PostgreSQL connection closed: {}", e);
+            }
+        });
+
+        // Resolve result columns up front so unsupported types fail early
+        let statement = client.prepare(&query).await
+            .map_err(|e| SourceError::DatabaseError(format!("Failed to prepare query: {}", e)))?;
+        let columns: Vec<(String, Type)> = statement.columns().iter()
+            .map(|c| (c.name().to_string(), c.type_().clone()))
+            .collect();
+        let schema = columns.iter()
+            .map(|(name, pg_type)| Ok(Field::new(name.into(), pg_to_polars_type(pg_type)?)))
+            .collect::<SourceResult<Schema>>()?;
+
+        // Cursors only live inside a transaction
+        client.batch_execute(&format!(
+            "BEGIN READ ONLY; DECLARE {} NO SCROLL CURSOR FOR {}",
+            CURSOR_NAME, query
+        )).await
+            .map_err(|e| SourceError::DatabaseError(format!("Failed to declare cursor: {}", e)))?;
+
+        let count_timeout_ms = config.options.get("count_timeout_ms")
+            .map(|t| t.parse::<u64>())
+            .transpose()
+            .map_err(|e| SourceError::Config(format!("Invalid count_timeout_ms: {}", e)))?
+            .unwrap_or(1000);
+        let num_records = if count_timeout_ms > 0 {
+            estimate_count(&client, &query, count_timeout_ms).await
+        } else {
+            None
+        };
+
+        Ok(Self {
+            client,
+            query,
+            columns,
+            chunk_size: config.chunk_size.unwrap_or(10_000),
+            exhausted: false,
+            num_records,
+            stats: StreamingStats::default(),
+            schema: Arc::new(schema),
+        })
+    }
+
+    async fn fetch_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let start = Instant::now();
+
+        let rows = self.client
+            .query(&format!("FETCH FORWARD {} FROM {}", self.chunk_size, CURSOR_NAME), &[])
+            .await
+            .map_err(|e| SourceError::DatabaseError(format!("FETCH failed: {}", e)))?;
+
+        if rows.len() < self.chunk_size {
+            self.exhausted = true;
+        }
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let df = self.rows_to_dataframe(&rows)?;
+
+        self.stats.records_processed += df.height();
+        self.stats.chunks_read += 1;
+        self.stats.avg_chunk_time_ms =
+            (self.stats.avg_chunk_time_ms * (self.stats.chunks_read - 1) as f64
+            + start.elapsed().as_millis() as f64) / self.stats.chunks_read as f64;
+        self.stats.memory_bytes = df.estimated_size() as u64;
+
+        Ok(Some(df))
+    }
+
+    fn rows_to_dataframe(&self, rows: &[Row]) -> SourceResult<DataFrame> {
+        let columns = self.columns.iter()
+            .enumerate()
+            .map(|(idx, (name, pg_type))| column_from_rows(rows, idx, name, pg_type))
+            .collect::<SourceResult<Vec<Column>>>()?;
+
+        DataFrame::new(columns).map_err(|e| SourceError::PolarsError(e.to_string()))
+    }
+}
+
+/// Count the query's rows unless it takes longer than `timeout_ms`
+///
+/// Runs under a savepoint so a cancelled count leaves the cursor's
+/// transaction usable.
+async fn estimate_count(client: &Client, query: &str, timeout_ms: u64) -> Option<usize> {
+    client.batch_execute(&format!(
+        "SAVEPOINT polarway_count; SET LOCAL statement_timeout = {}",
+        timeout_ms
+    )).await.ok()?;
+
+    let count = client
+        .query_one(&format!("SELECT COUNT(*) FROM ({}) AS polarway_count", query), &[])
+        .await
+        .ok()
+        .map(|row| row.get::<_, i64>(0) as usize);
+
+    // Also undoes the SET LOCAL
+    client.batch_execute("ROLLBACK TO SAVEPOINT polarway_count").await.ok()?;
+
+    count
+}
+
+fn pg_to_polars_type(pg_type: &Type) -> SourceResult<DataType> {
+    Ok(match *pg_type {
+        Type::BOOL => DataType::Boolean,
+        Type::INT2 => DataType::Int16,
+        Type::INT4 => DataType::Int32,
+        Type::INT8 => DataType::Int64,
+        Type::OID => DataType::UInt32,
+        Type::FLOAT4 => DataType::Float32,
+        Type::FLOAT8 => DataType::Float64,
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::JSON | Type::JSONB => {
+            DataType::String
+        }
+        Type::BYTEA => DataType::Binary,
+        Type::DATE => DataType::Date,
+        Type::TIMESTAMP => DataType::Datetime(TimeUnit::Microseconds, None),
+        Type::TIMESTAMPTZ => DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())),
+        _ => {
+            return Err(SourceError::UnsupportedOperation(format!(
+                "PostgreSQL type {} (OID {}), cast it in the query",
+                pg_type.name(),
+                pg_type.oid()
+            )));
+        }
+    })
+}
+
+fn column_from_rows(rows: &[Row], idx: usize, name: &str, pg_type: &Type) -> SourceResult<Column> {
+    fn values<'a, T: tokio_postgres::types::FromSql<'a>>(
+        rows: &'a [Row],
+        idx: usize,
+    ) -> SourceResult<Vec<Option<T>>> {
+        rows.iter()
+            .map(|row| row.try_get::<_, Option<T>>(idx))
+            .collect::<Result<_, _>>()
+            .map_err(|e| SourceError::ParseError(e.to_string()))
+    }
+
+    let name = PlSmallStr::from(name);
+    let series = match *pg_type {
+        Type::BOOL => Series::new(name, values::<bool>(rows, idx)?),
+        Type::INT2 => Series::new(name, values::<i16>(rows, idx)?),
+        Type::INT4 => Series::new(name, values::<i32>(rows, idx)?),
+        Type::INT8 => Series::new(name, values::<i64>(rows, idx)?),
+        Type::OID => Series::new(name, values::<u32>(rows, idx)?),
+        Type::FLOAT4 => Series::new(name, values::<f32>(rows, idx)?),
+        Type::FLOAT8 => Series::new(name, values::<f64>(rows, idx)?),
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME => {
+            Series::new(name, values::<String>(rows, idx)?)
+        }
+        Type::JSON | Type::JSONB => {
+            let json = values::<serde_json::Value>(rows, idx)?;
+            Series::new(name, json.into_iter().map(|v| v.map(|v| v.to_string())).collect::<Vec<_>>())
+        }
+        Type::BYTEA => Series::new(name, values::<Vec<u8>>(rows, idx)?),
+        Type::DATE => {
+            let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            Int32Chunked::from_iter_options(
+                name,
+                values::<chrono::NaiveDate>(rows, idx)?
+                    .into_iter()
+                    .map(|d| d.map(|d| (d - epoch).num_days() as i32)),
+            ).into_date().into_series()
+        }
+        Type::TIMESTAMP => Int64Chunked::from_iter_options(
+            name,
+            values::<chrono::NaiveDateTime>(rows, idx)?
+                .into_iter()
+                .map(|ts| ts.map(|ts| ts.and_utc().timestamp_micros())),
+        ).into_datetime(TimeUnit::Microseconds, None).into_series(),
+        Type::TIMESTAMPTZ => Int64Chunked::from_iter_options(
+            name,
+            values::<chrono::DateTime<chrono::Utc>>(rows, idx)?
+                .into_iter()
+                .map(|ts| ts.map(|ts| ts.timestamp_micros())),
+        ).into_datetime(TimeUnit::Microseconds, Some("UTC".into())).into_series(),
+        _ => return Err(SourceError::UnsupportedOperation(format!(
+            "PostgreSQL type {}", pg_type.name()
+        ))),
+    };
+
+    Ok(series.into())
+}
+
+#[async_trait]
+impl StreamingSource for PostgresSource {
+    async fn metadata(&self) -> SourceResult<SourceMetadata> {
+        Ok(SourceMetadata {
+            size_bytes: None,
+            num_records: self.num_records,
+            schema: Some(self.schema.clone()),
+            seekable: false,
+            parallelizable: false,
+        })
+    }
+
+    async fn read_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
+        self.stats.mark_started();
+        self.fetch_chunk().await
+    }
+
+    fn stats(&self) -> StreamingStats {
+        self.stats.clone()
+    }
+
+    async fn close(&mut self) -> SourceResult<()> {
+        self.exhausted = true;
+        self.client.batch_execute(&format!("CLOSE {}; COMMIT", CURSOR_NAME)).await
+            .map_err(|e| SourceError::DatabaseError(format!("Failed to close cursor: {}", e)))
+    }
+
+    fn has_more(&self) -> bool {
+        !self.exhausted
+    }
+}
+
+/// Quote a `schema.table` name part by part, doubling embedded quotes
+fn quote_table(table: &str) -> SourceResult<String> {
+    let parts = table.split('.')
+        .map(|part| match part.trim() {
+            "" => Err(SourceError::Config(format!("Invalid table name: {:?}", table))),
+            part => Ok(format!("\"{}\"", part.replace('"', "\"\""))),
+        })
+        .collect::<SourceResult<Vec<_>>>()?;
+    Ok(parts.join("."))
+}
+
+pub struct PostgresSourceFactory;
+
+impl super::SourceFactory for PostgresSourceFactory {
+    fn create(&self, config: super::SourceConfig) -> super::SourceResult<Box<dyn super::StreamingSource>> {
+        // PostgresSource::new is async, drive it on the shared runtime
+        Ok(Box::new(super::block_on(PostgresSource::new(config))??))
+    }
+
+    fn create_async(
+        &self,
+        config: super::SourceConfig,
+    ) -> futures::future::BoxFuture<'_, super::SourceResult<Box<dyn super::StreamingSource>>> {
+        Box::pin(async move {
+            Ok(Box::new(PostgresSource::new(config).await?) as Box<dyn super::StreamingSource>)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_mapping() {
+        assert_eq!(pg_to_polars_type(&Type::INT8).unwrap(), DataType::Int64);
+        assert_eq!(pg_to_polars_type(&Type::VARCHAR).unwrap(), DataType::String);
+        assert!(pg_to_polars_type(&Type::POINT).is_err());
+    }
+
+    #[test]
+    fn test_quote_table() {
+        assert_eq!(quote_table("trades").unwrap(), "\"trades\"");
+        assert_eq!(quote_table("public.trades").unwrap(), "\"public\".\"trades\"");
+        assert_eq!(
+            quote_table("t; DROP TABLE users; --").unwrap(),
+            "\"t; DROP TABLE users; --\""
+        );
+        assert_eq!(quote_table("a\"b").unwrap(), "\"a\"\"b\"");
+        assert!(quote_table("public.").is_err());
+        assert!(quote_table("").is_err());
+    }
+
+    /// Requires a database at `DATABASE_URL`, e.g. `postgres://postgres@localhost/postgres`
+    #[cfg(feature = "postgres-tests")]
+    #[tokio::test]
+    async fn test_cursor_streams_table() {
+        let url = std::env::var("DATABASE_URL").unwrap();
+        let (client, connection) = tokio_postgres::connect(&url, NoTls).await.unwrap();
+        tokio::spawn(connection);
+
+        let table = format!("polarway_features_{}", std::process::id());
+        client.batch_execute(&format!(
+            "CREATE TABLE {table} (id INT8, name TEXT, score FLOAT8, seen TIMESTAMPTZ);
+             INSERT INTO {table}
+             SELECT i, 'user_' || i, i * 0.5, TIMESTAMPTZ '2024-01-01' + i * INTERVAL '1 minute'
+             FROM generate_series(1, 250) AS i;"
+        )).await.unwrap();
+
+        let config = SourceConfig::new(url)
+            .with_chunk_size(100)
+            .with_option("table", table.clone());
+        let mut source = PostgresSource::new(config).await.unwrap();
+
+        let metadata = source.metadata().await.unwrap();
+        assert_eq!(metadata.num_records, Some(250));
+        assert!(!metadata.seekable);
+
+        let mut heights = Vec::new();
+        while let Some(chunk) = source.read_chunk().await.unwrap() {
+            assert_eq!(chunk.column("id").unwrap().dtype(), &DataType::Int64);
+            assert_eq!(chunk.column("name").unwrap().dtype(), &DataType::String);
+            assert!(matches!(chunk.column("seen").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+            heights.push(chunk.height());
+        }
+        assert_eq!(heights, vec![100, 100, 50]);
+
+        source.close().await.unwrap();
+        client.batch_execute(&format!("DROP TABLE {table}")).await.unwrap();
+    }
+}
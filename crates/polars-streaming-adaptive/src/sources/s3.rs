@@ -2,47 +2,95 @@
 //!
 //! Supports:
 //! - Streaming downloads with chunking
+//! - Parquet streamed one row group per chunk via ranged GETs
+//! - Whole prefixes (`s3://bucket/prefix/`) streamed object by object
+//! - Transparent gzip/zstd decompression of `.gz`/`.zst` objects
 //! - AWS credential management
 //! - Multi-region support
-//! - Retry logic for network errors
-//! - Parallel chunk downloads (optional)
+//! - Retries with jittered exponential backoff for transient errors
+//!   (`max_retries` and `base_delay_ms` options)
+//! - Parallel ranged downloads of uncompressed objects (`S3Source::parallel`)
 
 use super::{
+    filesystem::CompressionType,
     error::{SourceError, SourceResult},
     traits::{SourceMetadata, StreamingSource, StreamingStats},
     config::{SourceConfig, Credentials},
+    telemetry::{chunk_span, record_chunk},
 };
 use async_trait::async_trait;
+use tracing::Instrument;
+use polars::io::mmap::MmapBytesReader;
+use polars::io::parquet::metadata::FileMetadataRef;
 use polars::prelude::*;
 use aws_config::BehaviorVersion;
-use aws_sdk_s3::{Client, primitives::ByteStream};
-use std::time::Instant;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::config::http::HttpResponse;
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
+use aws_sdk_s3::operation::get_object::GetObjectError;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
 use bytes::Bytes;
 
+/// Parquet files end with a 4 byte footer length followed by this magic
+const PARQUET_MAGIC: &[u8; 4] = b"PAR1";
+
 #[derive(Debug)]
 pub struct S3Source {
-    client: Client,
+    fetcher: Arc<dyn RangeFetcher>,
+    retry: RetryPolicy,
     bucket: String,
     key: String,
     
+    // Objects to stream (key, size) in lexical order, and the current one
+    objects: Vec<(String, Option<u64>)>,
+    object_idx: usize,
+    
     // Chunking
-    chunk_size: usize,
     memory_limit: usize,
+    /// Ranged GETs issued at once per chunk
+    concurrency: usize,
     
     // State
     offset: u64,
     total_size: Option<u64>,
     buffer: Vec<u8>,
     exhausted: bool,
+    /// Schema read from the current CSV object's header, used to parse
+    /// the object's later headerless chunks
+    csv_schema: Option<SchemaRef>,
+    
+    // Decompression of the current object, if compressed
+    decoder: Option<Decoder>,
     
     // Statistics
     stats: StreamingStats,
     
-    // Schema
+    // Schema, unified across objects
     schema: Option<SchemaRef>,
+    
+    // Parquet footer, fetched on the first read
+    parquet: Option<ParquetFooter>,
+}
+
+/// Parquet metadata and progress through its row groups
+#[derive(Debug)]
+struct ParquetFooter {
+    metadata: FileMetadataRef,
+    /// Raw footer bytes (metadata, length and magic) and their offset
+    bytes: (u64, Bytes),
+    next_row_group: usize,
+    rows_before: usize,
 }
 
 impl S3Source {
+    /// Create a source for `s3://bucket/key` or, with a trailing slash,
+    /// every object under `s3://bucket/prefix/`
     pub async fn new(config: SourceConfig) -> SourceResult<Self> {
         // Parse S3 URI: s3://bucket/key
         let s3_uri = config.location.strip_prefix("s3://")
@@ -86,36 +134,275 @@ impl S3Source {
         
         let client = Client::new(&aws_config);
         
-        // Get object metadata
-        let head = client.head_object()
-            .bucket(&bucket)
-            .key(&key)
-            .send()
-            .await
-            .map_err(|e| SourceError::CloudError(format!("S3 HeadObject failed: {}", e)))?;
+        let objects = if key.is_empty() || key.ends_with('/') {
+            list_objects(&client, &bucket, &key).await?
+        } else {
+            // Get object metadata
+            let head = client.head_object()
+                .bucket(&bucket)
+                .key(&key)
+                .send()
+                .await
+                .map_err(|e| SourceError::CloudError(format!("S3 HeadObject failed: {}", e)))?;
+            
+            vec![(key, head.content_length().map(|s| s as u64))]
+        };
         
-        let total_size = head.content_length().map(|s| s as u64);
+        if objects.is_empty() {
+            return Err(SourceError::Config(format!("No objects found at {}", config.location)));
+        }
+        let (key, total_size) = objects[0].clone();
+        let decoder = Decoder::for_key(&key)?;
         
         Ok(Self {
-            client,
+            fetcher: Arc::new(client),
+            retry: RetryPolicy::from_options(&config.options),
             bucket,
             key,
-            chunk_size: config.chunk_size.unwrap_or(10_000),
+            objects,
+            object_idx: 0,
             memory_limit: config.memory_limit.unwrap_or(2_000_000_000),
+            concurrency: 1,
             offset: 0,
             total_size,
             buffer: Vec::new(),
             exhausted: false,
+            csv_schema: None,
+            decoder,
             stats: StreamingStats::default(),
             schema: None,
+            parquet: None,
+        })
+    }
+    
+    /// Download each chunk as `concurrency` ranged GETs issued at once
+    ///
+    /// The ranges are reassembled in order before parsing, so results are
+    /// identical to the sequential path; a chunk just spans `concurrency`
+    /// times as many bytes. Only used for uncompressed objects of known
+    /// size: compressed streams must be fed to the decoder in order, and
+    /// Parquet is already read one row group at a time.
+    pub fn parallel(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+    
+    /// Only stream objects whose key ends with `suffix`, e.g. `".parquet"`
+    ///
+    /// Useful to skip markers such as `_SUCCESS` when reading a prefix.
+    pub fn with_suffix_filter(mut self, suffix: &str) -> Self {
+        self.objects.retain(|(key, _)| key.ends_with(suffix));
+        self.select_object(0);
+        self
+    }
+    
+    /// Make `objects[idx]` the current object, or mark the source exhausted
+    fn select_object(&mut self, idx: usize) {
+        self.object_idx = idx;
+        self.offset = 0;
+        self.buffer.clear();
+        self.parquet = None;
+        self.csv_schema = None;
+        
+        match self.objects.get(idx) {
+            Some((key, size)) => {
+                self.key = key.clone();
+                self.total_size = *size;
+                self.exhausted = false;
+            }
+            None => self.exhausted = true,
+        }
+        
+        // A key with an unsupported codec fails on its first read instead
+        self.decoder = Decoder::for_key(&self.key).unwrap_or(None);
+    }
+    
+    /// Bring a chunk to the schema unified across all objects read so far
+    fn unify_schema(&mut self, df: DataFrame) -> SourceResult<DataFrame> {
+        let mut schema = self.schema.as_deref().cloned().unwrap_or_default();
+        for (name, dtype) in df.schema().iter() {
+            if schema.get(name).is_none() {
+                schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+        
+        let height = df.height();
+        let columns = schema.iter()
+            .map(|(name, dtype)| match df.column(name) {
+                Ok(column) => column.cast(dtype),
+                Err(_) => Ok(Series::full_null(name.clone(), height, dtype).into()),
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+            .and_then(DataFrame::new)
+            .map_err(|e| SourceError::PolarsError(e.to_string()))?;
+        
+        self.schema = Some(Arc::new(schema));
+        Ok(columns)
+    }
+    
+    fn format(&self) -> FileFormat {
+        let key = self.key
+            .strip_suffix(".gz")
+            .or_else(|| self.key.strip_suffix(".zst"))
+            .unwrap_or(&self.key);
+        
+        if key.ends_with(".parquet") {
+            FileFormat::Parquet
+        } else if key.ends_with(".json") {
+            FileFormat::Json
+        } else {
+            FileFormat::Csv
+        }
+    }
+    
+    /// Fetch bytes `[start, end)` of the object, retrying transient failures
+    async fn fetch_range(&self, start: u64, end: u64) -> SourceResult<Bytes> {
+        fetch_with_retry(self.fetcher.as_ref(), self.retry, &self.bucket, &self.key, start, end).await
+    }
+    
+    /// Fetch `[start, end)` as concurrent GETs of up to `part_size` bytes each
+    async fn fetch_range_parallel(&self, start: u64, end: u64, part_size: u64) -> SourceResult<Bytes> {
+        let parts = (start..end)
+            .step_by(part_size as usize)
+            .map(|part_start| self.fetch_range(part_start, std::cmp::min(part_start + part_size, end)));
+        let parts = futures::future::try_join_all(parts).await?;
+        
+        let mut bytes = Vec::with_capacity((end - start) as usize);
+        for part in parts {
+            bytes.extend_from_slice(&part);
+        }
+        Ok(Bytes::from(bytes))
+    }
+    
+    /// Fetch and decode the Parquet footer with two ranged GETs
+    async fn fetch_parquet_footer(&self) -> SourceResult<ParquetFooter> {
+        let total = self.total_size
+            .ok_or_else(|| SourceError::Config("Parquet streaming requires a known object size".to_string()))?;
+        if total < 12 {
+            return Err(SourceError::ParseError(format!("s3://{}/{} is too small to be Parquet", self.bucket, self.key)));
+        }
+        
+        let tail = self.fetch_range(total - 8, total).await?;
+        if tail.len() != 8 || &tail[4..] != PARQUET_MAGIC {
+            return Err(SourceError::ParseError(format!("s3://{}/{} has no Parquet footer", self.bucket, self.key)));
+        }
+        
+        let footer_len = u32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]) as u64;
+        let footer_start = total.checked_sub(8 + footer_len)
+            .ok_or_else(|| SourceError::ParseError("Parquet footer length exceeds object size".to_string()))?;
+        let footer = self.fetch_range(footer_start, total).await?;
+        
+        let object = RangedObject::new(total, vec![(footer_start, footer.clone())]);
+        let metadata = ParquetReader::new(object)
+            .get_metadata()
+            .map_err(|e| SourceError::PolarsError(e.to_string()))?
+            .clone();
+        
+        tracing::debug!(
+            "Fetched Parquet footer of s3://{}/{}: {} row groups",
+            self.bucket, self.key, metadata.row_groups.len()
+        );
+        
+        Ok(ParquetFooter {
+            metadata,
+            bytes: (footer_start, footer),
+            next_row_group: 0,
+            rows_before: 0,
         })
     }
     
+    /// Fetch and decode the next Parquet row group
+    async fn read_parquet_row_group(&mut self) -> SourceResult<Option<DataFrame>> {
+        let start = Instant::now();
+        
+        if self.parquet.is_none() {
+            self.parquet = Some(self.fetch_parquet_footer().await?);
+        }
+        let footer = self.parquet.as_ref().expect("footer fetched above");
+        
+        let idx = footer.next_row_group;
+        let Some(row_group) = footer.metadata.row_groups.get(idx) else {
+            self.exhausted = true;
+            return Ok(None);
+        };
+        
+        // Column chunks of a row group are contiguous; fetch them in one GET
+        let (begin, end) = row_group.byte_ranges_iter()
+            .fold((u64::MAX, 0), |(lo, hi), range| (lo.min(range.start), hi.max(range.end)));
+        let num_rows = row_group.num_rows();
+        let rows_before = footer.rows_before;
+        let metadata = footer.metadata.clone();
+        let footer_bytes = footer.bytes.clone();
+        
+        let data = self.fetch_range(begin, end).await?;
+        self.stats.bytes_read += data.len() as u64;
+        
+        let object = RangedObject::new(
+            self.total_size.unwrap_or_default(),
+            vec![(begin, data), footer_bytes],
+        );
+        let mut reader = ParquetReader::new(object);
+        reader.set_metadata(metadata);
+        let df = reader
+            .with_slice(Some((rows_before, num_rows)))
+            .finish()
+            .map_err(|e| SourceError::PolarsError(e.to_string()))?;
+        
+        let footer = self.parquet.as_mut().expect("footer fetched above");
+        footer.next_row_group += 1;
+        footer.rows_before += num_rows;
+        if footer.next_row_group >= footer.metadata.row_groups.len() {
+            self.exhausted = true;
+        }
+        
+        self.stats.records_processed += df.height();
+        self.stats.chunks_read += 1;
+        self.stats.avg_chunk_time_ms = 
+            (self.stats.avg_chunk_time_ms * (self.stats.chunks_read - 1) as f64 
+            + start.elapsed().as_millis() as f64) / self.stats.chunks_read as f64;
+        self.stats.memory_bytes = df.estimated_size() as u64;
+        
+        if self.schema.is_none() {
+            self.schema = Some(Arc::new(df.schema()));
+        }
+        
+        Ok(Some(df))
+    }
+    
+    /// Read the next chunk, moving on to the next object when one runs out
+    async fn next_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
+        loop {
+            if let Some(df) = self.download_chunk().await? {
+                return self.unify_schema(df).map(Some);
+            }
+            
+            if !self.exhausted {
+                continue; // Partial record buffered, fetch more
+            }
+            if self.object_idx + 1 >= self.objects.len() {
+                return Ok(None);
+            }
+            
+            self.select_object(self.object_idx + 1);
+            tracing::debug!("Advancing to s3://{}/{}", self.bucket, self.key);
+        }
+    }
+    
     async fn download_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
         if self.exhausted {
             return Ok(None);
         }
         
+        if let FileFormat::Parquet = self.format() {
+            if self.decoder.is_some() {
+                // Row groups can't be located inside a compressed stream
+                return Err(SourceError::UnsupportedOperation(
+                    "Streaming compressed Parquet objects".to_string()
+                ));
+            }
+            return self.read_parquet_row_group().await;
+        }
+        
         let start = Instant::now();
         
         // Calculate byte range
@@ -124,10 +411,16 @@ impl S3Source {
             5 * 1024 * 1024 // 5MB max
         );
         
+        // Ranges can only be split up front when the object is seekable
+        let concurrency = match (self.total_size, &self.decoder) {
+            (Some(_), None) => self.concurrency as u64,
+            _ => 1,
+        };
+        let span = chunk_bytes as u64 * concurrency;
         let range_end = if let Some(total) = self.total_size {
-            std::cmp::min(self.offset + chunk_bytes as u64, total)
+            std::cmp::min(self.offset + span, total)
         } else {
-            self.offset + chunk_bytes as u64
+            self.offset + span
         };
         
         if let Some(total) = self.total_size {
@@ -137,22 +430,12 @@ impl S3Source {
             }
         }
         
-        let range = format!("bytes={}-{}", self.offset, range_end - 1);
-        
         // Download chunk from S3
-        let response = self.client.get_object()
-            .bucket(&self.bucket)
-            .key(&self.key)
-            .range(range)
-            .send()
-            .await
-            .map_err(|e| SourceError::CloudError(format!("S3 GetObject failed: {}", e)))?;
-        
-        // Read response body
-        let body = response.body.collect().await
-            .map_err(|e| SourceError::CloudError(format!("Failed to read S3 response: {}", e)))?;
-        
-        let bytes = body.into_bytes();
+        let bytes = if concurrency > 1 {
+            self.fetch_range_parallel(self.offset, range_end, chunk_bytes as u64).await?
+        } else {
+            self.fetch_range(self.offset, range_end).await?
+        };
         let bytes_read = bytes.len();
         
         if bytes_read == 0 {
@@ -163,8 +446,19 @@ impl S3Source {
         self.stats.bytes_read += bytes_read as u64;
         self.offset += bytes_read as u64;
         
-        // Append to buffer
-        self.buffer.extend_from_slice(&bytes);
+        // Append to buffer, decompressing first if needed. Ranges are still
+        // fetched in order, so the decoder sees one continuous stream.
+        match self.decoder.as_mut() {
+            Some(decoder) => {
+                let at_end = self.total_size.is_some_and(|total| self.offset >= total);
+                let decoded = decoder.decode(&bytes, at_end)
+                    .map_err(|e| SourceError::ParseError(format!(
+                        "Failed to decompress s3://{}/{}: {}", self.bucket, self.key, e
+                    )))?;
+                self.buffer.extend_from_slice(&decoded);
+            }
+            None => self.buffer.extend_from_slice(&bytes),
+        }
         
         // Try to parse complete records
         let df = self.parse_buffer()?;
@@ -177,10 +471,10 @@ impl S3Source {
                 + start.elapsed().as_millis() as f64) / self.stats.chunks_read as f64;
             
             if self.schema.is_none() {
-                self.schema = Some(df.schema());
+                self.schema = Some(Arc::new(df.schema()));
             }
             
-            self.stats.memory_bytes = df.estimated_size() + self.buffer.len();
+            self.stats.memory_bytes = (df.estimated_size() + self.buffer.len()) as u64;
         }
         
         // Check if we've reached the end
@@ -198,16 +492,7 @@ impl S3Source {
             return Ok(None);
         }
         
-        // Detect format (CSV, Parquet, JSON)
-        let format = if self.key.ends_with(".parquet") {
-            FileFormat::Parquet
-        } else if self.key.ends_with(".json") {
-            FileFormat::Json
-        } else {
-            FileFormat::Csv
-        };
-        
-        match format {
+        match self.format() {
             FileFormat::Csv => {
                 // Find last complete line
                 let last_newline = self.buffer.iter().rposition(|&b| b == b'\n')
@@ -219,10 +504,20 @@ impl S3Source {
                 
                 let complete_data = &self.buffer[..last_newline];
                 
-                let df = CsvReader::new(std::io::Cursor::new(complete_data))
-                    .has_header(self.schema.is_none())
+                // Every object starts with its own header. Its later chunks
+                // are parsed with that object's columns, not the schema
+                // unified across objects, which may order or type them
+                // differently.
+                let has_header = self.csv_schema.is_none();
+                let df = CsvReadOptions::default()
+                    .with_has_header(has_header)
+                    .with_schema(self.csv_schema.clone())
+                    .into_reader_with_file_handle(std::io::Cursor::new(complete_data))
                     .finish()
                     .map_err(|e| SourceError::PolarsError(e.to_string()))?;
+                if has_header {
+                    self.csv_schema = Some(Arc::new(df.schema()));
+                }
                 
                 // Remove processed data from buffer
                 self.buffer.drain(..last_newline + 1);
@@ -230,16 +525,8 @@ impl S3Source {
                 Ok(Some(df))
             },
             FileFormat::Parquet => {
-                // For Parquet, we need the complete file
-                // This is a simplified implementation
-                let df = ParquetReader::new(std::io::Cursor::new(&self.buffer))
-                    .finish()
-                    .map_err(|e| SourceError::PolarsError(e.to_string()))?;
-                
-                self.buffer.clear();
-                self.exhausted = true;
-                
-                Ok(Some(df))
+                // Parquet is streamed by row group and never buffered
+                Err(SourceError::UnsupportedOperation("Buffered Parquet parsing".to_string()))
             },
             FileFormat::Json => {
                 // Try to parse JSON lines
@@ -264,6 +551,242 @@ enum FileFormat {
     Json,
 }
 
+/// Ranged GETs against a bucket
+///
+/// Implemented by the S3 `Client`; a trait so the retry loop can be
+/// exercised without a live endpoint.
+#[async_trait]
+trait RangeFetcher: std::fmt::Debug + Send + Sync {
+    /// Fetch bytes `[start, end)` of `bucket/key`
+    async fn get_range(&self, bucket: &str, key: &str, start: u64, end: u64) -> Result<Bytes, FetchError>;
+}
+
+/// A failed ranged GET
+#[derive(Debug)]
+struct FetchError {
+    message: String,
+    /// Worth retrying: timeouts, connection failures, 5xx and throttling
+    transient: bool,
+}
+
+#[async_trait]
+impl RangeFetcher for Client {
+    async fn get_range(&self, bucket: &str, key: &str, start: u64, end: u64) -> Result<Bytes, FetchError> {
+        let response = self.get_object()
+            .bucket(bucket)
+            .key(key)
+            .range(format!("bytes={}-{}", start, end - 1))
+            .send()
+            .await
+            .map_err(|e| FetchError {
+                transient: is_transient(&e),
+                message: format!("S3 GetObject failed: {}", e),
+            })?;
+        
+        // A body cut off mid-transfer is a network error, so retry it too
+        let body = response.body.collect().await
+            .map_err(|e| FetchError {
+                transient: true,
+                message: format!("Failed to read S3 response: {}", e),
+            })?;
+        
+        Ok(body.into_bytes())
+    }
+}
+
+/// Whether a GetObject failure is likely to succeed on retry
+fn is_transient(err: &SdkError<GetObjectError, HttpResponse>) -> bool {
+    match err {
+        SdkError::TimeoutError(_) | SdkError::DispatchFailure(_) | SdkError::ResponseError(_) => true,
+        SdkError::ServiceError(service) => {
+            let status = service.raw().status().as_u16();
+            status == 429
+                || status >= 500
+                || matches!(
+                    err.code(),
+                    Some("SlowDown" | "Throttling" | "ThrottlingException" | "RequestTimeout")
+                )
+        }
+        _ => false,
+    }
+}
+
+/// Retry settings for ranged GETs
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    /// Retries after the first attempt
+    max_retries: u32,
+    /// Delay cap of the first retry, doubled for each one after
+    base_delay_ms: u64,
+}
+
+impl RetryPolicy {
+    /// Read `max_retries` (default 3) and `base_delay_ms` (default 100)
+    fn from_options(options: &HashMap<String, String>) -> Self {
+        Self {
+            max_retries: options.get("max_retries")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(3),
+            base_delay_ms: options.get("base_delay_ms")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(100),
+        }
+    }
+    
+    /// Delay before retry number `attempt` (0-based)
+    ///
+    /// Uniform in the upper half of the exponential cap, so concurrent
+    /// readers that failed together don't retry in lockstep.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let cap = self.base_delay_ms.saturating_mul(1 << attempt.min(16));
+        let jitter = RandomState::new().build_hasher().finish() % (cap / 2 + 1);
+        Duration::from_millis(cap - cap / 2 + jitter)
+    }
+}
+
+/// Fetch `[start, end)`, retrying transient failures with jittered backoff
+async fn fetch_with_retry(
+    fetcher: &dyn RangeFetcher,
+    policy: RetryPolicy,
+    bucket: &str,
+    key: &str,
+    start: u64,
+    end: u64,
+) -> SourceResult<Bytes> {
+    let mut attempt = 0;
+    loop {
+        match fetcher.get_range(bucket, key, start, end).await {
+            Ok(bytes) => return Ok(bytes),
+            Err(e) if e.transient && attempt < policy.max_retries => {
+                let delay = policy.backoff(attempt);
+                tracing::debug!(
+                    "Retrying s3://{}/{} bytes {}-{} in {:?}: {}",
+                    bucket, key, start, end, delay, e.message
+                );
+                sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) if e.transient => {
+                return Err(SourceError::CloudError(format!(
+                    "{} (gave up after {} retries)", e.message, attempt
+                )));
+            }
+            Err(e) => return Err(SourceError::CloudError(e.message)),
+        }
+    }
+}
+
+/// Streaming decompressor fed with consecutive ranges of an object
+enum Decoder {
+    Gzip(flate2::write::GzDecoder<Vec<u8>>),
+    Zstd(zstd::stream::write::Decoder<'static, Vec<u8>>),
+}
+
+impl std::fmt::Debug for Decoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Decoder::Gzip(_) => f.write_str("Decoder::Gzip"),
+            Decoder::Zstd(_) => f.write_str("Decoder::Zstd"),
+        }
+    }
+}
+
+impl Decoder {
+    /// Pick a decoder from the key suffix, as `FilesystemSource` does for paths
+    fn for_key(key: &str) -> SourceResult<Option<Self>> {
+        let compression = if key.ends_with(".gz") {
+            CompressionType::Gzip
+        } else if key.ends_with(".zst") {
+            CompressionType::Zstd
+        } else {
+            CompressionType::None
+        };
+        
+        Ok(match compression {
+            CompressionType::Gzip => Some(Decoder::Gzip(flate2::write::GzDecoder::new(Vec::new()))),
+            CompressionType::Zstd => Some(Decoder::Zstd(
+                zstd::stream::write::Decoder::new(Vec::new())
+                    .map_err(|e| SourceError::Config(format!("Zstd decoder error: {}", e)))?
+            )),
+            CompressionType::None => None,
+        })
+    }
+    
+    /// Feed compressed bytes and take whatever has been decompressed so far
+    fn decode(&mut self, compressed: &[u8], at_end: bool) -> std::io::Result<Vec<u8>> {
+        match self {
+            Decoder::Gzip(decoder) => {
+                decoder.write_all(compressed)?;
+                if at_end {
+                    decoder.try_finish()?;
+                }
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Decoder::Zstd(decoder) => {
+                decoder.write_all(compressed)?;
+                decoder.flush()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+        }
+    }
+}
+
+/// Read-only view of an object of which only some byte ranges were fetched
+///
+/// Lets the Parquet reader address the footer and row group data at their
+/// real offsets without downloading the rest of the object.
+struct RangedObject {
+    len: u64,
+    ranges: Vec<(u64, Bytes)>,
+    position: u64,
+}
+
+impl RangedObject {
+    fn new(len: u64, ranges: Vec<(u64, Bytes)>) -> Self {
+        Self { len, ranges, position: 0 }
+    }
+}
+
+impl Read for RangedObject {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.position >= self.len || buf.is_empty() {
+            return Ok(0);
+        }
+        
+        let position = self.position;
+        let (start, bytes) = self.ranges.iter()
+            .find(|(start, bytes)| position >= *start && position < start + bytes.len() as u64)
+            .ok_or_else(|| std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Byte {} was not fetched", position),
+            ))?;
+        
+        let available = &bytes[(position - start) as usize..];
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+impl Seek for RangedObject {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        
+        self.position = position.ok_or_else(|| std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Seek before start of object",
+        ))?;
+        Ok(self.position)
+    }
+}
+
+impl MmapBytesReader for RangedObject {}
+
 #[async_trait]
 impl StreamingSource for S3Source {
     async fn metadata(&self) -> SourceResult<SourceMetadata> {
@@ -271,13 +794,19 @@ impl StreamingSource for S3Source {
             size_bytes: self.total_size,
             num_records: None,
             schema: self.schema.clone(),
-            seekable: true,
+            // A compressed stream isn't randomly seekable
+            seekable: self.decoder.is_none(),
             parallelizable: false,
         })
     }
     
     async fn read_chunk(&mut self) -> SourceResult<Option<DataFrame>> {
-        self.download_chunk().await
+        self.stats.mark_started();
+        let span = chunk_span("s3");
+        let bytes_before = self.stats.bytes_read;
+        let result = self.next_chunk().instrument(span.clone()).await;
+        record_chunk(&span, &result, self.stats.bytes_read - bytes_before);
+        result
     }
     
     fn stats(&self) -> StreamingStats {
@@ -285,14 +814,29 @@ impl StreamingSource for S3Source {
     }
     
     async fn reset(&mut self) -> SourceResult<()> {
-        self.offset = 0;
-        self.buffer.clear();
-        self.exhausted = false;
+        if self.object_idx == 0 {
+            self.offset = 0;
+            self.buffer.clear();
+            self.csv_schema = None;
+            if let Some(footer) = self.parquet.as_mut() {
+                footer.next_row_group = 0;
+                footer.rows_before = 0;
+            }
+            self.exhausted = self.objects.is_empty();
+        } else {
+            self.select_object(0);
+        }
         self.stats = StreamingStats::default();
         Ok(())
     }
     
     async fn seek(&mut self, position: u64) -> SourceResult<()> {
+        if self.decoder.is_some() {
+            return Err(SourceError::UnsupportedOperation(
+                "Seek on compressed S3 objects".to_string()
+            ));
+        }
+        
         self.offset = position;
         self.buffer.clear();
         Ok(())
@@ -305,24 +849,61 @@ impl StreamingSource for S3Source {
     }
     
     fn has_more(&self) -> bool {
-        !self.exhausted
+        !self.exhausted || self.object_idx + 1 < self.objects.len()
+    }
+}
+
+/// List every object under `prefix` in lexical key order
+async fn list_objects(client: &Client, bucket: &str, prefix: &str) -> SourceResult<Vec<(String, Option<u64>)>> {
+    let mut objects = Vec::new();
+    let mut pages = client.list_objects_v2()
+        .bucket(bucket)
+        .prefix(prefix)
+        .into_paginator()
+        .send();
+    
+    while let Some(page) = pages.next().await {
+        let page = page
+            .map_err(|e| SourceError::CloudError(format!("S3 ListObjectsV2 failed: {}", e)))?;
+        
+        for object in page.contents() {
+            match object.key() {
+                // Skip "directory" placeholder keys
+                Some(key) if !key.ends_with('/') => {
+                    objects.push((key.to_string(), object.size().map(|s| s as u64)));
+                }
+                _ => {}
+            }
+        }
     }
+    
+    objects.sort();
+    Ok(objects)
 }
 
 pub struct S3SourceFactory;
 
 impl super::SourceFactory for S3SourceFactory {
     fn create(&self, config: super::SourceConfig) -> super::SourceResult<Box<dyn super::StreamingSource>> {
-        // S3Source::new is async, need runtime
-        let rt = tokio::runtime::Runtime::new()
-            .map_err(|e| super::SourceError::Config(format!("Failed to create runtime: {}", e)))?;
-        Ok(Box::new(rt.block_on(S3Source::new(config))?))
+        // S3Source::new is async, drive it on the shared runtime
+        Ok(Box::new(super::block_on(S3Source::new(config))??))
+    }
+    
+    fn create_async(
+        &self,
+        config: super::SourceConfig,
+    ) -> futures::future::BoxFuture<'_, super::SourceResult<Box<dyn super::StreamingSource>>> {
+        Box::pin(async move {
+            Ok(Box::new(S3Source::new(config).await?) as Box<dyn super::StreamingSource>)
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "localstack")]
+    use aws_sdk_s3::primitives::ByteStream;
     
     #[test]
     fn test_s3_uri_parsing() {
@@ -331,4 +912,379 @@ mod tests {
         // Just testing URI parsing logic
         assert!(config.location.starts_with("s3://"));
     }
+    
+    #[test]
+    fn test_ranged_object_reads_fetched_ranges_only() {
+        let mut object = RangedObject::new(100, vec![
+            (10, Bytes::from_static(b"hello")),
+            (92, Bytes::from_static(b"footPAR1")),
+        ]);
+        
+        let mut buf = [0u8; 8];
+        object.seek(SeekFrom::End(-8)).unwrap();
+        object.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"footPAR1");
+        
+        object.seek(SeekFrom::Start(12)).unwrap();
+        let mut buf = [0u8; 3];
+        object.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"llo");
+        
+        object.seek(SeekFrom::Start(50)).unwrap();
+        assert!(object.read(&mut buf).is_err());
+    }
+    
+    #[test]
+    fn test_decoder_streams_gzip_in_pieces() {
+        let csv = (0..1000).map(|i| format!("{},{}\n", i, i * 2)).collect::<String>();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(csv.as_bytes()).unwrap();
+        let compressed = encoder.finish().unwrap();
+        
+        let mut decoder = Decoder::for_key("daily/trades.csv.gz").unwrap().unwrap();
+        let pieces: Vec<&[u8]> = compressed.chunks(100).collect();
+        let mut decoded = Vec::new();
+        for (i, piece) in pieces.iter().enumerate() {
+            decoded.extend(decoder.decode(piece, i + 1 == pieces.len()).unwrap());
+        }
+        
+        assert_eq!(decoded, csv.as_bytes());
+        assert!(Decoder::for_key("daily/trades.csv").unwrap().is_none());
+    }
+    
+    /// Fails with the given errors in turn, then returns the requested range
+    #[derive(Debug)]
+    struct FlakyFetcher {
+        failures: std::sync::Mutex<Vec<FetchError>>,
+        calls: std::sync::atomic::AtomicUsize,
+    }
+    
+    impl FlakyFetcher {
+        fn new(failures: Vec<FetchError>) -> Self {
+            Self {
+                failures: std::sync::Mutex::new(failures),
+                calls: std::sync::atomic::AtomicUsize::new(0),
+            }
+        }
+        
+        fn calls(&self) -> usize {
+            self.calls.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+    
+    #[async_trait]
+    impl RangeFetcher for FlakyFetcher {
+        async fn get_range(&self, _bucket: &str, _key: &str, start: u64, end: u64) -> Result<Bytes, FetchError> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let mut failures = self.failures.lock().unwrap();
+            if failures.is_empty() {
+                Ok(Bytes::from((start..end).map(|b| b as u8).collect::<Vec<_>>()))
+            } else {
+                Err(failures.remove(0))
+            }
+        }
+    }
+    
+    fn flaky(transient: bool) -> FetchError {
+        FetchError { message: "503 Slow Down".to_string(), transient }
+    }
+    
+    #[tokio::test]
+    async fn test_fetch_retries_transient_errors() {
+        let policy = RetryPolicy { max_retries: 3, base_delay_ms: 1 };
+        
+        let fetcher = FlakyFetcher::new(vec![flaky(true), flaky(true)]);
+        let bytes = fetch_with_retry(&fetcher, policy, "bucket", "key", 4, 8).await.unwrap();
+        assert_eq!(&bytes[..], &[4, 5, 6, 7]);
+        assert_eq!(fetcher.calls(), 3);
+        
+        // Permanent errors such as AccessDenied fail on the first attempt
+        let fetcher = FlakyFetcher::new(vec![flaky(false)]);
+        let result = fetch_with_retry(&fetcher, policy, "bucket", "key", 0, 4).await;
+        assert!(matches!(result, Err(SourceError::CloudError(_))));
+        assert_eq!(fetcher.calls(), 1);
+        
+        let fetcher = FlakyFetcher::new((0..5).map(|_| flaky(true)).collect());
+        let result = fetch_with_retry(&fetcher, policy, "bucket", "key", 0, 4).await;
+        assert!(matches!(result, Err(SourceError::CloudError(msg)) if msg.contains("after 3 retries")));
+        assert_eq!(fetcher.calls(), 4);
+    }
+    
+    /// Serves objects from memory
+    #[derive(Debug)]
+    struct MemoryFetcher(HashMap<String, Bytes>);
+    
+    #[async_trait]
+    impl RangeFetcher for MemoryFetcher {
+        async fn get_range(&self, _bucket: &str, key: &str, start: u64, end: u64) -> Result<Bytes, FetchError> {
+            let object = &self.0[key];
+            Ok(object.slice(start as usize..(end as usize).min(object.len())))
+        }
+    }
+    
+    /// Source over in-memory objects, read in `memory_limit / 10` byte chunks
+    fn memory_source(objects: Vec<(&str, String)>, memory_limit: usize) -> S3Source {
+        let listing = objects.iter()
+            .map(|(key, body)| (key.to_string(), Some(body.len() as u64)))
+            .collect::<Vec<_>>();
+        let bodies = objects.into_iter()
+            .map(|(key, body)| (key.to_string(), Bytes::from(body)))
+            .collect();
+        
+        let mut source = S3Source {
+            fetcher: Arc::new(MemoryFetcher(bodies)),
+            retry: RetryPolicy { max_retries: 0, base_delay_ms: 1 },
+            bucket: "bucket".to_string(),
+            key: String::new(),
+            objects: listing,
+            object_idx: 0,
+            memory_limit,
+            concurrency: 1,
+            offset: 0,
+            total_size: None,
+            buffer: Vec::new(),
+            exhausted: false,
+            csv_schema: None,
+            decoder: None,
+            stats: StreamingStats::default(),
+            schema: None,
+            parquet: None,
+        };
+        source.select_object(0);
+        source
+    }
+    
+    #[tokio::test]
+    async fn test_csv_objects_keep_their_own_columns() {
+        // The second object orders its columns differently and adds one
+        let a = (0..2_000).map(|i| format!("{},a{}\n", i, i)).collect::<String>();
+        let b = (0..2_000).map(|i| format!("b{},{},{}\n", i, i, 2 * i)).collect::<String>();
+        let mut source = memory_source(
+            vec![("parts/a.csv", format!("id,name\n{}", a)), ("parts/b.csv", format!("name,id,score\n{}", b))],
+            20_000,
+        );
+        
+        // 2KB chunks, so most chunks of each object have no header
+        let mut chunks = Vec::new();
+        while let Some(chunk) = source.read_chunk().await.unwrap() {
+            chunks.push(chunk);
+        }
+        
+        // Columns only grow, so the first object's chunks lack `score`
+        assert!(chunks.len() > 4);
+        let df = polars::functions::concat_df_diagonal(&chunks).unwrap();
+        assert_eq!(df.get_column_names(), &["id", "name", "score"]);
+        assert_eq!(df.height(), 4_000);
+        let ids = df.column("id").unwrap().i64().unwrap();
+        let names = df.column("name").unwrap().str().unwrap();
+        let scores = df.column("score").unwrap().i64().unwrap();
+        assert_eq!(ids.get(3_999), Some(1_999));
+        assert_eq!(names.get(3_999), Some("b1999"));
+        assert_eq!(scores.get(3_999), Some(3_998));
+        assert_eq!(scores.null_count(), 2_000);
+    }
+    
+    #[test]
+    fn test_retry_policy_from_options() {
+        let config = SourceConfig::new("s3://bucket/key.csv")
+            .with_option("max_retries", "5")
+            .with_option("base_delay_ms", "20");
+        let policy = RetryPolicy::from_options(&config.options);
+        assert_eq!(policy.max_retries, 5);
+        
+        for attempt in 0..3 {
+            let cap = 20 << attempt;
+            let delay = policy.backoff(attempt).as_millis() as u64;
+            assert!(delay >= cap / 2 && delay <= cap, "attempt {}: {}ms", attempt, delay);
+        }
+    }
+    
+    /// Client and fresh bucket on LocalStack
+    #[cfg(feature = "localstack")]
+    async fn localstack_bucket(name: &str) -> (Client, String) {
+        let aws_config = aws_config::defaults(BehaviorVersion::latest()).load().await;
+        let client = Client::from_conf(
+            aws_sdk_s3::config::Builder::from(&aws_config).force_path_style(true).build()
+        );
+        let bucket = format!("polarway-{}-{}", name, std::process::id());
+        client.create_bucket().bucket(&bucket).send().await.unwrap();
+        (client, bucket)
+    }
+    
+    /// Requires a LocalStack endpoint, e.g. `AWS_ENDPOINT_URL=http://localhost:4566`
+    #[cfg(feature = "localstack")]
+    #[tokio::test]
+    async fn test_parquet_streams_row_groups() {
+        let (client, bucket) = localstack_bucket("row-groups").await;
+        
+        let mut df = df!(
+            "id" => (0..10_000i64).collect::<Vec<_>>(),
+            "value" => (0..10_000).map(|i| i as f64 * 0.5).collect::<Vec<_>>(),
+        ).unwrap();
+        let mut buffer = Vec::new();
+        ParquetWriter::new(&mut buffer)
+            .with_row_group_size(Some(2_500))
+            .finish(&mut df)
+            .unwrap();
+        client.put_object()
+            .bucket(&bucket)
+            .key("data.parquet")
+            .body(ByteStream::from(buffer))
+            .send()
+            .await
+            .unwrap();
+        
+        let config = SourceConfig::new(format!("s3://{}/data.parquet", bucket));
+        let mut source = S3Source::new(config).await.unwrap();
+        
+        let mut chunks = Vec::new();
+        while let Some(chunk) = source.read_chunk().await.unwrap() {
+            chunks.push(chunk);
+        }
+        
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.iter().map(|c| c.height()).sum::<usize>(), 10_000);
+        assert_eq!(chunks[1].column("id").unwrap().i64().unwrap().get(0), Some(2_500));
+    }
+    
+    #[cfg(feature = "localstack")]
+    #[tokio::test]
+    async fn test_prefix_streams_all_objects() {
+        let (client, bucket) = localstack_bucket("prefix").await;
+        
+        // Uploaded out of order, with a marker object that must be skipped
+        for (name, rows) in [("c.csv", 30), ("a.csv", 10), ("b.csv", 20)] {
+            let mut body = String::from("id,name\n");
+            for i in 0..rows {
+                body.push_str(&format!("{},{}\n", i, name));
+            }
+            client.put_object()
+                .bucket(&bucket)
+                .key(format!("daily/{}", name))
+                .body(ByteStream::from(body.into_bytes()))
+                .send()
+                .await
+                .unwrap();
+        }
+        client.put_object()
+            .bucket(&bucket)
+            .key("daily/_SUCCESS")
+            .body(ByteStream::from_static(b""))
+            .send()
+            .await
+            .unwrap();
+        
+        let config = SourceConfig::new(format!("s3://{}/daily/", bucket));
+        let mut source = S3Source::new(config).await.unwrap().with_suffix_filter(".csv");
+        
+        let mut names = Vec::new();
+        while let Some(chunk) = source.read_chunk().await.unwrap() {
+            assert_eq!(chunk.get_column_names(), &["id", "name"]);
+            names.extend(
+                chunk.column("name").unwrap().str().unwrap()
+                    .into_no_null_iter()
+                    .map(|s| s.to_string())
+            );
+        }
+        
+        assert_eq!(names.len(), 60);
+        assert!(names[..10].iter().all(|n| n == "a.csv"));
+        assert!(names[30..].iter().all(|n| n == "c.csv"));
+        assert!(!source.has_more());
+    }
+    
+    #[cfg(feature = "localstack")]
+    #[tokio::test]
+    async fn test_parallel_download_matches_sequential() {
+        let (client, bucket) = localstack_bucket("parallel").await;
+        
+        let csv = std::iter::once("id,value\n".to_string())
+            .chain((0..20_000).map(|i| format!("{},{}\n", i, i * 3)))
+            .collect::<String>();
+        client.put_object()
+            .bucket(&bucket)
+            .key("data.csv")
+            .body(ByteStream::from(csv.clone().into_bytes()))
+            .send()
+            .await
+            .unwrap();
+        
+        // 10% of the memory limit gives 20KB parts, so the object spans many
+        let location = format!("s3://{}/data.csv", bucket);
+        let config = || SourceConfig::new(&location).with_memory_limit(200_000);
+        
+        let source = S3Source::new(config()).await.unwrap().parallel(4);
+        let total = csv.len() as u64;
+        let parallel = source.fetch_range_parallel(0, total, 20_000).await.unwrap();
+        assert_eq!(parallel, source.fetch_range(0, total).await.unwrap());
+        assert_eq!(&parallel[..], csv.as_bytes());
+        
+        async fn read_all(mut source: S3Source) -> (usize, DataFrame) {
+            let mut chunks = 0;
+            let mut df = DataFrame::empty();
+            while let Some(chunk) = source.read_chunk().await.unwrap() {
+                chunks += 1;
+                df = if df.is_empty() { chunk } else { df.vstack(&chunk).unwrap() };
+            }
+            (chunks, df)
+        }
+        let (sequential_chunks, expected) = read_all(S3Source::new(config()).await.unwrap()).await;
+        let (parallel_chunks, actual) = read_all(S3Source::new(config()).await.unwrap().parallel(4)).await;
+        
+        assert!(parallel_chunks < sequential_chunks);
+        assert_eq!(actual.height(), 20_000);
+        assert!(actual.equals(&expected));
+    }
+    
+    #[cfg(feature = "localstack")]
+    #[tokio::test]
+    async fn test_gzip_csv_object() {
+        let (client, bucket) = localstack_bucket("gzip").await;
+        
+        let mut csv = String::from("id,price\n");
+        for i in 0..5_000 {
+            csv.push_str(&format!("{},{}.25\n", i, i));
+        }
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(csv.as_bytes()).unwrap();
+        client.put_object()
+            .bucket(&bucket)
+            .key("trades.csv.gz")
+            .body(ByteStream::from(encoder.finish().unwrap()))
+            .send()
+            .await
+            .unwrap();
+        
+        let config = SourceConfig::new(format!("s3://{}/trades.csv.gz", bucket));
+        let mut source = S3Source::new(config).await.unwrap();
+        assert!(!source.metadata().await.unwrap().seekable);
+        assert!(source.seek(10).await.is_err());
+        
+        let mut rows = 0;
+        while let Some(chunk) = source.read_chunk().await.unwrap() {
+            assert_eq!(chunk.get_column_names(), &["id", "price"]);
+            assert_eq!(chunk.column("price").unwrap().dtype(), &DataType::Float64);
+            rows += chunk.height();
+        }
+        assert_eq!(rows, 5_000);
+    }
+    
+    #[cfg(feature = "localstack")]
+    #[tokio::test]
+    async fn test_factory_create_async_inside_runtime() {
+        use crate::sources::SourceFactory;
+        
+        let (client, bucket) = localstack_bucket("factory").await;
+        client.put_object()
+            .bucket(&bucket)
+            .key("data.csv")
+            .body(ByteStream::from_static(b"a,b\n1,2\n"))
+            .send()
+            .await
+            .unwrap();
+        
+        let config = SourceConfig::new(format!("s3://{}/data.csv", bucket));
+        let mut source = S3SourceFactory.create_async(config).await.unwrap();
+        assert_eq!(source.read_chunk().await.unwrap().unwrap().height(), 1);
+    }
 }
@@ -0,0 +1,38 @@
+//! Tracing spans around source reads
+//!
+//! With the `otel` feature every `read_chunk` runs inside a `read_chunk`
+//! span carrying `source`, `rows` and `bytes`, ready for export by an
+//! OpenTelemetry layer such as `tracing-opentelemetry`. Without it the
+//! spans are disabled and cost nothing.
+
+use polars::prelude::*;
+use tracing::Span;
+
+use super::SourceResult;
+
+/// Span for one chunk read from `source`, e.g. `"s3"`
+#[cfg(feature = "otel")]
+pub(crate) fn chunk_span(source: &'static str) -> Span {
+    tracing::info_span!(
+        "read_chunk",
+        source,
+        rows = tracing::field::Empty,
+        bytes = tracing::field::Empty,
+    )
+}
+
+#[cfg(not(feature = "otel"))]
+pub(crate) fn chunk_span(_source: &'static str) -> Span {
+    Span::none()
+}
+
+/// Record the outcome of a read on its span
+///
+/// `rows` is only set when a chunk was produced, so end-of-stream reads
+/// don't show up as empty chunks.
+pub(crate) fn record_chunk(span: &Span, result: &SourceResult<Option<DataFrame>>, bytes: u64) {
+    if let Ok(Some(df)) = result {
+        span.record("rows", df.height() as u64);
+    }
+    span.record("bytes", bytes);
+}
@@ -1,11 +1,13 @@
 //! Core traits for streaming sources
 
 use async_trait::async_trait;
+use futures::future::BoxFuture;
 use polars::prelude::*;
-use std::sync::Arc;
 
 use super::{SourceConfig, SourceError, SourceResult};
 
+pub use crate::stats::StreamingStats;
+
 /// Metadata about a streaming source
 #[derive(Debug, Clone)]
 pub struct SourceMetadata {
@@ -21,21 +23,6 @@ pub struct SourceMetadata {
     pub parallelizable: bool,
 }
 
-/// Statistics about streaming progress
-#[derive(Debug, Clone, Default)]
-pub struct StreamingStats {
-    /// Bytes read so far
-    pub bytes_read: u64,
-    /// Records processed
-    pub records_processed: usize,
-    /// Number of chunks read
-    pub chunks_read: usize,
-    /// Current memory usage
-    pub memory_bytes: u64,
-    /// Average chunk processing time (ms)
-    pub avg_chunk_time_ms: f64,
-}
-
 /// Core trait for all streaming sources
 #[async_trait]
 pub trait StreamingSource: Send + Sync {
@@ -70,6 +57,14 @@ pub trait StreamingSource: Send + Sync {
 /// Factory trait for creating sources
 pub trait SourceFactory: Send + Sync {
     fn create(&self, config: SourceConfig) -> SourceResult<Box<dyn StreamingSource>>;
+    
+    /// Create a source from within an async context
+    ///
+    /// The default runs `create` inline. Sources whose constructor is async
+    /// override this so callers already on a Tokio runtime don't block it.
+    fn create_async(&self, config: SourceConfig) -> BoxFuture<'_, SourceResult<Box<dyn StreamingSource>>> {
+        Box::pin(async move { self.create(config) })
+    }
 }
 
 /// Builder pattern for configuring adaptive streaming
@@ -113,10 +108,15 @@ impl AdaptiveStreamBuilder {
     }
     
     pub async fn collect(mut self) -> SourceResult<DataFrame> {
-        let mut frames = Vec::new();
+        let mut combined: Option<DataFrame> = None;
         
         while let Some(chunk) = self.source.read_chunk().await? {
-            frames.push(chunk);
+            match &mut combined {
+                Some(df) => {
+                    df.vstack_mut(&chunk)?;
+                }
+                None => combined = Some(chunk),
+            }
             
             // Check memory limit
             if let Some(limit) = self.memory_limit {
@@ -127,13 +127,9 @@ impl AdaptiveStreamBuilder {
             }
         }
         
-        if frames.is_empty() {
-            return Err(SourceError::EmptySource);
-        }
-        
-        // Concatenate all frames
-        polars::functions::concat_df(&frames)
-            .map_err(|e| SourceError::PolarsError(e.to_string()))
+        let mut df = combined.ok_or(SourceError::EmptySource)?;
+        df.align_chunks();
+        Ok(df)
     }
 }
 
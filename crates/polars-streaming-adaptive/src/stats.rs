@@ -0,0 +1,112 @@
+//! Streaming statistics shared by readers and sources
+
+use std::time::{Duration, Instant};
+
+/// Statistics about streaming progress
+#[derive(Debug, Clone, Default)]
+pub struct StreamingStats {
+    /// Bytes read so far
+    pub bytes_read: u64,
+    /// Records processed
+    pub records_processed: usize,
+    /// Number of chunks read
+    pub chunks_read: usize,
+    /// Current memory usage
+    pub memory_bytes: u64,
+    /// Average chunk processing time (ms)
+    pub avg_chunk_time_ms: f64,
+    /// When the first chunk started reading, `None` before any read
+    pub started_at: Option<Instant>,
+}
+
+impl StreamingStats {
+    /// Start the throughput clock, if not already running
+    ///
+    /// Called when a chunk read begins, so the clock covers the first read.
+    pub fn mark_started(&mut self) {
+        self.started_at.get_or_insert_with(Instant::now);
+    }
+
+    /// Time since the first chunk started reading
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.map_or(Duration::ZERO, |started| started.elapsed())
+    }
+
+    /// Bytes read per second of wall time since the first read
+    pub fn bytes_per_sec(&self) -> f64 {
+        per_sec(self.bytes_read as f64, self.elapsed())
+    }
+
+    /// Records processed per second of wall time since the first read
+    pub fn records_per_sec(&self) -> f64 {
+        per_sec(self.records_processed as f64, self.elapsed())
+    }
+
+    /// Combine statistics from several readers
+    ///
+    /// Counters are summed, memory is the maximum of any reader and the
+    /// average chunk time is weighted by each reader's chunk count. The
+    /// clock starts at the earliest reader's start.
+    pub fn aggregate<'a>(stats: impl IntoIterator<Item = &'a StreamingStats>) -> Self {
+        let mut total = StreamingStats::default();
+        let mut total_time_ms = 0.0;
+
+        for s in stats {
+            total.bytes_read += s.bytes_read;
+            total.records_processed += s.records_processed;
+            total.chunks_read += s.chunks_read;
+            total.memory_bytes = total.memory_bytes.max(s.memory_bytes);
+            total_time_ms += s.avg_chunk_time_ms * s.chunks_read as f64;
+            total.started_at = match (total.started_at, s.started_at) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        }
+
+        if total.chunks_read > 0 {
+            total.avg_chunk_time_ms = total_time_ms / total.chunks_read as f64;
+        }
+        total
+    }
+}
+
+fn per_sec(amount: f64, elapsed: Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 {
+        amount / secs
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_weights_chunk_time() {
+        let a = StreamingStats {
+            bytes_read: 100,
+            records_processed: 10,
+            chunks_read: 1,
+            memory_bytes: 500,
+            avg_chunk_time_ms: 10.0,
+            ..Default::default()
+        };
+        let b = StreamingStats {
+            bytes_read: 300,
+            records_processed: 30,
+            chunks_read: 3,
+            memory_bytes: 200,
+            avg_chunk_time_ms: 2.0,
+            ..Default::default()
+        };
+
+        let total = StreamingStats::aggregate([&a, &b]);
+        assert_eq!(total.bytes_read, 400);
+        assert_eq!(total.records_processed, 40);
+        assert_eq!(total.chunks_read, 4);
+        assert_eq!(total.memory_bytes, 500);
+        assert!((total.avg_chunk_time_ms - 4.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,123 @@
+//! EMA (Exponential Moving Average) calculation
+//!
+//! EMA weights recent prices more heavily than older ones, which makes it
+//! react faster than a simple moving average. With `α = 2 / (span + 1)`:
+//!
+//! Formula: EMA₀ = P₀, EMAₜ = α × Pₜ + (1 − α) × EMAₜ₋₁
+
+use polars::prelude::*;
+use crate::error::{TimeSeriesError, TimeSeriesResult};
+
+/// Calculate EMA for a DataFrame
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `price_col` - Name of price column
+/// * `span` - EMA span in rows (must be at least 1)
+///
+/// # Returns
+/// DataFrame with additional "ema" column
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::ema;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+/// ])?;
+///
+/// let df_with_ema = ema(&df, "close", 3)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn ema(
+    df: &DataFrame,
+    price_col: &str,
+    span: usize,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = ema_lazy(lf, price_col, span)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate EMA using lazy evaluation
+///
+/// Uses the recursive (non-adjusted) form, so the first value equals the
+/// first price.
+pub fn ema_lazy(
+    lf: LazyFrame,
+    price_col: &str,
+    span: usize,
+) -> TimeSeriesResult<LazyFrame> {
+    if span == 0 {
+        return Err(TimeSeriesError::InvalidConfig(
+            "EMA span must be at least 1".to_string(),
+        ));
+    }
+
+    let options = EWMOptions::default().and_span(span).and_adjust(false);
+    let result = lf.with_columns([col(price_col)
+        .cast(DataType::Float64)
+        .ewm_mean(options)
+        .alias("ema")]);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ema_recurrence() {
+        let prices = vec![100.0, 102.0, 101.0, 105.0, 104.0, 108.0];
+        let df = DataFrame::new(vec![Series::new("close".into(), prices.clone()).into()]).unwrap();
+
+        let result = ema(&df, "close", 3).unwrap();
+        let ema_col = result.column("ema").unwrap().f64().unwrap();
+
+        // First EMA is the first price
+        assert!((ema_col.get(0).unwrap() - prices[0]).abs() < 1e-9);
+
+        // Later values follow EMAₜ = α·Pₜ + (1 − α)·EMAₜ₋₁ with α = 2 / (3 + 1)
+        let alpha = 0.5;
+        let mut expected = prices[0];
+        for (i, price) in prices.iter().enumerate().skip(1) {
+            expected = alpha * price + (1.0 - alpha) * expected;
+            let got = ema_col.get(i).unwrap();
+            assert!((got - expected).abs() < 1e-9, "row {i}: {got} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_ema_validation() {
+        let df = DataFrame::new(vec![Series::new("close".into(), vec![1.0]).into()]).unwrap();
+        assert!(matches!(
+            ema(&df, "open", 3),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "open"
+        ));
+        assert!(matches!(
+            ema(&df, "close", 0),
+            Err(TimeSeriesError::InvalidConfig(_))
+        ));
+
+        let empty = DataFrame::new(vec![Series::new("close".into(), Vec::<f64>::new()).into()]).unwrap();
+        assert!(matches!(
+            ema(&empty, "close", 3),
+            Err(TimeSeriesError::EmptyDataFrame)
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+//! Technical indicators for trading strategies
+//!
+//! - **RSI** (Relative Strength Index): Wilder's momentum oscillator in [0, 100]
+//! - **Bollinger Bands**: Rolling mean ± a multiple of the rolling standard deviation
+
+use polars::prelude::*;
+use crate::error::{TimeSeriesError, TimeSeriesResult};
+
+/// Calculate Wilder's RSI for a DataFrame
+///
+/// Price changes are split into gains and losses. The first average is the
+/// simple mean of the first `period` changes; after that Wilder smoothing
+/// applies: `avg = (prev_avg × (period − 1) + current) / period`.
+///
+/// Formula: RSI = 100 − 100 / (1 + avg_gain / avg_loss)
+///
+/// The first `period` rows are null. When there are no losses in the window
+/// the RSI is 100; a completely flat window gives 50. A null price counts
+/// as no change.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `price_col` - Name of price column
+/// * `period` - Lookback period in rows (14 is customary)
+///
+/// # Returns
+/// DataFrame with additional "rsi" column
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::rsi;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+/// ])?;
+///
+/// let df_with_rsi = rsi(&df, "close", 3)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn rsi(
+    df: &DataFrame,
+    price_col: &str,
+    period: usize,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    if period == 0 {
+        return Err(TimeSeriesError::InvalidConfig(
+            "RSI period must be at least 1".to_string(),
+        ));
+    }
+
+    let prices = df.column(price_col)?.cast(&DataType::Float64)?;
+    let prices = prices.f64()?;
+
+    let mut values: Vec<Option<f64>> = vec![None; df.height()];
+    let (mut avg_gain, mut avg_loss) = (0.0, 0.0);
+    let period_f = period as f64;
+
+    let mut prev = prices.get(0);
+    for (i, price) in prices.iter().enumerate().skip(1) {
+        let delta = match (prev, price) {
+            (Some(p), Some(c)) => c - p,
+            _ => 0.0,
+        };
+        if price.is_some() {
+            prev = price;
+        }
+        let (gain, loss) = (delta.max(0.0), (-delta).max(0.0));
+
+        if i <= period {
+            // Seed with the simple average of the first `period` changes
+            avg_gain += gain / period_f;
+            avg_loss += loss / period_f;
+        } else {
+            avg_gain = (avg_gain * (period_f - 1.0) + gain) / period_f;
+            avg_loss = (avg_loss * (period_f - 1.0) + loss) / period_f;
+        }
+
+        if i >= period {
+            values[i] = Some(rsi_value(avg_gain, avg_loss));
+        }
+    }
+
+    let mut result = df.clone();
+    result.with_column(Series::new("rsi".into(), values))?;
+
+    Ok(result)
+}
+
+/// RSI from smoothed gains and losses, defined for zero denominators
+fn rsi_value(avg_gain: f64, avg_loss: f64) -> f64 {
+    match (avg_gain == 0.0, avg_loss == 0.0) {
+        (true, true) => 50.0,
+        (false, true) => 100.0,
+        _ => 100.0 - 100.0 / (1.0 + avg_gain / avg_loss),
+    }
+}
+
+/// Calculate Bollinger Bands for a DataFrame
+///
+/// Adds `bb_middle` (rolling mean), `bb_upper` and `bb_lower`
+/// (`bb_middle ± num_std × rolling_std`). Windows fill from the first row
+/// like `twap` (`min_periods = 1`); the standard deviation is the
+/// population one (ddof = 0), as in Bollinger's definition.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `price_col` - Name of price column
+/// * `window` - Number of rows in the window (20 is customary)
+/// * `num_std` - Band width in standard deviations (2.0 is customary)
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::bollinger_bands;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+/// ])?;
+///
+/// let df_with_bands = bollinger_bands(&df, "close", 3, 2.0)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn bollinger_bands(
+    df: &DataFrame,
+    price_col: &str,
+    window: usize,
+    num_std: f64,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = bollinger_bands_lazy(lf, price_col, window, num_std)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate Bollinger Bands using lazy evaluation
+pub fn bollinger_bands_lazy(
+    lf: LazyFrame,
+    price_col: &str,
+    window: usize,
+    num_std: f64,
+) -> TimeSeriesResult<LazyFrame> {
+    let options = |fn_params| RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: 1,
+        center: false,
+        fn_params,
+        ..Default::default()
+    };
+
+    let price = col(price_col).cast(DataType::Float64);
+    let middle = price.clone().rolling_mean(options(None));
+    let std = price.rolling_std(options(Some(RollingFnParams::Var(RollingVarParams {
+        ddof: 0,
+    }))));
+
+    let result = lf
+        .with_columns([middle.alias("bb_middle"), std.alias("__bb_std")])
+        .with_columns([
+            (col("bb_middle") + lit(num_std) * col("__bb_std")).alias("bb_upper"),
+            (col("bb_middle") - lit(num_std) * col("__bb_std")).alias("bb_lower"),
+        ])
+        .drop(["__bb_std"]);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rsi_values(prices: Vec<f64>, period: usize) -> Vec<Option<f64>> {
+        let df = DataFrame::new(vec![Series::new("close".into(), prices).into()]).unwrap();
+        let result = rsi(&df, "close", period).unwrap();
+        result.column("rsi").unwrap().f64().unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_rsi_increasing_series() {
+        let values = rsi_values((0..20).map(|i| 100.0 + i as f64).collect(), 14);
+
+        // First `period` rows have no RSI yet
+        assert!(values[..14].iter().all(Option::is_none));
+        // Only gains: RSI pinned at 100
+        assert!(values[14..].iter().all(|v| *v == Some(100.0)));
+    }
+
+    #[test]
+    fn test_rsi_wilder_smoothing() {
+        // Changes +1, -1, +1 with period 2:
+        // seed gain 0.5 / loss 0.5 → 50; then gain 0.75 / loss 0.25 → RS 3 → 75
+        let values = rsi_values(vec![1.0, 2.0, 1.0, 2.0], 2);
+        assert_eq!(values[..2], [None, None]);
+        assert!((values[2].unwrap() - 50.0).abs() < 1e-9);
+        assert!((values[3].unwrap() - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rsi_validation() {
+        let df = DataFrame::new(vec![Series::new("close".into(), vec![1.0]).into()]).unwrap();
+        assert!(matches!(
+            rsi(&df, "open", 14),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "open"
+        ));
+        assert!(matches!(rsi(&df, "close", 0), Err(TimeSeriesError::InvalidConfig(_))));
+
+        let empty = DataFrame::new(vec![Series::new("close".into(), Vec::<f64>::new()).into()]).unwrap();
+        assert!(matches!(rsi(&empty, "close", 14), Err(TimeSeriesError::EmptyDataFrame)));
+    }
+
+    #[test]
+    fn test_bollinger_constant_series() {
+        let df = DataFrame::new(vec![Series::new("close".into(), vec![50.0; 10]).into()]).unwrap();
+        let result = bollinger_bands(&df, "close", 4, 2.0).unwrap();
+
+        let column = |name| -> Vec<f64> {
+            result.column(name).unwrap().f64().unwrap().into_no_null_iter().collect()
+        };
+        let (middle, upper, lower) = (column("bb_middle"), column("bb_upper"), column("bb_lower"));
+
+        // Zero variance collapses the bands onto the mean
+        assert_eq!(middle.len(), 10);
+        for i in 0..10 {
+            assert!((middle[i] - 50.0).abs() < 1e-9);
+            assert!((upper[i] - middle[i]).abs() < 1e-9);
+            assert!((lower[i] - middle[i]).abs() < 1e-9);
+        }
+        assert!(result.column("__bb_std").is_err());
+    }
+
+    #[test]
+    fn test_bollinger_band_width() {
+        // Window [1, 3]: mean 2, population std 1
+        let df = DataFrame::new(vec![Series::new("close".into(), vec![1.0, 3.0]).into()]).unwrap();
+        let result = bollinger_bands(&df, "close", 2, 2.0).unwrap();
+
+        let upper = result.column("bb_upper").unwrap().f64().unwrap().get(1).unwrap();
+        let lower = result.column("bb_lower").unwrap().f64().unwrap().get(1).unwrap();
+        assert!((upper - 4.0).abs() < 1e-9);
+        assert!((lower - 0.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bollinger_validation() {
+        let df = DataFrame::new(vec![Series::new("close".into(), vec![1.0]).into()]).unwrap();
+        assert!(matches!(
+            bollinger_bands(&df, "open", 20, 2.0),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "open"
+        ));
+
+        let empty = DataFrame::new(vec![Series::new("close".into(), Vec::<f64>::new()).into()]).unwrap();
+        assert!(matches!(
+            bollinger_bands(&empty, "close", 20, 2.0),
+            Err(TimeSeriesError::EmptyDataFrame)
+        ));
+    }
+}
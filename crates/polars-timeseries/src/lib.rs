@@ -4,17 +4,21 @@
 //! focusing on trading and financial workflows:
 //!
 //! - **TWAP** (Time-Weighted Average Price): Calculate time-weighted averages
-//! - **VWAP** (Volume-Weighted Average Price): Calculate volume-weighted averages
-//! - **Multi-Frequency Resampling**: Resample data to different time frequencies
+//! - **VWAP** (Volume-Weighted Average Price): Cumulative or rolling volume-weighted averages
+//! - **EMA** (Exponential Moving Average): Span-based exponential smoothing
+//! - **Indicators**: RSI and Bollinger Bands
+//! - **Returns**: Simple and log return series, drawdowns
+//! - **Rolling Statistics**: Rolling correlation between columns, rolling z-scores
+//! - **Multi-Frequency Resampling**: Resample data to different time frequencies or OHLC bars
 //! - **Session Handling**: Split data by trading sessions
 //!
 //! # Examples
 //!
 //! ```rust,no_run
 //! use polars::prelude::*;
-//! use polars_timeseries::{twap, vwap};
+//! use polars_timeseries::{twap_by_time, vwap};
 //!
-//! # fn main() -> PolarsResult<()> {
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let df = DataFrame::new(vec![
 //!     // ... your OHLCV data
 //! ])?;
@@ -23,7 +27,7 @@
 //! let df_with_vwap = vwap(&df, "timestamp", "close", "volume")?;
 //!
 //! // Calculate TWAP
-//! let df_with_twap = twap(&df, "timestamp", "close", "5m")?;
+//! let df_with_twap = twap_by_time(&df, "timestamp", "close", "5m")?;
 //! # Ok(())
 //! # }
 //! ```
@@ -31,11 +35,19 @@
 mod error;
 mod vwap;
 mod twap;
+mod ema;
+mod indicators;
+mod returns;
+mod rolling;
 mod resample;
 mod session;
 
 pub use error::{TimeSeriesError, TimeSeriesResult};
-pub use vwap::{vwap, vwap_lazy};
-pub use twap::{twap, twap_lazy};
-pub use resample::{multi_frequency_resample, ResampleConfig};
+pub use vwap::{rolling_vwap, rolling_vwap_lazy, vwap, vwap_lazy};
+pub use twap::{twap, twap_by_time, twap_by_time_lazy, twap_lazy};
+pub use ema::{ema, ema_lazy};
+pub use indicators::{bollinger_bands, bollinger_bands_lazy, rsi};
+pub use returns::{drawdown_series, max_drawdown, returns, returns_lazy};
+pub use rolling::{rolling_correlation, rolling_correlation_lazy, rolling_zscore, rolling_zscore_lazy};
+pub use resample::{multi_frequency_resample, resample_ohlc, ResampleConfig};
 pub use session::{split_by_session, SessionConfig};
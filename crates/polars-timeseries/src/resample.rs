@@ -1,6 +1,7 @@
 //! Multi-frequency resampling for time-series data
 
 use polars::prelude::*;
+use polars_ops::series::SeriesMethods;
 use crate::error::{TimeSeriesError, TimeSeriesResult};
 
 /// Configuration for multi-frequency resampling
@@ -151,9 +152,116 @@ pub fn multi_frequency_resample(
     Ok(result)
 }
 
+/// Resample tick or bar data into OHLC bars
+///
+/// Groups rows into `every`-sized buckets with `group_by_dynamic` and emits
+/// `open`, `high`, `low`, `close` (first/max/min/last of `price_col`) plus
+/// `volume` (sum of `volume_col`) when a volume column is given. Bars are
+/// labelled by the start of their bucket; empty buckets are skipped.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `time_col` - Name of the datetime column, sorted ascending
+/// * `price_col` - Name of price column
+/// * `volume_col` - Optional name of volume column
+/// * `every` - Bar width (e.g., "1m", "5m", "1h")
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::resample_ohlc;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     // 1-minute tick data
+/// ])?;
+///
+/// let bars = resample_ohlc(&df, "timestamp", "price", Some("volume"), "5m")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn resample_ohlc(
+    df: &DataFrame,
+    time_col: &str,
+    price_col: &str,
+    volume_col: Option<&str>,
+    every: &str,
+) -> TimeSeriesResult<DataFrame> {
+    let col_names = df.get_column_names();
+    for name in [Some(time_col), Some(price_col), volume_col].into_iter().flatten() {
+        if !col_names.iter().any(|c| c.as_str() == name) {
+            return Err(TimeSeriesError::MissingColumn(name.to_string()));
+        }
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    validate_time_column(df, time_col)?;
+    // Reject malformed frequencies before Duration::parse can panic on them
+    parse_frequency(every)?;
+
+    let price = col(price_col);
+    let mut agg_exprs = vec![
+        price.clone().first().alias("open"),
+        price.clone().max().alias("high"),
+        price.clone().min().alias("low"),
+        price.last().alias("close"),
+    ];
+    if let Some(volume_col) = volume_col {
+        agg_exprs.push(col(volume_col).sum().alias("volume"));
+    }
+
+    let result = df
+        .clone()
+        .lazy()
+        .group_by_dynamic(
+            col(time_col),
+            [],
+            DynamicGroupOptions {
+                every: Duration::parse(every),
+                period: Duration::parse(every),
+                offset: Duration::parse("0s"),
+                closed_window: ClosedWindow::Left,
+                label: Label::Left,
+                ..Default::default()
+            },
+        )
+        .agg(agg_exprs)
+        .collect()?;
+
+    Ok(result)
+}
+
+/// Check that `time_col` is a datetime column sorted ascending
+pub(crate) fn validate_time_column(df: &DataFrame, time_col: &str) -> TimeSeriesResult<()> {
+    let time = df.column(time_col)?.as_materialized_series();
+    if !matches!(time.dtype(), DataType::Datetime(_, _)) {
+        return Err(TimeSeriesError::InvalidTimeColumn(format!(
+            "{} must be a datetime column, got {}",
+            time_col,
+            time.dtype()
+        )));
+    }
+    if !time.is_sorted(SortOptions::default())? {
+        return Err(TimeSeriesError::InvalidTimeColumn(format!(
+            "{} must be sorted ascending",
+            time_col
+        )));
+    }
+
+    Ok(())
+}
+
 /// Parse frequency string to milliseconds
-fn parse_frequency(freq: &str) -> TimeSeriesResult<i64> {
-    let (value, unit) = freq.split_at(freq.len() - 1);
+pub(crate) fn parse_frequency(freq: &str) -> TimeSeriesResult<i64> {
+    let split = freq
+        .len()
+        .checked_sub(1)
+        .filter(|&i| freq.is_char_boundary(i))
+        .ok_or_else(|| TimeSeriesError::InvalidFrequency(freq.to_string()))?;
+    let (value, unit) = freq.split_at(split);
     let value: i64 = value
         .parse()
         .map_err(|_| TimeSeriesError::InvalidFrequency(freq.to_string()))?;
@@ -189,5 +297,66 @@ mod tests {
         assert_eq!(parse_frequency("1m").unwrap(), 60_000);
         assert_eq!(parse_frequency("5m").unwrap(), 300_000);
         assert_eq!(parse_frequency("1h").unwrap(), 3_600_000);
+        assert!(parse_frequency("").is_err());
+    }
+
+    #[test]
+    fn test_resample_ohlc_minutes_to_five_minute_bars() {
+        // 12 one-minute prices: a zig-zag so highs and lows differ from open/close
+        let ts = Series::new("ts".into(), (0..12i64).map(|i| i * 60_000).collect::<Vec<_>>())
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let prices: Vec<f64> = (0..12).map(|i| 100.0 + (i % 3) as f64 * 2.0 - i as f64 * 0.5).collect();
+        let df = DataFrame::new(vec![
+            ts.into(),
+            Series::new("price".into(), prices.clone()).into(),
+            Series::new("volume".into(), vec![10i64; 12]).into(),
+        ])
+        .unwrap();
+
+        let bars = resample_ohlc(&df, "ts", "price", Some("volume"), "5m").unwrap();
+        // Minutes 0-4, 5-9 and 10-11
+        assert_eq!(bars.height(), 3);
+
+        let column = |name| -> Vec<f64> {
+            bars.column(name).unwrap().f64().unwrap().into_no_null_iter().collect()
+        };
+        let (open, high, low, close) = (column("open"), column("high"), column("low"), column("close"));
+        for i in 0..bars.height() {
+            assert!(high[i] >= close[i] && close[i] >= low[i]);
+            assert!(high[i] >= open[i] && open[i] >= low[i]);
+        }
+        assert_eq!(open[0], prices[0]);
+        assert_eq!(close[0], prices[4]);
+        assert_eq!(close[2], prices[11]);
+
+        let volume: Vec<i64> = bars.column("volume").unwrap().i64().unwrap().into_no_null_iter().collect();
+        assert_eq!(volume, vec![50, 50, 20]);
+    }
+
+    #[test]
+    fn test_resample_ohlc_validation() {
+        let ts = Series::new("ts".into(), vec![60_000i64, 0])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        let df = DataFrame::new(vec![
+            ts.into(),
+            Series::new("price".into(), vec![1.0, 2.0]).into(),
+        ])
+        .unwrap();
+
+        assert!(matches!(
+            resample_ohlc(&df, "ts", "price", Some("volume"), "5m"),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "volume"
+        ));
+        assert!(matches!(
+            resample_ohlc(&df, "price", "price", None, "5m"),
+            Err(TimeSeriesError::InvalidTimeColumn(_))
+        ));
+        // Unsorted timestamps
+        assert!(matches!(
+            resample_ohlc(&df, "ts", "price", None, "5m"),
+            Err(TimeSeriesError::InvalidTimeColumn(_))
+        ));
     }
 }
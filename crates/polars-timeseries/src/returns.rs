@@ -0,0 +1,214 @@
+//! Return series for backtesting and performance metrics
+//!
+//! - **Simple returns**: Rₜ = Pₜ / Pₜ₋₁ − 1
+//! - **Log returns**: rₜ = ln(Pₜ / Pₜ₋₁)
+//! - **Drawdown**: DDₜ = Eₜ / max(E₀..Eₜ) − 1
+//!
+//! Log returns add up over time, which makes them the usual input for
+//! volatility and Sharpe calculations.
+
+use polars::prelude::*;
+use crate::error::{TimeSeriesError, TimeSeriesResult};
+
+/// Calculate period-over-period returns for a DataFrame
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `price_col` - Name of price column
+/// * `log` - `true` for log returns, `false` for simple returns
+///
+/// # Returns
+/// DataFrame with additional "returns" column (null on the first row)
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::returns;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+/// ])?;
+///
+/// let df_with_returns = returns(&df, "close", true)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn returns(
+    df: &DataFrame,
+    price_col: &str,
+    log: bool,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = returns_lazy(lf, price_col, log)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate returns using lazy evaluation
+pub fn returns_lazy(
+    lf: LazyFrame,
+    price_col: &str,
+    log: bool,
+) -> TimeSeriesResult<LazyFrame> {
+    let price = col(price_col).cast(DataType::Float64);
+    let ratio = price.clone() / price.shift(lit(1));
+
+    let returns = if log {
+        ratio.log(std::f64::consts::E)
+    } else {
+        ratio - lit(1.0)
+    };
+
+    Ok(lf.with_columns([returns.alias("returns")]))
+}
+
+/// Calculate the drawdown of an equity curve at every row
+///
+/// The drawdown is the fractional distance below the running peak: `0.0`
+/// at a new high, `-0.25` when equity is 25% under its best value so far.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `equity_col` - Name of the equity (or price) column
+///
+/// # Returns
+/// DataFrame with additional "drawdown" column
+pub fn drawdown_series(df: &DataFrame, equity_col: &str) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == equity_col) {
+        return Err(TimeSeriesError::MissingColumn(equity_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let equity = col(equity_col).cast(DataType::Float64);
+    let result = df
+        .clone()
+        .lazy()
+        .with_columns([(equity.clone() / equity.cum_max(false) - lit(1.0)).alias("drawdown")])
+        .collect()?;
+
+    Ok(result)
+}
+
+/// Calculate the maximum drawdown of an equity curve
+///
+/// Returns the deepest drawdown as a non-positive fraction, e.g. `-0.20`
+/// for a 20% peak-to-trough decline. Nulls are ignored.
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::max_drawdown;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("equity".into(), vec![100.0, 120.0, 96.0, 110.0]).into(),
+/// ])?;
+///
+/// let mdd = max_drawdown(&df, "equity")?; // -0.20
+/// # Ok(())
+/// # }
+/// ```
+pub fn max_drawdown(df: &DataFrame, equity_col: &str) -> TimeSeriesResult<f64> {
+    let result = drawdown_series(df, equity_col)?;
+    let drawdown = result.column("drawdown")?.f64()?.min();
+
+    Ok(drawdown.unwrap_or(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices() -> DataFrame {
+        DataFrame::new(vec![Series::new("close".into(), vec![100.0, 110.0, 99.0, 99.0]).into()])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_simple_returns() {
+        let result = returns(&prices(), "close", false).unwrap();
+        let r = result.column("returns").unwrap().f64().unwrap();
+
+        assert_eq!(r.get(0), None);
+        let expected = [0.10, -0.10, 0.0];
+        for (i, want) in expected.iter().enumerate() {
+            assert!((r.get(i + 1).unwrap() - want).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_log_returns() {
+        let result = returns(&prices(), "close", true).unwrap();
+        let r = result.column("returns").unwrap().f64().unwrap();
+
+        assert_eq!(r.get(0), None);
+        let expected = [1.1f64.ln(), 0.9f64.ln(), 0.0];
+        for (i, want) in expected.iter().enumerate() {
+            assert!((r.get(i + 1).unwrap() - want).abs() < 1e-12);
+        }
+
+        // Log returns telescope: their sum is ln(last / first)
+        let total = r.sum().unwrap();
+        assert!((total - 0.99f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_returns_validation() {
+        assert!(matches!(
+            returns(&prices(), "open", false),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "open"
+        ));
+
+        let empty = DataFrame::new(vec![Series::new("close".into(), Vec::<f64>::new()).into()]).unwrap();
+        assert!(matches!(
+            returns(&empty, "close", true),
+            Err(TimeSeriesError::EmptyDataFrame)
+        ));
+    }
+
+    #[test]
+    fn test_max_drawdown() {
+        // Rises to 125, falls 20% to 100, then recovers short of the peak
+        let df = DataFrame::new(vec![
+            Series::new("equity".into(), vec![100.0, 110.0, 125.0, 112.5, 100.0, 120.0]).into(),
+        ])
+        .unwrap();
+
+        assert!((max_drawdown(&df, "equity").unwrap() + 0.20).abs() < 1e-12);
+
+        let result = drawdown_series(&df, "equity").unwrap();
+        let dd = result.column("drawdown").unwrap().f64().unwrap();
+        assert_eq!(dd.get(2), Some(0.0));
+        assert!((dd.get(3).unwrap() + 0.10).abs() < 1e-12);
+        assert!((dd.get(5).unwrap() + 0.04).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_max_drawdown_validation() {
+        let empty = DataFrame::new(vec![Series::new("equity".into(), Vec::<f64>::new()).into()]).unwrap();
+        assert!(matches!(
+            max_drawdown(&empty, "equity"),
+            Err(TimeSeriesError::EmptyDataFrame)
+        ));
+        assert!(matches!(
+            max_drawdown(&empty, "pnl"),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "pnl"
+        ));
+    }
+}
@@ -0,0 +1,235 @@
+//! Rolling statistics over fixed row windows
+//!
+//! - **Rolling correlation**: Pearson correlation of two columns, e.g. the
+//!   legs of a pairs trade
+//! - **Rolling z-score**: Distance from the rolling mean in rolling standard
+//!   deviations, e.g. for spotting spread anomalies
+
+use polars::prelude::*;
+use crate::error::{TimeSeriesError, TimeSeriesResult};
+
+/// Calculate the rolling Pearson correlation between two columns
+///
+/// Rows before the window has filled are null.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `col_a` - Name of the first column
+/// * `col_b` - Name of the second column
+/// * `window` - Number of rows in the window (must be at least 2)
+///
+/// # Returns
+/// DataFrame with additional "rolling_corr" column
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::rolling_correlation;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("btc".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+///     Series::new("eth".into(), vec![10.0, 10.2, 10.1, 10.3, 10.4]).into(),
+/// ])?;
+///
+/// let df_with_corr = rolling_correlation(&df, "btc", "eth", 3)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn rolling_correlation(
+    df: &DataFrame,
+    col_a: &str,
+    col_b: &str,
+    window: usize,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    for name in [col_a, col_b] {
+        if !col_names.iter().any(|c| c.as_str() == name) {
+            return Err(TimeSeriesError::MissingColumn(name.to_string()));
+        }
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = rolling_correlation_lazy(lf, col_a, col_b, window)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate rolling correlation using lazy evaluation
+pub fn rolling_correlation_lazy(
+    lf: LazyFrame,
+    col_a: &str,
+    col_b: &str,
+    window: usize,
+) -> TimeSeriesResult<LazyFrame> {
+    if window < 2 {
+        return Err(TimeSeriesError::InvalidConfig(
+            "Rolling correlation window must be at least 2".to_string(),
+        ));
+    }
+
+    let window = window as IdxSize;
+    let options = RollingCovOptions {
+        window_size: window,
+        min_periods: window,
+        ddof: 1,
+    };
+    let result = lf.with_columns([rolling_corr(
+        col(col_a).cast(DataType::Float64),
+        col(col_b).cast(DataType::Float64),
+        options,
+    )
+    .alias("rolling_corr")]);
+
+    Ok(result)
+}
+
+/// Calculate the rolling z-score of a column
+///
+/// Formula: zₜ = (xₜ − mean) / std over the trailing `window` rows, using
+/// the sample standard deviation. Rows before the window has filled, and
+/// windows with zero variance, are null.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `value_col` - Name of the column to normalize
+/// * `window` - Number of rows in the window (must be at least 2)
+///
+/// # Returns
+/// DataFrame with additional "zscore" column
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::rolling_zscore;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("spread".into(), vec![0.1, -0.2, 0.05, 0.3, -0.1]).into(),
+/// ])?;
+///
+/// let df_with_zscore = rolling_zscore(&df, "spread", 3)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn rolling_zscore(
+    df: &DataFrame,
+    value_col: &str,
+    window: usize,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == value_col) {
+        return Err(TimeSeriesError::MissingColumn(value_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = rolling_zscore_lazy(lf, value_col, window)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate rolling z-score using lazy evaluation
+pub fn rolling_zscore_lazy(
+    lf: LazyFrame,
+    value_col: &str,
+    window: usize,
+) -> TimeSeriesResult<LazyFrame> {
+    if window < 2 {
+        return Err(TimeSeriesError::InvalidConfig(
+            "Rolling z-score window must be at least 2".to_string(),
+        ));
+    }
+
+    let options = RollingOptionsFixedWindow {
+        window_size: window,
+        min_periods: window,
+        center: false,
+        ..Default::default()
+    };
+
+    let value = col(value_col).cast(DataType::Float64);
+    let mean = value.clone().rolling_mean(options.clone());
+    let std = value.clone().rolling_std(options);
+
+    let result = lf.with_columns([when(std.clone().gt(lit(0.0)))
+        .then((value - mean) / std)
+        .otherwise(lit(NULL))
+        .alias("zscore")]);
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_correlation_identical_columns() {
+        let prices = vec![100.0, 102.0, 101.0, 105.0, 104.0, 108.0, 107.0];
+        let df = DataFrame::new(vec![
+            Series::new("a".into(), prices.clone()).into(),
+            Series::new("b".into(), prices).into(),
+        ])
+        .unwrap();
+
+        let result = rolling_correlation(&df, "a", "b", 3).unwrap();
+        let corr = result.column("rolling_corr").unwrap().f64().unwrap();
+
+        assert_eq!(corr.get(0), None);
+        assert_eq!(corr.get(1), None);
+        for i in 2..corr.len() {
+            assert!((corr.get(i).unwrap() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_rolling_correlation_validation() {
+        let df = DataFrame::new(vec![Series::new("a".into(), vec![1.0, 2.0]).into()]).unwrap();
+        assert!(matches!(
+            rolling_correlation(&df, "a", "b", 2),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "b"
+        ));
+        assert!(matches!(
+            rolling_correlation(&df, "a", "a", 1),
+            Err(TimeSeriesError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_rolling_zscore_one_std_above_mean() {
+        // Last window [0, 0, 1, 2, 2]: mean 1, sample std 1
+        let df = DataFrame::new(vec![
+            Series::new("spread".into(), vec![0.0, 0.0, 1.0, 2.0, 2.0]).into(),
+        ])
+        .unwrap();
+
+        let result = rolling_zscore(&df, "spread", 5).unwrap();
+        let z = result.column("zscore").unwrap().f64().unwrap();
+
+        assert_eq!(z.null_count(), 4);
+        assert!((z.get(4).unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rolling_zscore_zero_variance_is_null() {
+        let df = DataFrame::new(vec![Series::new("spread".into(), vec![3.0; 5]).into()]).unwrap();
+
+        let result = rolling_zscore(&df, "spread", 2).unwrap();
+        assert_eq!(result.column("zscore").unwrap().null_count(), 5);
+
+        assert!(matches!(
+            rolling_zscore(&df, "price", 2),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "price"
+        ));
+    }
+}
@@ -5,14 +5,16 @@
 
 use polars::prelude::*;
 use crate::error::{TimeSeriesError, TimeSeriesResult};
+use crate::resample::{parse_frequency, validate_time_column};
 
-/// Calculate TWAP for a DataFrame
+/// Calculate TWAP for a DataFrame over a fixed number of rows
+///
+/// Use [`twap_by_time`] for wall-clock windows such as `"5m"`.
 ///
 /// # Arguments
 /// * `df` - Input DataFrame with time-series data
-/// * `time_col` - Name of timestamp column
 /// * `price_col` - Name of price column
-/// * `window` - Time window (e.g., "5m", "1h", "1d")
+/// * `window_size` - Number of rows in the window
 ///
 /// # Returns
 /// DataFrame with additional "twap" column
@@ -24,8 +26,8 @@ use crate::error::{TimeSeriesError, TimeSeriesResult};
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let df = DataFrame::new(vec![
-///     Series::new("timestamp".into(), vec![1i64, 2, 3, 4, 5]),
-///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]),
+///     Series::new("timestamp".into(), vec![1i64, 2, 3, 4, 5]).into(),
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
 /// ])?;
 ///
 /// let df_with_twap = twap(&df, "close", 3)?;
@@ -78,6 +80,73 @@ pub fn twap_lazy(
     Ok(result)
 }
 
+/// Calculate TWAP over a trailing wall-clock window
+///
+/// Each row averages the prices whose timestamps fall in `(t - window, t]`,
+/// so irregularly spaced ticks are windowed by time rather than row count.
+///
+/// # Arguments
+/// * `df` - Input DataFrame with time-series data
+/// * `time_col` - Name of a sorted datetime column
+/// * `price_col` - Name of price column
+/// * `window` - Time window (e.g., "30s", "5m", "1h", "1d")
+///
+/// # Returns
+/// DataFrame with additional "twap" column
+pub fn twap_by_time(
+    df: &DataFrame,
+    time_col: &str,
+    price_col: &str,
+    window: &str,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == time_col) {
+        return Err(TimeSeriesError::MissingColumn(time_col.to_string()));
+    }
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    validate_time_column(df, time_col)?;
+
+    let lf = df.clone().lazy();
+    let result = twap_by_time_lazy(lf, time_col, price_col, window)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate time-windowed TWAP using lazy evaluation
+///
+/// The caller is responsible for `time_col` being a sorted datetime column.
+pub fn twap_by_time_lazy(
+    lf: LazyFrame,
+    time_col: &str,
+    price_col: &str,
+    window: &str,
+) -> TimeSeriesResult<LazyFrame> {
+    // Reject malformed windows before Duration::parse can panic on them
+    parse_frequency(window)?;
+
+    let result = lf.with_columns([col(price_col)
+        .rolling_mean_by(
+            col(time_col),
+            RollingOptionsDynamicWindow {
+                window_size: Duration::parse(window),
+                min_periods: 1,
+                closed_window: ClosedWindow::Right,
+                fn_params: None,
+            },
+        )
+        .alias("twap")]);
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -96,4 +165,49 @@ mod tests {
         assert!(result_df.column("twap").is_ok());
         assert_eq!(result_df.height(), 5);
     }
+
+    fn irregular_ticks() -> DataFrame {
+        // 0s, 60s, 120s, then a gap to 600s and 610s
+        let ts = Series::new("ts".into(), vec![0i64, 60_000, 120_000, 600_000, 610_000])
+            .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+            .unwrap();
+        DataFrame::new(vec![
+            ts.into(),
+            Series::new("close".into(), vec![1.0, 2.0, 3.0, 4.0, 5.0]).into(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_twap_by_time_uses_wall_clock() {
+        let result = twap_by_time(&irregular_ticks(), "ts", "close", "5m").unwrap();
+        let twap = result.column("twap").unwrap().f64().unwrap();
+
+        let got: Vec<f64> = twap.into_no_null_iter().collect();
+        // A 3-row window would give 3.0 at 600s; the 5m window only sees 4.0
+        assert_eq!(got, vec![1.0, 1.5, 2.0, 4.0, 4.5]);
+    }
+
+    #[test]
+    fn test_twap_by_time_validation() {
+        let df = irregular_ticks();
+        assert!(matches!(
+            twap_by_time(&df, "missing", "close", "5m"),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "missing"
+        ));
+        assert!(matches!(
+            twap_by_time(&df, "close", "close", "5m"),
+            Err(TimeSeriesError::InvalidTimeColumn(_))
+        ));
+        assert!(matches!(
+            twap_by_time(&df, "ts", "close", "five minutes"),
+            Err(TimeSeriesError::InvalidFrequency(_))
+        ));
+
+        let unsorted = df.sort(["close"], SortMultipleOptions::default().with_order_descending(true)).unwrap();
+        assert!(matches!(
+            twap_by_time(&unsorted, "ts", "close", "5m"),
+            Err(TimeSeriesError::InvalidTimeColumn(_))
+        ));
+    }
 }
@@ -4,6 +4,9 @@
 //! throughout the day, based on both volume and price.
 //!
 //! Formula: VWAP = Σ(Price × Volume) / Σ(Volume)
+//!
+//! [`vwap`] accumulates from the first row (session VWAP); [`rolling_vwap`]
+//! sums over a trailing window of rows instead.
 
 use polars::prelude::*;
 use polars_ops::series::cum_sum;
@@ -116,6 +119,81 @@ pub fn vwap_lazy(
     Ok(result)
 }
 
+/// Calculate VWAP over a trailing fixed-size window
+///
+/// # Arguments
+/// * `df` - Input DataFrame with price and volume data
+/// * `price_col` - Name of price column
+/// * `volume_col` - Name of volume column
+/// * `window_size` - Number of rows in the window
+///
+/// # Returns
+/// DataFrame with additional "vwap" column
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use polars_timeseries::rolling_vwap;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let df = DataFrame::new(vec![
+///     Series::new("close".into(), vec![100.0, 101.0, 102.0, 101.5, 103.0]).into(),
+///     Series::new("volume".into(), vec![1000i64, 1500, 1200, 1100, 1300]).into(),
+/// ])?;
+///
+/// let df_with_vwap = rolling_vwap(&df, "close", "volume", 3)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn rolling_vwap(
+    df: &DataFrame,
+    price_col: &str,
+    volume_col: &str,
+    window_size: usize,
+) -> TimeSeriesResult<DataFrame> {
+    // Validate columns exist
+    let col_names = df.get_column_names();
+    if !col_names.iter().any(|c| c.as_str() == price_col) {
+        return Err(TimeSeriesError::MissingColumn(price_col.to_string()));
+    }
+    if !col_names.iter().any(|c| c.as_str() == volume_col) {
+        return Err(TimeSeriesError::MissingColumn(volume_col.to_string()));
+    }
+
+    if df.height() == 0 {
+        return Err(TimeSeriesError::EmptyDataFrame);
+    }
+
+    let lf = df.clone().lazy();
+    let result = rolling_vwap_lazy(lf, price_col, volume_col, window_size)?;
+
+    Ok(result.collect()?)
+}
+
+/// Calculate rolling VWAP using lazy evaluation with fixed window
+///
+/// `rolling_sum(price * volume) / rolling_sum(volume)`, computed in `f64`
+/// so integer prices and volumes don't truncate.
+pub fn rolling_vwap_lazy(
+    lf: LazyFrame,
+    price_col: &str,
+    volume_col: &str,
+    window_size: usize,
+) -> TimeSeriesResult<LazyFrame> {
+    let options = RollingOptionsFixedWindow {
+        window_size,
+        min_periods: 1,
+        center: false,
+        ..Default::default()
+    };
+
+    let pv = (col(price_col).cast(DataType::Float64) * col(volume_col).cast(DataType::Float64))
+        .rolling_sum(options.clone());
+    let volume = col(volume_col).cast(DataType::Float64).rolling_sum(options);
+
+    Ok(lf.with_columns([(pv / volume).alias("vwap")]))
+}
+
 /// Calculate typical price (HLC/3) for VWAP
 ///
 /// Typical price is often used instead of close price for VWAP calculation:
@@ -169,4 +247,45 @@ mod tests {
         // (105 + 95 + 100) / 3 = 100
         assert!((value - 100.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_rolling_vwap() {
+        let df = DataFrame::new(vec![
+            Series::new("close".into(), vec![10.0, 20.0, 30.0, 40.0]).into(),
+            Series::new("volume".into(), vec![1i64, 3, 2, 4]).into(),
+        ])
+        .unwrap();
+
+        let result = rolling_vwap(&df, "close", "volume", 2).unwrap();
+        let vwap_col = result.column("vwap").unwrap().f64().unwrap();
+
+        // (10*1)/1, (10*1 + 20*3)/4, (20*3 + 30*2)/5, (30*2 + 40*4)/6
+        let expected = [10.0, 17.5, 24.0, 220.0 / 6.0];
+        for (i, want) in expected.iter().enumerate() {
+            let got = vwap_col.get(i).unwrap();
+            assert!((got - want).abs() < 1e-9, "row {i}: {got} != {want}");
+        }
+    }
+
+    #[test]
+    fn test_rolling_vwap_validation() {
+        let df = DataFrame::new(vec![
+            Series::new("close".into(), vec![10.0]).into(),
+        ])
+        .unwrap();
+        assert!(matches!(
+            rolling_vwap(&df, "close", "volume", 2),
+            Err(TimeSeriesError::MissingColumn(c)) if c == "volume"
+        ));
+
+        let empty = DataFrame::new(vec![
+            Series::new("close".into(), Vec::<f64>::new()).into(),
+            Series::new("volume".into(), Vec::<f64>::new()).into(),
+        ])
+        .unwrap();
+        assert!(matches!(
+            rolling_vwap(&empty, "close", "volume", 2),
+            Err(TimeSeriesError::EmptyDataFrame)
+        ));
+    }
 }
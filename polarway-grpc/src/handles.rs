@@ -1,8 +1,12 @@
 use dashmap::DashMap;
 use polars::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
+use tokio::runtime::RuntimeFlavor;
 use tracing::{debug, info, warn};
 
 use crate::error::{PolarwayError, Result};
@@ -15,33 +19,95 @@ pub struct DataFrameHandleInfo {
     pub created_at: Instant,
     pub last_accessed: Instant,
     pub ttl: std::time::Duration,
+    /// Monotonic access sequence used for LRU ordering
+    access_seq: u64,
+    expires_at: Instant,
 }
 
 impl DataFrameHandleInfo {
-    fn new(dataframe: DataFrame, ttl: std::time::Duration) -> Self {
+    fn new(handle: String, dataframe: DataFrame, ttl: std::time::Duration, access_seq: u64) -> Self {
         let now = Instant::now();
         Self {
-            handle: Uuid::new_v4().to_string(),
+            handle,
             dataframe: Arc::new(dataframe),
             created_at: now,
             last_accessed: now,
             ttl,
+            access_seq,
+            expires_at: now + ttl,
         }
     }
     
     fn is_expired(&self) -> bool {
-        self.last_accessed.elapsed() > self.ttl
+        Instant::now() > self.expires_at
     }
     
-    fn touch(&mut self) {
+    fn touch(&mut self, access_seq: u64) {
         self.last_accessed = Instant::now();
+        self.access_seq = access_seq;
+    }
+    
+    /// Restart the TTL from now
+    fn extend(&mut self) {
+        self.expires_at = Instant::now() + self.ttl;
+    }
+
+    fn persisted_ttl(&self) -> PersistedTtl {
+        let remaining = self.expires_at.saturating_duration_since(Instant::now());
+        PersistedTtl {
+            ttl_ms: self.ttl.as_millis() as u64,
+            expires_at_ms: unix_millis(SystemTime::now() + remaining),
+        }
+    }
+}
+
+/// TTL of a persisted handle, stored as `<handle>.json` next to its data
+///
+/// The expiry is wall-clock time so it still holds after a restart.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PersistedTtl {
+    ttl_ms: u64,
+    /// Milliseconds since the Unix epoch
+    expires_at_ms: u64,
+}
+
+impl PersistedTtl {
+    /// Time left before expiry, `None` once expired
+    fn remaining(&self) -> Option<Duration> {
+        let now = unix_millis(SystemTime::now());
+        (self.expires_at_ms > now).then(|| Duration::from_millis(self.expires_at_ms - now))
     }
 }
 
-/// Manages DataFrame handles with TTL and reference counting
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_millis() as u64)
+}
+
+/// Snapshot of handle manager counters
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HandleStats {
+    /// Handles currently stored
+    pub live: usize,
+    /// Handles removed to stay under the max-handle cap, if one is set
+    pub evicted: u64,
+    /// Handles removed because their TTL elapsed
+    pub expired: u64,
+}
+
+/// Manages DataFrame handles with TTL expiry and optional LRU eviction
+///
+/// With [`HandleManager::with_store_dir`], every handle is also written to
+/// `<dir>/<handle>.parquet`, with its TTL in `<dir>/<handle>.json`, so it
+/// can be resolved after a restart.
 pub struct HandleManager {
     handles: DashMap<String, DataFrameHandleInfo>,
     default_ttl: std::time::Duration,
+    max_handles: Option<usize>,
+    touch_extends_ttl: bool,
+    store_dir: Option<PathBuf>,
+    access_counter: AtomicU64,
+    evicted: AtomicU64,
+    expired: AtomicU64,
 }
 
 impl HandleManager {
@@ -49,13 +115,230 @@ impl HandleManager {
         Self {
             handles: DashMap::new(),
             default_ttl,
+            max_handles: None,
+            touch_extends_ttl: true,
+            store_dir: None,
+            access_counter: AtomicU64::new(0),
+            evicted: AtomicU64::new(0),
+            expired: AtomicU64::new(0),
+        }
+    }
+
+    /// Cap the number of live handles; the least recently accessed
+    /// handle is evicted when a new one would exceed it
+    ///
+    /// Unbounded by default, so handles only go away through TTL expiry
+    /// or `drop_handle`.
+    pub fn with_max_handles(mut self, max_handles: usize) -> Self {
+        self.max_handles = Some(max_handles.max(1));
+        self
+    }
+
+    /// Whether `get_dataframe` restarts a handle's TTL (default: true)
+    ///
+    /// When false, handles expire `ttl` after creation unless kept alive
+    /// with `heartbeat`.
+    pub fn with_touch_extends_ttl(mut self, touch_extends_ttl: bool) -> Self {
+        self.touch_extends_ttl = touch_extends_ttl;
+        self
+    }
+
+    /// Persist handles as parquet files under `dir`, creating it if needed
+    ///
+    /// A handle missing from memory is reloaded from disk on access with
+    /// the TTL and expiry it had when last written. Dropping, expiring or
+    /// evicting a handle deletes its files. Files of handles that expired
+    /// while the server was down are removed here.
+    pub fn with_store_dir(mut self, dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        self.store_dir = Some(dir);
+        self.sweep_store()?;
+        Ok(self)
+    }
+
+    /// Delete persisted handles that have expired, and leftover temp or
+    /// TTL files without data
+    fn sweep_store(&self) -> Result<usize> {
+        let Some(dir) = &self.store_dir else { return Ok(0) };
+
+        let mut removed = 0;
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let orphan_ttl = path.extension().is_some_and(|ext| ext == "json")
+                && !path.with_extension("parquet").exists();
+            if orphan_ttl || path.extension().is_some_and(|ext| ext == "tmp") {
+                let _ = std::fs::remove_file(&path);
+                continue;
+            }
+            let Some(handle) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_suffix(".parquet"))
+            else {
+                continue;
+            };
+            if self.load_ttl(handle).remaining().is_none() {
+                self.unpersist(handle);
+                removed += 1;
+            }
+        }
+
+        if removed > 0 {
+            info!("Removed {} expired persisted handles", removed);
+        }
+        Ok(removed)
+    }
+
+    /// On-disk location of a handle, if persistence is enabled
+    ///
+    /// Only UUIDs map to a path, so a handle can never escape the store.
+    fn store_path(&self, handle: &str) -> Option<PathBuf> {
+        let dir = self.store_dir.as_ref()?;
+        Uuid::parse_str(handle).ok()?;
+        Some(dir.join(format!("{}.parquet", handle)))
+    }
+
+    /// Write a handle's data and TTL to the store
+    ///
+    /// The write finishes before this returns, so a later drop, eviction
+    /// or expiry always finds the files to delete.
+    fn persist(&self, info: &DataFrameHandleInfo) {
+        let Some(path) = self.store_path(&info.handle) else { return };
+
+        let ttl = info.persisted_ttl();
+        let written = run_blocking(|| {
+            write_parquet(&path, &info.dataframe)
+                .and_then(|()| write_ttl(&path.with_extension("json"), ttl))
+        });
+        if let Err(e) = written {
+            warn!("Failed to persist handle {}: {}", info.handle, e);
+        }
+    }
+
+    /// Rewrite a persisted handle's expiry after its TTL was extended
+    ///
+    /// Callers hold the handle's map entry, so this can't interleave with
+    /// removing the handle and leave a stray TTL file behind.
+    fn persist_ttl(&self, info: &DataFrameHandleInfo) {
+        let Some(path) = self.store_path(&info.handle) else { return };
+
+        let ttl = info.persisted_ttl();
+        if let Err(e) = run_blocking(|| write_ttl(&path.with_extension("json"), ttl)) {
+            warn!("Failed to persist TTL of handle {}: {}", info.handle, e);
+        }
+    }
+
+    /// TTL stored next to a persisted handle
+    ///
+    /// Files without one (or with an unreadable one) fall back to the
+    /// default TTL counted from the file's last modification.
+    fn load_ttl(&self, handle: &str) -> PersistedTtl {
+        let Some(path) = self.store_path(handle) else {
+            return PersistedTtl { ttl_ms: 0, expires_at_ms: 0 };
+        };
+
+        let stored = std::fs::read(path.with_extension("json"))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok());
+        stored.unwrap_or_else(|| {
+            let modified = std::fs::metadata(&path)
+                .and_then(|meta| meta.modified())
+                .unwrap_or(UNIX_EPOCH);
+            PersistedTtl {
+                ttl_ms: self.default_ttl.as_millis() as u64,
+                expires_at_ms: unix_millis(modified + self.default_ttl),
+            }
+        })
+    }
+
+    fn unpersist(&self, handle: &str) -> bool {
+        match self.store_path(handle) {
+            Some(path) => {
+                let _ = std::fs::remove_file(path.with_extension("json"));
+                std::fs::remove_file(path).is_ok()
+            }
+            None => false,
+        }
+    }
+
+    /// Load a persisted handle back into memory with its stored TTL
+    fn rehydrate(&self, handle: &str) -> Result<Option<Arc<DataFrame>>> {
+        let Some(path) = self.store_path(handle).filter(|path| path.exists()) else {
+            return Ok(None);
+        };
+
+        let ttl = self.load_ttl(handle);
+        let Some(remaining) = ttl.remaining() else {
+            self.unpersist(handle);
+            self.expired.fetch_add(1, Ordering::Relaxed);
+            return Err(PolarwayError::HandleExpired(handle.to_string()));
+        };
+
+        let dataframe = read_parquet(&path)?;
+        self.evict_for_insert();
+
+        let mut info = DataFrameHandleInfo::new(
+            handle.to_string(),
+            dataframe,
+            Duration::from_millis(ttl.ttl_ms),
+            self.next_access_seq(),
+        );
+        info.expires_at = Instant::now() + remaining;
+        let dataframe = Arc::clone(&info.dataframe);
+        self.handles.insert(handle.to_string(), info);
+
+        info!("Rehydrated handle: {} from {}", handle, path.display());
+        Ok(Some(dataframe))
+    }
+
+    fn next_access_seq(&self) -> u64 {
+        self.access_counter.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Make room for one more handle, dropping expired handles first and
+    /// then the least recently accessed ones
+    fn evict_for_insert(&self) {
+        let Some(max_handles) = self.max_handles else { return };
+        if self.handles.len() < max_handles {
+            return;
+        }
+
+        self.cleanup_expired();
+
+        while self.handles.len() >= max_handles {
+            let oldest = self
+                .handles
+                .iter()
+                .min_by_key(|entry| entry.access_seq)
+                .map(|entry| entry.key().clone());
+
+            let Some(oldest) = oldest else { break };
+            if self.handles.remove(&oldest).is_some() {
+                self.unpersist(&oldest);
+                self.evicted.fetch_add(1, Ordering::Relaxed);
+                warn!("Evicted least recently used handle: {}", oldest);
+            }
         }
     }
     
-    /// Create a new handle for a DataFrame
+    /// Create a new handle for a DataFrame with the default TTL
     pub fn create_handle(&self, dataframe: DataFrame) -> String {
-        let info = DataFrameHandleInfo::new(dataframe, self.default_ttl);
+        self.create_handle_with_ttl(dataframe, self.default_ttl)
+    }
+    
+    /// Create a new handle that expires `ttl` after its last access
+    pub fn create_handle_with_ttl(&self, dataframe: DataFrame, ttl: std::time::Duration) -> String {
+        self.evict_for_insert();
+
+        let info = DataFrameHandleInfo::new(
+            Uuid::new_v4().to_string(),
+            dataframe,
+            ttl,
+            self.next_access_seq(),
+        );
         let handle = info.handle.clone();
+        self.persist(&info);
         
         info!("Created handle: {} (shape: {:?})", handle, info.dataframe.shape());
         self.handles.insert(handle.clone(), info);
@@ -65,16 +348,26 @@ impl HandleManager {
     
     /// Get DataFrame by handle (updates last_accessed)
     pub fn get_dataframe(&self, handle: &str) -> Result<Arc<DataFrame>> {
-        let mut entry = self.handles.get_mut(handle)
-            .ok_or_else(|| PolarwayError::HandleNotFound(handle.to_string()))?;
+        let Some(mut entry) = self.handles.get_mut(handle) else {
+            return self
+                .rehydrate(handle)?
+                .ok_or_else(|| PolarwayError::HandleNotFound(handle.to_string()));
+        };
         
         if entry.is_expired() {
             drop(entry);
-            self.handles.remove(handle);
+            if self.handles.remove(handle).is_some() {
+                self.unpersist(handle);
+                self.expired.fetch_add(1, Ordering::Relaxed);
+            }
             return Err(PolarwayError::HandleExpired(handle.to_string()));
         }
         
-        entry.touch();
+        entry.touch(self.next_access_seq());
+        if self.touch_extends_ttl {
+            entry.extend();
+            self.persist_ttl(&entry);
+        }
         debug!("Accessed handle: {}", handle);
         Ok(Arc::clone(&entry.dataframe))
     }
@@ -89,18 +382,25 @@ impl HandleManager {
     
     /// Drop a handle explicitly
     pub fn drop_handle(&self, handle: &str) -> Result<()> {
-        self.handles.remove(handle)
-            .ok_or_else(|| PolarwayError::HandleNotFound(handle.to_string()))?;
+        let in_memory = self.handles.remove(handle).is_some();
+        if !self.unpersist(handle) && !in_memory {
+            return Err(PolarwayError::HandleNotFound(handle.to_string()));
+        }
         info!("Dropped handle: {}", handle);
         Ok(())
     }
     
     /// Extend TTL for a handle (heartbeat)
     pub fn heartbeat(&self, handle: &str) -> Result<()> {
+        if !self.handles.contains_key(handle) {
+            self.rehydrate(handle)?;
+        }
         let mut entry = self.handles.get_mut(handle)
             .ok_or_else(|| PolarwayError::HandleNotFound(handle.to_string()))?;
         
-        entry.touch();
+        entry.touch(self.next_access_seq());
+        entry.extend();
+        self.persist_ttl(&entry);
         debug!("Heartbeat for handle: {}", handle);
         Ok(())
     }
@@ -111,6 +411,7 @@ impl HandleManager {
         self.handles.retain(|handle, info| {
             if info.is_expired() {
                 warn!("Removing expired handle: {}", handle);
+                self.unpersist(handle);
                 removed += 1;
                 false
             } else {
@@ -119,6 +420,7 @@ impl HandleManager {
         });
         
         if removed > 0 {
+            self.expired.fetch_add(removed as u64, Ordering::Relaxed);
             info!("Cleaned up {} expired handles", removed);
         }
         
@@ -130,13 +432,62 @@ impl HandleManager {
         self.handles.len()
     }
     
-    /// Check if handle exists and is alive
+    /// Live, evicted and expired handle counts
+    pub fn stats(&self) -> HandleStats {
+        HandleStats {
+            live: self.handles.len(),
+            evicted: self.evicted.load(Ordering::Relaxed),
+            expired: self.expired.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Maximum number of live handles, `None` when unbounded
+    pub fn max_handles(&self) -> Option<usize> {
+        self.max_handles
+    }
+    
+    /// Check if handle exists and is alive (in memory or persisted)
     pub fn is_alive(&self, handle: &str) -> bool {
         if let Some(entry) = self.handles.get(handle) {
             !entry.is_expired()
         } else {
-            false
+            self.store_path(handle).is_some_and(|path| path.exists())
+                && self.load_ttl(handle).remaining().is_some()
+        }
+    }
+}
+
+fn read_parquet(path: &Path) -> Result<DataFrame> {
+    let file = std::fs::File::open(path)?;
+    Ok(ParquetReader::new(file).finish()?)
+}
+
+/// Write under a temporary name and rename, so readers never see a
+/// partial file
+fn write_parquet(path: &Path, dataframe: &DataFrame) -> Result<()> {
+    let tmp = path.with_extension("parquet.tmp");
+    let file = std::fs::File::create(&tmp)?;
+    ParquetWriter::new(file).finish(&mut dataframe.clone())?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+fn write_ttl(path: &Path, ttl: PersistedTtl) -> Result<()> {
+    let json = serde_json::to_vec(&ttl).map_err(|e| PolarwayError::Serialization(e.to_string()))?;
+    std::fs::write(path, json)?;
+    Ok(())
+}
+
+/// Run file IO to completion on the calling thread
+///
+/// On a multi-threaded Tokio runtime the worker first hands its other
+/// tasks to the rest of the pool, so they aren't stalled behind the IO.
+fn run_blocking<T>(io: impl FnOnce() -> T) -> T {
+    match tokio::runtime::Handle::try_current() {
+        Ok(runtime) if runtime.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(io)
         }
+        _ => io(),
     }
 }
 
@@ -209,5 +560,174 @@ mod tests {
         let result = manager.get_dataframe(&handle);
         
         assert!(matches!(result, Err(PolarwayError::HandleExpired(_))));
+        assert_eq!(manager.stats().expired, 1);
+    }
+    
+    #[test]
+    fn test_per_handle_ttl() {
+        let manager = HandleManager::default();
+        let short = manager.create_handle_with_ttl(create_test_df(), std::time::Duration::from_millis(50));
+        let long = manager.create_handle_with_ttl(create_test_df(), std::time::Duration::from_secs(60));
+        
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        
+        assert!(matches!(
+            manager.get_dataframe(&short),
+            Err(PolarwayError::HandleExpired(_))
+        ));
+        assert!(manager.get_dataframe(&long).is_ok());
+    }
+    
+    #[test]
+    fn test_touch_extends_ttl_flag() {
+        let ttl = std::time::Duration::from_millis(200);
+        let sliding = HandleManager::new(ttl);
+        let fixed = HandleManager::new(ttl).with_touch_extends_ttl(false);
+        let sliding_handle = sliding.create_handle(create_test_df());
+        let fixed_handle = fixed.create_handle(create_test_df());
+        
+        std::thread::sleep(std::time::Duration::from_millis(120));
+        sliding.get_dataframe(&sliding_handle).unwrap();
+        fixed.get_dataframe(&fixed_handle).unwrap();
+        
+        std::thread::sleep(std::time::Duration::from_millis(120));
+        assert!(sliding.get_dataframe(&sliding_handle).is_ok());
+        assert!(matches!(
+            fixed.get_dataframe(&fixed_handle),
+            Err(PolarwayError::HandleExpired(_))
+        ));
+    }
+    
+    #[test]
+    fn test_persisted_handle_survives_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        
+        let handle = {
+            let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+            manager.create_handle(create_test_df())
+        };
+        
+        let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+        assert!(manager.is_alive(&handle));
+        let retrieved = manager.get_dataframe(&handle).unwrap();
+        assert!(retrieved.equals(&create_test_df()));
+        
+        manager.drop_handle(&handle).unwrap();
+        assert!(!dir.path().join(format!("{}.parquet", handle)).exists());
+        assert!(matches!(
+            manager.get_dataframe(&handle),
+            Err(PolarwayError::HandleNotFound(_))
+        ));
+    }
+    
+    #[test]
+    fn test_expired_handle_deletes_persisted_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = HandleManager::new(std::time::Duration::from_millis(100))
+            .with_store_dir(dir.path())
+            .unwrap();
+        let handle = manager.create_handle(create_test_df());
+        let path = dir.path().join(format!("{}.parquet", handle));
+        assert!(path.exists());
+        
+        std::thread::sleep(std::time::Duration::from_millis(150));
+        assert_eq!(manager.cleanup_expired(), 1);
+        assert!(!path.exists());
+    }
+    
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_drop_in_runtime_leaves_no_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+        
+        let handle = manager.create_handle(create_test_df());
+        manager.get_dataframe(&handle).unwrap();
+        manager.drop_handle(&handle).unwrap();
+        
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+        assert!(!manager.is_alive(&handle));
+    }
+    
+    #[test]
+    fn test_expired_persisted_handle_is_not_alive() {
+        let dir = tempfile::tempdir().unwrap();
+        let handle = {
+            let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+            manager.create_handle_with_ttl(create_test_df(), std::time::Duration::from_millis(50))
+        };
+        
+        let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+        assert!(manager.is_alive(&handle));
+        
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        assert!(!manager.is_alive(&handle));
+    }
+    
+    #[test]
+    fn test_rehydrate_restores_handle_ttl() {
+        let dir = tempfile::tempdir().unwrap();
+        let ttl = std::time::Duration::from_millis(200);
+        
+        let handle = {
+            let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+            manager.create_handle_with_ttl(create_test_df(), ttl)
+        };
+        
+        // The restarted manager's one hour default must not apply
+        let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+        manager.get_dataframe(&handle).unwrap();
+        
+        std::thread::sleep(std::time::Duration::from_millis(250));
+        assert!(matches!(
+            manager.get_dataframe(&handle),
+            Err(PolarwayError::HandleExpired(_))
+        ));
+    }
+    
+    #[test]
+    fn test_startup_sweeps_expired_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let (expired, alive) = {
+            let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+            (
+                manager.create_handle_with_ttl(create_test_df(), std::time::Duration::from_millis(50)),
+                manager.create_handle(create_test_df()),
+            )
+        };
+        
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        let manager = HandleManager::default().with_store_dir(dir.path()).unwrap();
+        
+        assert!(!dir.path().join(format!("{}.parquet", expired)).exists());
+        assert!(!dir.path().join(format!("{}.json", expired)).exists());
+        assert!(!manager.is_alive(&expired));
+        assert!(manager.get_dataframe(&alive).is_ok());
+    }
+    
+    #[test]
+    fn test_lru_eviction_at_capacity() {
+        assert_eq!(HandleManager::default().max_handles(), None);
+
+        let manager = HandleManager::default().with_max_handles(2);
+        let first = manager.create_handle(create_test_df());
+        let second = manager.create_handle(create_test_df());
+        
+        // Touch the first so the second becomes least recently used
+        manager.get_dataframe(&first).unwrap();
+        let third = manager.create_handle(create_test_df());
+        
+        assert!(manager.is_alive(&first));
+        assert!(!manager.is_alive(&second));
+        assert!(manager.is_alive(&third));
+        
+        let fourth = manager.create_handle(create_test_df());
+        assert!(!manager.is_alive(&first));
+        assert!(manager.is_alive(&fourth));
+        
+        assert_eq!(
+            manager.stats(),
+            HandleStats { live: 2, evicted: 2, expired: 0 }
+        );
     }
 }
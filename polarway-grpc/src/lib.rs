@@ -11,6 +11,6 @@ pub mod proto {
 }
 
 pub use service::PolarwayDataFrameService;
-pub use handles::{HandleManager, DataFrameHandleInfo};
+pub use handles::{HandleManager, DataFrameHandleInfo, HandleStats};
 pub use error::{PolarwayError, Result};
-pub use storage::{StorageBackend, HybridStorage, ParquetBackend, CacheBackend, DuckDBBackend};
+pub use storage::{StorageBackend, AsyncStorageBackend, BlockingAdapter, HybridStorage, ParquetBackend, CacheBackend, DuckDBBackend};
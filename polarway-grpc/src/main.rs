@@ -37,8 +37,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("📈 Time-series native support");
     info!("🌐 Network data sources ready");
     
+    // Handle store: in memory, or persisted under a (shared) state directory
+    let handle_store = std::env::var("POLARWAY_HANDLE_STORE")
+        .unwrap_or_else(|_| "memory".to_string());
+    let handle_manager = match handle_store.as_str() {
+        "memory" => handles::HandleManager::default(),
+        "external" => {
+            let state_dir = std::env::var("POLARWAY_STATE_DIR")
+                .map_err(|_| "POLARWAY_HANDLE_STORE=external requires POLARWAY_STATE_DIR")?;
+            info!("💾 Persisting handles under: {}", state_dir);
+            handles::HandleManager::default().with_store_dir(state_dir)?
+        }
+        other => return Err(format!("Unknown POLARWAY_HANDLE_STORE: {}", other).into()),
+    };
+
     // Create service
-    let dataframe_service = PolarwayDataFrameService::new();
+    let dataframe_service = PolarwayDataFrameService::with_handle_manager(handle_manager);
 
     // Start HTTP REST API (QuestDB-like)
     let http_bind_addr = std::env::var("POLARWAY_HTTP_BIND_ADDRESS")
@@ -19,7 +19,12 @@ pub struct PolarwayDataFrameService {
 
 impl PolarwayDataFrameService {
     pub fn new() -> Self {
-        let handle_manager = Arc::new(HandleManager::default());
+        Self::with_handle_manager(HandleManager::default())
+    }
+
+    /// Serve handles from `handle_manager`, e.g. one with a persistent store
+    pub fn with_handle_manager(handle_manager: HandleManager) -> Self {
+        let handle_manager = Arc::new(handle_manager);
         
         // Spawn cleanup task
         let manager_clone = Arc::clone(&handle_manager);
@@ -127,6 +132,7 @@ impl PolarwayDataFrameService {
 impl DataFrameService for PolarwayDataFrameService {
     type CollectStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
     type CollectStreamingStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
+    type StreamBatchesStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
     type StreamWebSocketStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
     type StreamRestApiStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
     type StreamGrpcStream = ReceiverStream<std::result::Result<ArrowBatch, Status>>;
@@ -311,6 +317,49 @@ impl DataFrameService for PolarwayDataFrameService {
         Ok(Response::new(ReceiverStream::new(rx)))
     }
     
+    /// Stream DataFrame as Arrow IPC batches of at most `max_batch_rows` rows
+    async fn stream_batches(
+        &self,
+        request: Request<StreamBatchesRequest>,
+    ) -> std::result::Result<Response<Self::StreamBatchesStream>, Status> {
+        let req = request.into_inner();
+        info!("StreamBatches request: handle={}, max_batch_rows={}", req.handle, req.max_batch_rows);
+        
+        if req.max_batch_rows == 0 {
+            return Err(Status::invalid_argument("max_batch_rows must be greater than 0"));
+        }
+        
+        let df = self.handle_manager.get_dataframe(&req.handle)
+            .map_err(|e| Status::from(e))?;
+        
+        // A small buffer keeps encoding at most a few batches ahead of the client
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+        let max_batch_rows = req.max_batch_rows as usize;
+        
+        tokio::task::spawn_blocking(move || {
+            // An empty frame still sends one batch so the client receives the schema
+            let mut offset = 0;
+            loop {
+                let batch = df.slice(offset as i64, max_batch_rows);
+                let item = Self::dataframe_to_arrow_ipc(&batch)
+                    .map(|arrow_ipc| ArrowBatch { arrow_ipc, error: None })
+                    .map_err(|e| Status::internal(format!("Arrow conversion failed: {}", e)));
+                let failed = item.is_err();
+                
+                if tx.blocking_send(item).is_err() || failed {
+                    break;
+                }
+                
+                offset += max_batch_rows;
+                if offset >= df.height() {
+                    break;
+                }
+            }
+        });
+        
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+    
     /// Drop handle
     async fn drop_handle(
         &self,
@@ -4,13 +4,14 @@
 //! - Least Recently Used (LRU) eviction policy
 //! - Thread-safe operations with RwLock
 //! - Hit/miss statistics tracking
-//! - Configurable size limit
+//! - Byte-accurate size limit
+//! - Optional time-to-live, expired lazily on access
 
 use arrow::record_batch::RecordBatch;
 use lru::LruCache;
 use std::error::Error;
-use std::num::NonZeroUsize;
 use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use super::{StorageBackend, StorageStats};
 
@@ -21,6 +22,24 @@ struct CacheStatsInner {
     misses: u64,
 }
 
+/// A cached batch and the moment it was inserted
+struct CacheEntry {
+    batch: RecordBatch,
+    inserted_at: Instant,
+}
+
+impl CacheEntry {
+    fn size_bytes(&self) -> usize {
+        self.batch.get_array_memory_size()
+    }
+}
+
+/// Cached entries plus their cumulative Arrow memory footprint
+struct CacheState {
+    entries: LruCache<String, CacheEntry>,
+    size_bytes: usize,
+}
+
 /// LRU cache backend for hot data
 ///
 /// # Features
@@ -29,18 +48,20 @@ struct CacheStatsInner {
 /// - **Thread-Safe**: RwLock for concurrent reads, exclusive writes
 /// - **Statistics**: Hit/miss tracking for performance monitoring
 ///
-/// # Size Estimation
-/// The cache size is estimated based on:
-/// - RecordBatch schema (Arrow metadata)
-/// - Number of rows × number of columns
-/// - Approximate 8 bytes per cell (rough estimate)
+/// # Size Accounting
+/// Each entry is charged `RecordBatch::get_array_memory_size()` bytes.
+/// On every `store` the least recently used entries are evicted until the
+/// total is back under the byte budget. A batch larger than the whole
+/// budget is not cached at all.
 ///
-/// For a 2 GB cache with 100 columns:
-/// - ~250,000 rows per DataFrame
-/// - ~100 DataFrames in cache (if all same size)
+/// # Expiry
+/// With [`CacheBackend::with_ttl`], entries older than the TTL are treated
+/// as misses and evicted the next time they are loaded.
 pub struct CacheBackend {
-    cache: Arc<RwLock<LruCache<String, RecordBatch>>>,
+    cache: Arc<RwLock<CacheState>>,
     stats: Arc<RwLock<CacheStatsInner>>,
+    max_bytes: usize,
+    ttl: Option<Duration>,
 }
 
 impl CacheBackend {
@@ -54,16 +75,48 @@ impl CacheBackend {
     /// let cache = CacheBackend::new(2.0); // 2 GB cache
     /// ```
     pub fn new(max_size_gb: f64) -> Self {
-        // Estimate capacity: assume ~10 MB per DataFrame on average
-        let estimated_capacity = (max_size_gb * 1024.0 / 10.0) as usize;
-        let capacity = NonZeroUsize::new(estimated_capacity.max(1)).unwrap();
+        Self::with_max_bytes((max_size_gb * 1024.0 * 1024.0 * 1024.0) as usize)
+    }
 
+    /// Create a new cache bounded to `max_bytes` of Arrow memory
+    pub fn with_max_bytes(max_bytes: usize) -> Self {
         Self {
-            cache: Arc::new(RwLock::new(LruCache::new(capacity))),
+            cache: Arc::new(RwLock::new(CacheState {
+                entries: LruCache::unbounded(),
+                size_bytes: 0,
+            })),
             stats: Arc::new(RwLock::new(CacheStatsInner::default())),
+            max_bytes,
+            ttl: None,
         }
     }
 
+    /// Expire entries `ttl` after they were stored
+    ///
+    /// # Example
+    /// ```ignore
+    /// let cache = CacheBackend::new(2.0).with_ttl(Duration::from_secs(60));
+    /// ```
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Time-to-live applied to cached entries, if any
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Maximum number of bytes the cache may hold
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Current Arrow memory held by cached batches, in bytes
+    pub fn size_bytes(&self) -> usize {
+        self.cache.read().map(|c| c.size_bytes).unwrap_or(0)
+    }
+
     /// Record a cache hit
     fn record_hit(&self) {
         if let Ok(mut stats) = self.stats.write() {
@@ -92,13 +145,14 @@ impl CacheBackend {
     /// Clear all cached data
     pub fn clear(&self) {
         if let Ok(mut cache) = self.cache.write() {
-            cache.clear();
+            cache.entries.clear();
+            cache.size_bytes = 0;
         }
     }
 
     /// Get current number of cached items
     pub fn len(&self) -> usize {
-        self.cache.read().map(|c| c.len()).unwrap_or(0)
+        self.cache.read().map(|c| c.entries.len()).unwrap_or(0)
     }
 
     /// Check if cache is empty
@@ -109,17 +163,50 @@ impl CacheBackend {
 
 impl StorageBackend for CacheBackend {
     fn store(&self, key: &str, batch: RecordBatch) -> Result<(), Box<dyn Error>> {
+        let size = batch.get_array_memory_size();
         let mut cache = self.cache.write().map_err(|e| format!("Lock error: {}", e))?;
-        cache.put(key.to_string(), batch);
+
+        if let Some(old) = cache.entries.pop(key) {
+            cache.size_bytes -= old.size_bytes();
+        }
+        if size > self.max_bytes {
+            return Ok(());
+        }
+
+        while cache.size_bytes + size > self.max_bytes {
+            match cache.entries.pop_lru() {
+                Some((_, evicted)) => cache.size_bytes -= evicted.size_bytes(),
+                None => break,
+            }
+        }
+
+        cache.entries.put(
+            key.to_string(),
+            CacheEntry {
+                batch,
+                inserted_at: Instant::now(),
+            },
+        );
+        cache.size_bytes += size;
         Ok(())
     }
 
     fn load(&self, key: &str) -> Result<Option<RecordBatch>, Box<dyn Error>> {
         let mut cache = self.cache.write().map_err(|e| format!("Lock error: {}", e))?;
 
-        if let Some(batch) = cache.get(key) {
+        let expired = match (cache.entries.peek(key), self.ttl) {
+            (Some(entry), Some(ttl)) => entry.inserted_at.elapsed() >= ttl,
+            _ => false,
+        };
+        if expired {
+            if let Some(old) = cache.entries.pop(key) {
+                cache.size_bytes -= old.size_bytes();
+            }
+        }
+
+        if let Some(entry) = cache.entries.get(key) {
             self.record_hit();
-            Ok(Some(batch.clone()))
+            Ok(Some(entry.batch.clone()))
         } else {
             self.record_miss();
             Ok(None)
@@ -128,12 +215,14 @@ impl StorageBackend for CacheBackend {
 
     fn list_keys(&self) -> Result<Vec<String>, Box<dyn Error>> {
         let cache = self.cache.read().map_err(|e| format!("Lock error: {}", e))?;
-        Ok(cache.iter().map(|(k, _)| k.clone()).collect())
+        Ok(cache.entries.iter().map(|(k, _)| k.clone()).collect())
     }
 
     fn delete(&self, key: &str) -> Result<(), Box<dyn Error>> {
         let mut cache = self.cache.write().map_err(|e| format!("Lock error: {}", e))?;
-        cache.pop(key);
+        if let Some(old) = cache.entries.pop(key) {
+            cache.size_bytes -= old.size_bytes();
+        }
         Ok(())
     }
 
@@ -141,12 +230,9 @@ impl StorageBackend for CacheBackend {
         let cache = self.cache.read().map_err(|e| format!("Lock error: {}", e))?;
         let stats = self.stats.read().map_err(|e| format!("Lock error: {}", e))?;
 
-        // Estimate size: very rough approximation
-        let estimated_size = cache.len() * 10_000_000; // 10 MB per item estimate
-
         Ok(StorageStats {
-            total_keys: cache.len(),
-            total_size_bytes: estimated_size as u64,
+            total_keys: cache.entries.len(),
+            total_size_bytes: cache.size_bytes as u64,
             cache_hits: stats.hits,
             cache_misses: stats.misses,
             compression_ratio: 1.0, // N/A for cache
@@ -167,6 +253,12 @@ mod tests {
         RecordBatch::try_new(schema, vec![Arc::new(array)]).unwrap()
     }
 
+    fn create_sized_batch(rows: usize) -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![Field::new("value", DataType::Int64, false)]));
+        let array = Int64Array::from(vec![0; rows]);
+        RecordBatch::try_new(schema, vec![Arc::new(array)]).unwrap()
+    }
+
     #[test]
     fn test_cache_hit_miss() {
         let cache = CacheBackend::new(0.1); // 100 MB
@@ -192,12 +284,12 @@ mod tests {
 
     #[test]
     fn test_lru_eviction() {
-        let cache = CacheBackend::new(0.001); // Very small cache
+        let cache = CacheBackend::new(0.001); // Very small cache (~1 MB)
 
-        // Fill cache beyond capacity
+        // Fill cache beyond capacity (~128 KB per batch)
         for i in 0..100 {
             cache
-                .store(&format!("key{}", i), create_test_batch(i))
+                .store(&format!("key{}", i), create_sized_batch(16_384))
                 .unwrap();
         }
 
@@ -206,6 +298,41 @@ mod tests {
         assert!(keys.len() < 100);
     }
 
+    #[test]
+    fn test_byte_budget_evicts_oldest() {
+        let batch_size = create_sized_batch(10_000).get_array_memory_size();
+        let cache = CacheBackend::with_max_bytes(batch_size * 3);
+
+        for i in 0..5 {
+            cache
+                .store(&format!("key{}", i), create_sized_batch(10_000))
+                .unwrap();
+        }
+
+        // Only the three most recent batches fit in the budget
+        assert_eq!(cache.len(), 3);
+        assert!(cache.size_bytes() <= cache.max_bytes());
+        assert_eq!(cache.stats().unwrap().total_size_bytes, (batch_size * 3) as u64);
+        assert!(cache.load("key0").unwrap().is_none());
+        assert!(cache.load("key1").unwrap().is_none());
+        assert!(cache.load("key4").unwrap().is_some());
+    }
+
+    #[test]
+    fn test_ttl_expiry_is_a_miss() {
+        let cache = CacheBackend::new(0.1).with_ttl(Duration::ZERO);
+
+        cache.store("key1", create_test_batch(1)).unwrap();
+        assert!(cache.load("key1").unwrap().is_none());
+
+        // Expired entry is evicted and counted as a miss
+        let stats = cache.stats().unwrap();
+        assert_eq!(stats.cache_hits, 0);
+        assert_eq!(stats.cache_misses, 1);
+        assert_eq!(stats.total_keys, 0);
+        assert_eq!(cache.size_bytes(), 0);
+    }
+
     #[test]
     fn test_clear() {
         let cache = CacheBackend::new(0.1);
@@ -9,7 +9,9 @@
 
 use arrow::record_batch::RecordBatch;
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "duckdb")]
+use std::sync::Mutex;
 
 use super::{StorageBackend, StorageStats};
 
@@ -43,10 +45,15 @@ use super::{StorageBackend, StorageStats};
 /// ```
 pub struct DuckDBBackend {
     db_path: PathBuf,
-    // NOTE: Actual DuckDB connection will be added when duckdb-rs is integrated
-    // For now, this is a placeholder structure
+    /// Directory of Parquet files exposed as the `data` view
+    parquet_path: Option<PathBuf>,
+    #[cfg(feature = "duckdb")]
+    connection: Mutex<duckdb::Connection>,
 }
 
+/// Name of the view registered over the Parquet directory
+pub const PARQUET_VIEW: &str = "data";
+
 impl DuckDBBackend {
     /// Create a new DuckDB backend
     ///
@@ -54,35 +61,99 @@ impl DuckDBBackend {
     /// - `db_path`: Path to DuckDB database file, or ":memory:" for in-memory
     ///
     /// # Note
-    /// This is a placeholder implementation. To use DuckDB:
-    /// 1. Add `duckdb` crate to Cargo.toml: `duckdb = "0.10"`
-    /// 2. Initialize connection: `Connection::open(db_path)`
-    /// 3. Implement query execution with Arrow result conversion
+    /// Query execution requires the `duckdb` feature. Without it the backend
+    /// can still be constructed but `execute_sql` returns an error.
     pub fn new<P: Into<PathBuf>>(db_path: P) -> Result<Self, Box<dyn Error>> {
+        let db_path = db_path.into();
+
+        #[cfg(feature = "duckdb")]
+        let connection = if db_path.to_str() == Some(":memory:") {
+            duckdb::Connection::open_in_memory()?
+        } else {
+            duckdb::Connection::open(&db_path)?
+        };
+
         Ok(Self {
-            db_path: db_path.into(),
+            db_path,
+            parquet_path: None,
+            #[cfg(feature = "duckdb")]
+            connection: Mutex::new(connection),
         })
     }
 
+    /// Expose every `*.parquet` file in `path` as the [`PARQUET_VIEW`] view
+    pub fn with_parquet_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.parquet_path = Some(path.into());
+        self
+    }
+
+    /// Path of the underlying DuckDB database
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+
     /// Execute SQL query on Parquet files
     ///
+    /// When a Parquet directory is configured, its files are readable as
+    /// the `data` view. The view is recreated on every call so files
+    /// written since the last query are picked up.
+    ///
     /// # Example
     /// ```ignore
-    /// let backend = DuckDBBackend::new(":memory:")?;
-    /// let result = backend.execute_sql(
-    ///     "SELECT * FROM read_parquet('/data/cold/*.parquet') LIMIT 100"
-    /// )?;
+    /// let backend = DuckDBBackend::new(":memory:")?.with_parquet_path("/data/cold");
+    /// let result = backend.execute_sql("SELECT symbol, avg(price) FROM data GROUP BY symbol")?;
     /// ```
+    #[cfg(feature = "duckdb")]
+    pub fn execute_sql(&self, sql: &str) -> Result<RecordBatch, Box<dyn Error>> {
+        let conn = self
+            .connection
+            .lock()
+            .map_err(|_| "DuckDB connection lock poisoned")?;
+        self.register_parquet_view(&conn)?;
+
+        let mut stmt = conn.prepare(sql)?;
+        let arrow = stmt.query_arrow([])?;
+        let schema = arrow.get_schema();
+        let batches: Vec<RecordBatch> = arrow.collect();
+
+        Ok(arrow::compute::concat_batches(&schema, &batches)?)
+    }
+
+    /// Execute SQL query on Parquet files
+    ///
+    /// Always errors: rebuild with the `duckdb` feature to run queries.
+    #[cfg(not(feature = "duckdb"))]
     pub fn execute_sql(&self, sql: &str) -> Result<RecordBatch, Box<dyn Error>> {
-        // Placeholder implementation
         Err(format!(
-            "DuckDB backend not yet implemented. \
-             To enable: add 'duckdb = \"0.10\"' to Cargo.toml and implement connection.\n\
+            "DuckDB support not enabled. Rebuild with the `duckdb` feature.\n\
              Query attempted: {}",
             sql
         )
         .into())
     }
+
+    #[cfg(feature = "duckdb")]
+    fn register_parquet_view(&self, conn: &duckdb::Connection) -> Result<(), Box<dyn Error>> {
+        let Some(dir) = &self.parquet_path else {
+            return Ok(());
+        };
+
+        // read_parquet fails on an empty glob, so only bind the view once
+        // there is something to read.
+        let has_files = std::fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .any(|entry| entry.path().extension().and_then(|e| e.to_str()) == Some("parquet"));
+        if !has_files {
+            return Ok(());
+        }
+
+        let pattern = dir.join("*.parquet").to_string_lossy().replace('\'', "''");
+        conn.execute_batch(&format!(
+            "CREATE OR REPLACE VIEW {} AS SELECT * FROM read_parquet('{}')",
+            PARQUET_VIEW, pattern
+        ))?;
+        Ok(())
+    }
 }
 
 impl StorageBackend for DuckDBBackend {
@@ -123,13 +194,45 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_duckdb_placeholder() {
+    #[cfg(not(feature = "duckdb"))]
+    fn test_duckdb_disabled() {
         let backend = DuckDBBackend::new(":memory:").unwrap();
 
-        // Should return error indicating not yet implemented
+        // Should return error indicating the feature is off
         let result = backend.execute_sql("SELECT 1");
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("not yet implemented"));
+        assert!(result.unwrap_err().to_string().contains("not enabled"));
+    }
+
+    #[test]
+    #[cfg(feature = "duckdb")]
+    fn test_duckdb_counts_parquet_rows() {
+        use crate::storage::ParquetBackend;
+        use arrow::array::{Array, Int64Array};
+        use arrow::datatypes::{DataType, Field, Schema};
+        use std::sync::Arc;
+
+        let dir = tempfile::tempdir().unwrap();
+        let parquet = ParquetBackend::new(dir.path()).unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new("value", DataType::Int64, false)]));
+        for (key, rows) in [("a", 3), ("b", 4)] {
+            let array = Int64Array::from((0..rows).collect::<Vec<i64>>());
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+            parquet.store(key, batch).unwrap();
+        }
+
+        let backend = DuckDBBackend::new(":memory:")
+            .unwrap()
+            .with_parquet_path(dir.path());
+        let result = backend.query("SELECT COUNT(*) AS n FROM data").unwrap();
+
+        assert_eq!(result.num_rows(), 1);
+        let count = result
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(count.value(0), 7);
     }
 
     #[test]
@@ -141,48 +244,3 @@ mod tests {
         assert!(backend.delete("key").is_err());
     }
 }
-
-/* TODO: Full implementation with duckdb-rs
-
-use duckdb::{Connection, Result as DuckResult};
-use arrow::ffi_stream::ArrowArrayStreamReader;
-
-impl DuckDBBackend {
-    pub fn new<P: Into<PathBuf>>(db_path: P) -> Result<Self, Box<dyn Error>> {
-        let db_path = db_path.into();
-        let conn = if db_path.to_str() == Some(":memory:") {
-            Connection::open_in_memory()?
-        } else {
-            Connection::open(&db_path)?
-        };
-
-        Ok(Self {
-            db_path,
-            connection: Mutex::new(conn),
-        })
-    }
-
-    pub fn execute_sql(&self, sql: &str) -> Result<RecordBatch, Box<dyn Error>> {
-        let conn = self.connection.lock().unwrap();
-        let mut stmt = conn.prepare(sql)?;
-
-        // Execute and convert to Arrow RecordBatch
-        let arrow_stream = stmt.query_arrow([])?;
-        let reader = ArrowArrayStreamReader::try_new(arrow_stream)?;
-
-        // Collect all batches
-        let batches: Vec<RecordBatch> = reader.collect::<Result<Vec<_>, _>>()?;
-
-        if batches.is_empty() {
-            return Err("Query returned no results".into());
-        }
-
-        // Concatenate if multiple batches
-        let schema = batches[0].schema();
-        let result = arrow::compute::concat_batches(&schema, &batches)?;
-
-        Ok(result)
-    }
-}
-
-*/
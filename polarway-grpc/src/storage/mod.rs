@@ -9,8 +9,13 @@
 //! - Check cache first (fast, RAM)
 //! - Fall back to Parquet (compressed, disk)
 //! - Query via DuckDB (SQL analytics)
+//!
+//! Backends do blocking disk I/O. Async callers such as the gRPC service
+//! go through `AsyncStorageBackend`, which runs the work on Tokio's
+//! blocking pool instead of a runtime worker thread.
 
 use arrow::record_batch::RecordBatch;
+use async_trait::async_trait;
 use std::error::Error;
 use std::sync::Arc;
 
@@ -63,6 +68,109 @@ pub trait StorageBackend: Send + Sync {
     fn stats(&self) -> Result<StorageStats, Box<dyn Error>>;
 }
 
+/// Result type for async storage operations (errors must cross threads)
+pub type AsyncStorageResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Async counterpart of [`StorageBackend`] for use inside the Tokio runtime
+///
+/// Wrap any synchronous [`StorageBackend`] in a [`BlockingAdapter`] to run
+/// its methods through `tokio::task::spawn_blocking`.
+#[async_trait]
+pub trait AsyncStorageBackend: Send + Sync {
+    /// Store a DataFrame with the given key
+    async fn store(&self, key: &str, batch: RecordBatch) -> AsyncStorageResult<()>;
+
+    /// Load a DataFrame by key (returns None if not found)
+    async fn load(&self, key: &str) -> AsyncStorageResult<Option<RecordBatch>>;
+
+    /// Execute a SQL query (not all backends support this)
+    async fn query(&self, sql: &str) -> AsyncStorageResult<RecordBatch>;
+
+    /// List all available keys
+    async fn list_keys(&self) -> AsyncStorageResult<Vec<String>>;
+
+    /// Delete data by key
+    async fn delete(&self, key: &str) -> AsyncStorageResult<()>;
+
+    /// Get storage statistics
+    async fn stats(&self) -> AsyncStorageResult<StorageStats>;
+}
+
+/// Run blocking storage work on the blocking thread pool
+///
+/// `Box<dyn Error>` is not `Send`, so errors are flattened to strings
+/// before leaving the blocking thread.
+async fn run_blocking<T, F>(f: F) -> AsyncStorageResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Box<dyn Error>> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || f().map_err(|e| e.to_string()))
+        .await?
+        .map_err(Into::into)
+}
+
+/// Adapter exposing a synchronous [`StorageBackend`] as [`AsyncStorageBackend`]
+///
+/// # Example
+/// ```ignore
+/// let cold = BlockingAdapter::new(ParquetBackend::new("/data/cold")?);
+/// let batch = cold.load("BTC_USD_20260203").await?;
+/// ```
+pub struct BlockingAdapter<B> {
+    backend: Arc<B>,
+}
+
+impl<B: StorageBackend + 'static> BlockingAdapter<B> {
+    /// Wrap an owned backend
+    pub fn new(backend: B) -> Self {
+        Self::from_arc(Arc::new(backend))
+    }
+
+    /// Wrap a backend that is already shared
+    pub fn from_arc(backend: Arc<B>) -> Self {
+        Self { backend }
+    }
+
+    /// Access the wrapped synchronous backend
+    pub fn inner(&self) -> &Arc<B> {
+        &self.backend
+    }
+}
+
+#[async_trait]
+impl<B: StorageBackend + 'static> AsyncStorageBackend for BlockingAdapter<B> {
+    async fn store(&self, key: &str, batch: RecordBatch) -> AsyncStorageResult<()> {
+        let (backend, key) = (Arc::clone(&self.backend), key.to_string());
+        run_blocking(move || backend.store(&key, batch)).await
+    }
+
+    async fn load(&self, key: &str) -> AsyncStorageResult<Option<RecordBatch>> {
+        let (backend, key) = (Arc::clone(&self.backend), key.to_string());
+        run_blocking(move || backend.load(&key)).await
+    }
+
+    async fn query(&self, sql: &str) -> AsyncStorageResult<RecordBatch> {
+        let (backend, sql) = (Arc::clone(&self.backend), sql.to_string());
+        run_blocking(move || backend.query(&sql)).await
+    }
+
+    async fn list_keys(&self) -> AsyncStorageResult<Vec<String>> {
+        let backend = Arc::clone(&self.backend);
+        run_blocking(move || backend.list_keys()).await
+    }
+
+    async fn delete(&self, key: &str) -> AsyncStorageResult<()> {
+        let (backend, key) = (Arc::clone(&self.backend), key.to_string());
+        run_blocking(move || backend.delete(&key)).await
+    }
+
+    async fn stats(&self) -> AsyncStorageResult<StorageStats> {
+        let backend = Arc::clone(&self.backend);
+        run_blocking(move || backend.stats()).await
+    }
+}
+
 /// Hybrid storage combining cache, cold storage, and SQL analytics
 ///
 /// This is the recommended storage backend for Polarway, providing:
@@ -95,6 +203,10 @@ pub trait StorageBackend: Send + Sync {
 /// │  (Queries)  │
 /// └─────────────┘
 /// ```
+///
+/// All operations are exposed through [`AsyncStorageBackend`]; cloning is
+/// cheap since every backend is shared behind an `Arc`.
+#[derive(Clone)]
 pub struct HybridStorage {
     /// LRU cache for hot data (typically 1-2 GB)
     cache: Arc<CacheBackend>,
@@ -102,6 +214,8 @@ pub struct HybridStorage {
     cold_storage: Arc<ParquetBackend>,
     /// DuckDB backend for SQL queries
     duckdb: Arc<DuckDBBackend>,
+    /// Copy Parquet hits into the cache on read (default: true)
+    promote_on_read: bool,
 }
 
 impl HybridStorage {
@@ -117,18 +231,29 @@ impl HybridStorage {
         cache_size_gb: f64,
     ) -> Result<Self, Box<dyn Error>> {
         let cache = Arc::new(CacheBackend::new(cache_size_gb));
-        let cold_storage = Arc::new(ParquetBackend::new(parquet_path)?);
-        let duckdb = Arc::new(DuckDBBackend::new(duckdb_path)?);
+        let cold_storage = Arc::new(ParquetBackend::new(&parquet_path)?);
+        let duckdb = Arc::new(DuckDBBackend::new(duckdb_path)?.with_parquet_path(parquet_path));
 
         Ok(Self {
             cache,
             cold_storage,
             duckdb,
+            promote_on_read: true,
         })
     }
 
+    /// Enable or disable read-through promotion of Parquet hits into the cache
+    ///
+    /// Disable it for one-off scans of cold data that would otherwise
+    /// evict the hot working set.
+    pub fn with_promote_on_read(mut self, promote: bool) -> Self {
+        self.promote_on_read = promote;
+        self
+    }
+
     /// Smart load: check cache first, then Parquet, warm cache on miss
-    pub fn smart_load(&self, key: &str) -> Result<Option<RecordBatch>, Box<dyn Error>> {
+    /// (unless promotion was disabled with `with_promote_on_read(false)`)
+    fn smart_load(&self, key: &str) -> Result<Option<RecordBatch>, Box<dyn Error>> {
         // Try cache first
         if let Some(batch) = self.cache.load(key)? {
             return Ok(Some(batch));
@@ -137,7 +262,9 @@ impl HybridStorage {
         // Cache miss - load from Parquet
         if let Some(batch) = self.cold_storage.load(key)? {
             // Warm the cache for next access
-            self.cache.store(key, batch.clone())?;
+            if self.promote_on_read {
+                self.cache.store(key, batch.clone())?;
+            }
             return Ok(Some(batch));
         }
 
@@ -146,36 +273,23 @@ impl HybridStorage {
     }
 }
 
-impl StorageBackend for HybridStorage {
-    fn store(&self, key: &str, batch: RecordBatch) -> Result<(), Box<dyn Error>> {
+// Blocking implementations behind the async surface
+impl HybridStorage {
+    fn store_blocking(&self, key: &str, batch: RecordBatch) -> Result<(), Box<dyn Error>> {
         // Store in both cache and cold storage
         self.cache.store(key, batch.clone())?;
         self.cold_storage.store(key, batch)?;
         Ok(())
     }
 
-    fn load(&self, key: &str) -> Result<Option<RecordBatch>, Box<dyn Error>> {
-        self.smart_load(key)
-    }
-
-    fn query(&self, sql: &str) -> Result<RecordBatch, Box<dyn Error>> {
-        // Delegate SQL queries to DuckDB
-        self.duckdb.query(sql)
-    }
-
-    fn list_keys(&self) -> Result<Vec<String>, Box<dyn Error>> {
-        // List from cold storage (authoritative source)
-        self.cold_storage.list_keys()
-    }
-
-    fn delete(&self, key: &str) -> Result<(), Box<dyn Error>> {
+    fn delete_blocking(&self, key: &str) -> Result<(), Box<dyn Error>> {
         // Delete from both cache and cold storage
         self.cache.delete(key)?;
         self.cold_storage.delete(key)?;
         Ok(())
     }
 
-    fn stats(&self) -> Result<StorageStats, Box<dyn Error>> {
+    fn stats_blocking(&self) -> Result<StorageStats, Box<dyn Error>> {
         let cache_stats = self.cache.stats()?;
         let cold_stats = self.cold_storage.stats()?;
 
@@ -189,6 +303,41 @@ impl StorageBackend for HybridStorage {
     }
 }
 
+#[async_trait]
+impl AsyncStorageBackend for HybridStorage {
+    async fn store(&self, key: &str, batch: RecordBatch) -> AsyncStorageResult<()> {
+        let (this, key) = (self.clone(), key.to_string());
+        run_blocking(move || this.store_blocking(&key, batch)).await
+    }
+
+    async fn load(&self, key: &str) -> AsyncStorageResult<Option<RecordBatch>> {
+        let (this, key) = (self.clone(), key.to_string());
+        run_blocking(move || this.smart_load(&key)).await
+    }
+
+    async fn query(&self, sql: &str) -> AsyncStorageResult<RecordBatch> {
+        // Delegate SQL queries to DuckDB
+        let (duckdb, sql) = (Arc::clone(&self.duckdb), sql.to_string());
+        run_blocking(move || duckdb.query(&sql)).await
+    }
+
+    async fn list_keys(&self) -> AsyncStorageResult<Vec<String>> {
+        // List from cold storage (authoritative source)
+        let cold_storage = Arc::clone(&self.cold_storage);
+        run_blocking(move || cold_storage.list_keys()).await
+    }
+
+    async fn delete(&self, key: &str) -> AsyncStorageResult<()> {
+        let (this, key) = (self.clone(), key.to_string());
+        run_blocking(move || this.delete_blocking(&key)).await
+    }
+
+    async fn stats(&self) -> AsyncStorageResult<StorageStats> {
+        let this = self.clone();
+        run_blocking(move || this.stats_blocking()).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,8 +351,8 @@ mod tests {
         RecordBatch::try_new(schema, vec![ArrowArc::new(array)]).unwrap()
     }
 
-    #[test]
-    fn test_hybrid_storage_lifecycle() {
+    #[tokio::test]
+    async fn test_hybrid_storage_lifecycle() {
         let storage = HybridStorage::new(
             "/tmp/test_parquet".to_string(),
             ":memory:".to_string(),
@@ -214,21 +363,73 @@ mod tests {
         let batch = create_test_batch();
 
         // Store
-        storage.store("test_key", batch.clone()).unwrap();
+        storage.store("test_key", batch.clone()).await.unwrap();
 
         // Load (should hit cache)
-        let loaded = storage.load("test_key").unwrap();
+        let loaded = storage.load("test_key").await.unwrap();
         assert!(loaded.is_some());
         assert_eq!(loaded.unwrap().num_rows(), 5);
 
         // Stats
-        let stats = storage.stats().unwrap();
+        let stats = storage.stats().await.unwrap();
         assert_eq!(stats.total_keys, 1);
         assert_eq!(stats.cache_hits, 1);
 
         // Delete
-        storage.delete("test_key").unwrap();
-        let deleted = storage.load("test_key").unwrap();
+        storage.delete("test_key").await.unwrap();
+        let deleted = storage.load("test_key").await.unwrap();
         assert!(deleted.is_none());
     }
+
+    #[tokio::test]
+    async fn test_blocking_adapter_for_sync_backend() {
+        let cache = BlockingAdapter::new(CacheBackend::new(0.1));
+
+        cache.store("key", create_test_batch()).await.unwrap();
+        let loaded = cache.load("key").await.unwrap();
+        assert_eq!(loaded.unwrap().num_rows(), 5);
+        assert_eq!(cache.stats().await.unwrap().cache_hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_parquet_hit_promoted_to_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = HybridStorage::new(
+            dir.path().to_string_lossy().into_owned(),
+            ":memory:".to_string(),
+            0.1,
+        )
+        .unwrap();
+
+        // Only on disk, not in cache
+        storage.cold_storage.store("cold_key", create_test_batch()).unwrap();
+
+        assert!(storage.load("cold_key").await.unwrap().is_some());
+        assert!(storage.load("cold_key").await.unwrap().is_some());
+
+        let stats = storage.stats().await.unwrap();
+        assert_eq!(stats.cache_misses, 1);
+        assert_eq!(stats.cache_hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_promotion_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = HybridStorage::new(
+            dir.path().to_string_lossy().into_owned(),
+            ":memory:".to_string(),
+            0.1,
+        )
+        .unwrap()
+        .with_promote_on_read(false);
+
+        storage.cold_storage.store("cold_key", create_test_batch()).unwrap();
+
+        assert!(storage.load("cold_key").await.unwrap().is_some());
+        assert!(storage.load("cold_key").await.unwrap().is_some());
+
+        let stats = storage.stats().await.unwrap();
+        assert_eq!(stats.cache_misses, 2);
+        assert_eq!(stats.cache_hits, 0);
+    }
 }
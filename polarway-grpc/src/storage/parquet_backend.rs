@@ -5,6 +5,7 @@
 //! - Column-oriented storage (efficient for analytics)
 //! - Schema evolution support
 //! - Append-only architecture (no updates)
+//! - Incremental appends as part files, merged on demand by `compact`
 
 use arrow::record_batch::RecordBatch;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
@@ -31,7 +32,9 @@ use super::{StorageBackend, StorageStats};
 /// parquet_path/
 ///   ├── BTC_USD_20260203.parquet
 ///   ├── ETH_USD_20260203.parquet
-///   └── trades_daily_20260203.parquet
+///   ├── trades_daily_20260203.parquet
+///   ├── trades_daily_20260203.part1.parquet   (from append)
+///   └── trades_daily_20260203.part2.parquet
 /// ```
 pub struct ParquetBackend {
     base_path: PathBuf,
@@ -74,7 +77,7 @@ impl ParquetBackend {
     fn sanitize_key(&self, key: &str) -> Result<String, Box<dyn Error>> {
         // Replace dangerous characters
         let sanitized = key
-            .replace(['/', '\\', '..'], "_")
+            .replace(['/', '\\', '.'], "_")
             .replace(' ', "_");
 
         if sanitized.is_empty() {
@@ -91,6 +94,128 @@ impl ParquetBackend {
         Ok(self.base_path.join(filename))
     }
 
+    /// Split a file stem into its key and optional part number
+    ///
+    /// `trades.part3` → (`trades`, Some(3)); `trades` → (`trades`, None).
+    /// Sanitized keys never contain '.', so only `append` produces a suffix.
+    fn split_part(stem: &str) -> (&str, Option<u64>) {
+        if let Some((key, part)) = stem.rsplit_once(".part") {
+            if let Ok(n) = part.parse() {
+                return (key, Some(n));
+            }
+        }
+        (stem, None)
+    }
+
+    /// Part files written by `append` for a key, ordered by part number
+    fn part_paths(&self, key: &str) -> Result<Vec<(u64, PathBuf)>, Box<dyn Error>> {
+        let sanitized = self.sanitize_key(key)?;
+        let mut parts: Vec<(u64, PathBuf)> = self
+            .list_parquet_files()?
+            .into_iter()
+            .filter_map(|path| {
+                let stem = path.file_stem()?.to_str()?;
+                match Self::split_part(stem) {
+                    (k, Some(n)) if k == sanitized => Some((n, path.clone())),
+                    _ => None,
+                }
+            })
+            .collect();
+        parts.sort_by_key(|(n, _)| *n);
+        Ok(parts)
+    }
+
+    /// Base file (if any) followed by all part files for a key
+    fn key_files(&self, key: &str) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+        let mut files = Vec::new();
+        let base = self.key_to_path(key)?;
+        if base.exists() {
+            files.push(base);
+        }
+        files.extend(self.part_paths(key)?.into_iter().map(|(_, path)| path));
+        Ok(files)
+    }
+
+    /// Write batches to a single Parquet file
+    fn write_file(&self, path: &Path, batches: &[RecordBatch]) -> Result<(), Box<dyn Error>> {
+        let Some(first) = batches.first() else {
+            return Err("Nothing to write".into());
+        };
+
+        // Create writer with high compression
+        let file = File::create(path)?;
+        let mut writer = ArrowWriter::try_new(file, first.schema(), Some(self.writer_props.clone()))?;
+
+        for batch in batches {
+            writer.write(batch)?;
+        }
+
+        // Finalize (writes footer and flushes)
+        writer.close()?;
+
+        Ok(())
+    }
+
+    /// Read every batch from a single Parquet file
+    fn read_file(path: &Path) -> Result<Vec<RecordBatch>, Box<dyn Error>> {
+        let file = File::open(path)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+        Ok(reader.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Append a batch as a new part file without rewriting existing data
+    ///
+    /// Parts are named `{key}.partN.parquet` and are read back by `load`
+    /// together with the base file. Call `compact` to merge them.
+    pub fn append(&self, key: &str, batch: RecordBatch) -> Result<(), Box<dyn Error>> {
+        let sanitized = self.sanitize_key(key)?;
+
+        // Acquire write lock (Parquet writers not thread-safe)
+        let _lock = self.write_lock.lock().unwrap();
+
+        let next = self
+            .part_paths(key)?
+            .last()
+            .map_or(1, |(n, _)| n + 1);
+        let path = self.base_path.join(format!("{}.part{}.parquet", sanitized, next));
+
+        self.write_file(&path, &[batch])
+    }
+
+    /// Number of files (base plus parts) currently backing a key
+    pub fn part_count(&self, key: &str) -> Result<usize, Box<dyn Error>> {
+        Ok(self.key_files(key)?.len())
+    }
+
+    /// Merge the base file and all part files for a key into one file
+    ///
+    /// The merged file is written under a temporary name and renamed over
+    /// the base file before the parts are removed.
+    pub fn compact(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        let _lock = self.write_lock.lock().unwrap();
+
+        let files = self.key_files(key)?;
+        if files.len() <= 1 {
+            return Ok(());
+        }
+
+        let mut batches = Vec::new();
+        for path in &files {
+            batches.extend(Self::read_file(path)?);
+        }
+
+        let base = self.key_to_path(key)?;
+        let tmp = base.with_extension("parquet.tmp");
+        self.write_file(&tmp, &batches)?;
+        fs::rename(&tmp, &base)?;
+
+        for (_, part) in self.part_paths(key)? {
+            fs::remove_file(part)?;
+        }
+
+        Ok(())
+    }
+
     /// List all Parquet files in the base directory
     fn list_parquet_files(&self) -> Result<Vec<PathBuf>, Box<dyn Error>> {
         let mut files = Vec::new();
@@ -146,34 +271,28 @@ impl StorageBackend for ParquetBackend {
         // Acquire write lock (Parquet writers not thread-safe)
         let _lock = self.write_lock.lock().unwrap();
 
-        // Create writer with high compression
-        let file = File::create(&path)?;
-        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(self.writer_props.clone()))?;
-
-        // Write the batch
-        writer.write(&batch)?;
-
-        // Finalize (writes footer and flushes)
-        writer.close()?;
+        // Overwrite replaces any appended parts as well
+        for (_, part) in self.part_paths(key)? {
+            fs::remove_file(part)?;
+        }
 
-        Ok(())
+        self.write_file(&path, &[batch])
     }
 
     fn load(&self, key: &str) -> Result<Option<RecordBatch>, Box<dyn Error>> {
-        let path = self.key_to_path(key)?;
+        // Keep compact and store from swapping files mid-read
+        let _lock = self.write_lock.lock().unwrap();
 
-        if !path.exists() {
+        let files = self.key_files(key)?;
+
+        if files.is_empty() {
             return Ok(None);
         }
 
-        let file = File::open(&path)?;
-        let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
-        let mut reader = builder.build()?;
-
-        // Read all batches and concatenate
+        // Read all batches from the base file and every part
         let mut batches = Vec::new();
-        while let Some(batch) = reader.next() {
-            batches.push(batch?);
+        for path in &files {
+            batches.extend(Self::read_file(path)?);
         }
 
         if batches.is_empty() {
@@ -190,22 +309,24 @@ impl StorageBackend for ParquetBackend {
     fn list_keys(&self) -> Result<Vec<String>, Box<dyn Error>> {
         let files = self.list_parquet_files()?;
 
-        let keys: Vec<String> = files
+        let mut keys: Vec<String> = files
             .iter()
             .filter_map(|path| {
                 path.file_stem()
                     .and_then(|stem| stem.to_str())
-                    .map(|s| s.to_string())
+                    .map(|s| Self::split_part(s).0.to_string())
             })
             .collect();
+        keys.sort();
+        keys.dedup();
 
         Ok(keys)
     }
 
     fn delete(&self, key: &str) -> Result<(), Box<dyn Error>> {
-        let path = self.key_to_path(key)?;
+        let _lock = self.write_lock.lock().unwrap();
 
-        if path.exists() {
+        for path in self.key_files(key)? {
             fs::remove_file(&path)?;
         }
 
@@ -214,7 +335,7 @@ impl StorageBackend for ParquetBackend {
 
     fn stats(&self) -> Result<StorageStats, Box<dyn Error>> {
         let files = self.list_parquet_files()?;
-        let total_keys = files.len();
+        let total_keys = self.list_keys()?.len();
 
         let mut total_size_bytes = 0u64;
         for file in &files {
@@ -299,4 +420,39 @@ mod tests {
         assert!(keys.contains(&"______etc_passwd".to_string()));
         assert!(keys.contains(&"data_with_slashes".to_string()));
     }
+
+    #[test]
+    fn test_part_like_key_is_not_a_part() {
+        let dir = tempdir().unwrap();
+        let backend = ParquetBackend::new(dir.path()).unwrap();
+
+        backend.store("foo", create_test_batch()).unwrap();
+        backend.store("foo.part2", create_test_batch()).unwrap();
+
+        assert_eq!(backend.part_count("foo").unwrap(), 1);
+        assert_eq!(backend.load("foo").unwrap().unwrap().num_rows(), 5);
+        assert_eq!(
+            backend.list_keys().unwrap(),
+            vec!["foo".to_string(), "foo_part2".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_append_and_compact() {
+        let dir = tempdir().unwrap();
+        let backend = ParquetBackend::new(dir.path()).unwrap();
+
+        for _ in 0..3 {
+            backend.append("ticks", create_test_batch()).unwrap();
+        }
+        assert_eq!(backend.part_count("ticks").unwrap(), 3);
+        assert_eq!(backend.list_keys().unwrap(), vec!["ticks".to_string()]);
+
+        let loaded = backend.load("ticks").unwrap().unwrap();
+        assert_eq!(loaded.num_rows(), 15);
+
+        backend.compact("ticks").unwrap();
+        assert_eq!(backend.part_count("ticks").unwrap(), 1);
+        assert_eq!(backend.load("ticks").unwrap().unwrap().num_rows(), 15);
+    }
 }
@@ -186,6 +186,81 @@ async fn grpc_collect_returns_decodable_arrow_ipc() {
     let _ = shutdown_tx.send(());
 }
 
+#[tokio::test]
+async fn grpc_stream_batches_reassembles_into_original_frame() {
+    let (endpoint, shutdown_tx) = spawn_grpc_server().await;
+    let mut client = connect_client(&endpoint).await;
+
+    let input_path = unique_tmp_path("parquet");
+
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), (0..10i64).collect::<Vec<_>>()).into(),
+        Series::new("value".into(), (0..10).map(|i| i as f64 * 1.5).collect::<Vec<_>>()).into(),
+    ])
+    .expect("df");
+
+    {
+        let mut f = std::fs::File::create(&input_path).expect("create parquet");
+        ParquetWriter::new(&mut f)
+            .finish(&mut df.clone())
+            .expect("write parquet");
+    }
+
+    let handle = client
+        .read_parquet(ReadParquetRequest {
+            path: input_path.to_string_lossy().to_string(),
+            columns: vec![],
+            predicate: None,
+            n_rows: None,
+            row_index_offset: None,
+            parallel: false,
+        })
+        .await
+        .expect("read_parquet")
+        .into_inner()
+        .handle;
+
+    let err = client
+        .stream_batches(StreamBatchesRequest { handle: handle.clone(), max_batch_rows: 0 })
+        .await
+        .expect_err("max_batch_rows = 0 should be rejected");
+
+    assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+    let mut stream = client
+        .stream_batches(StreamBatchesRequest { handle, max_batch_rows: 4 })
+        .await
+        .expect("stream_batches")
+        .into_inner();
+
+    let mut heights = Vec::new();
+    let mut reassembled: Option<DataFrame> = None;
+
+    while let Some(batch) = tokio::time::timeout(Duration::from_secs(5), stream.message())
+        .await
+        .expect("timeout")
+        .expect("stream message")
+    {
+        let decoded = polars::io::ipc::IpcReader::new(std::io::Cursor::new(batch.arrow_ipc))
+            .finish()
+            .expect("decode ipc");
+
+        heights.push(decoded.height());
+        match reassembled.as_mut() {
+            Some(acc) => {
+                acc.vstack_mut(&decoded).expect("vstack");
+            }
+            None => reassembled = Some(decoded),
+        }
+    }
+
+    assert_eq!(heights, vec![4, 4, 2]);
+    assert!(reassembled.expect("at least one batch").equals(&df));
+
+    let _ = std::fs::remove_file(&input_path);
+    let _ = shutdown_tx.send(());
+}
+
 #[tokio::test]
 async fn grpc_stream_rest_api_streams_batches() {
     async fn handler() -> &'static str {
@@ -24,6 +24,7 @@
 //!         Some("strategy-456".into()),
 //!         "Backtest on BTC/USD 1m".into(),
 //!         None,
+//!         None,
 //!     ).await;
 //!
 //!     // Query billing summary
@@ -37,8 +38,14 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
-use deltalake::arrow::array::{Array, ArrayRef, RecordBatch, StringArray, UInt64Array};
+use chrono::{DateTime, NaiveDate, Utc};
+use deltalake::arrow::array::{
+    Array, ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray, UInt64Array,
+};
+use deltalake::arrow::csv::WriterBuilder;
+use deltalake::parquet::arrow::ArrowWriter;
+use deltalake::parquet::errors::ParquetError;
+use serde_json::json;
 use tokio::sync::{mpsc, oneshot};
 use tracing::{info, warn};
 use uuid::Uuid;
@@ -59,6 +66,7 @@ enum AuditMsg {
         resource: Option<String>,
         detail: String,
         ip_address: Option<String>,
+        user_agent: Option<String>,
     },
     GetUserActivity {
         user_id: String,
@@ -75,6 +83,18 @@ enum AuditMsg {
         limit: usize,
         reply: oneshot::Sender<Vec<AuditEntry>>,
     },
+    GetEventsPage {
+        before_timestamp: Option<String>,
+        limit: usize,
+        reply: oneshot::Sender<Vec<AuditEntry>>,
+    },
+    Export {
+        user_id: Option<String>,
+        start_date: String,
+        end_date: String,
+        format: ExportFormat,
+        reply: oneshot::Sender<Result<Vec<u8>>>,
+    },
 }
 
 // ─── Actor ───
@@ -98,8 +118,8 @@ impl AuditActor {
     async fn run(mut self) {
         while let Some(msg) = self.rx.recv().await {
             match msg {
-                AuditMsg::Log { user_id, username, action, resource, detail, ip_address } => {
-                    if let Err(e) = self.handle_log(user_id, username, action, resource, detail, ip_address).await {
+                AuditMsg::Log { user_id, username, action, resource, detail, ip_address, user_agent } => {
+                    if let Err(e) = self.handle_log(user_id, username, action, resource, detail, ip_address, user_agent).await {
                         warn!(error = ?e, "Failed to write audit log");
                     }
                 }
@@ -112,11 +132,20 @@ impl AuditActor {
                 AuditMsg::GetRecentEvents { limit, reply } => {
                     let _ = reply.send(self.handle_recent_events(limit).await);
                 }
+                AuditMsg::GetEventsPage { before_timestamp, limit, reply } => {
+                    let _ = reply.send(self.handle_events_page(before_timestamp.as_deref(), limit).await);
+                }
+                AuditMsg::Export { user_id, start_date, end_date, format, reply } => {
+                    let _ = reply.send(
+                        self.handle_export(user_id.as_deref(), &start_date, &end_date, format).await,
+                    );
+                }
             }
         }
         info!("AuditActor stopped");
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn handle_log(
         &self,
         user_id: String,
@@ -125,23 +154,28 @@ impl AuditActor {
         resource: Option<String>,
         detail: String,
         ip_address: Option<String>,
+        user_agent: Option<String>,
     ) -> Result<()> {
         let event_id = Uuid::new_v4().to_string();
         let now = Utc::now();
         let timestamp = now.to_rfc3339();
         let date_partition = now.format("%Y-%m-%d").to_string();
 
+        // The table has no username/detail columns — both live in details_json
+        let details_json = json!({ "username": username, "detail": detail }).to_string();
+
+        // Column order must match audit_log_arrow_schema()
         let batch = RecordBatch::try_new(
             Arc::new(schema::audit_log_arrow_schema()),
             vec![
                 Arc::new(StringArray::from(vec![event_id.as_str()])) as ArrayRef,
+                Arc::new(StringArray::from(vec![timestamp.as_str()])),
                 Arc::new(StringArray::from(vec![user_id.as_str()])),
-                Arc::new(StringArray::from(vec![username.as_str()])),
                 Arc::new(StringArray::from(vec![action.as_str()])),
                 Arc::new(StringArray::from(vec![resource.as_deref()])),
-                Arc::new(StringArray::from(vec![detail.as_str()])),
+                Arc::new(StringArray::from(vec![Some(details_json.as_str())])),
                 Arc::new(StringArray::from(vec![ip_address.as_deref()])),
-                Arc::new(StringArray::from(vec![timestamp.as_str()])),
+                Arc::new(StringArray::from(vec![user_agent.as_deref()])),
                 Arc::new(StringArray::from(vec![date_partition.as_str()])),
             ],
         )?;
@@ -152,7 +186,7 @@ impl AuditActor {
 
     async fn handle_user_activity(&self, user_id: &str, limit: usize) -> Vec<AuditEntry> {
         let sql = format!(
-            "SELECT * FROM audit_log WHERE user_id = '{}' ORDER BY timestamp DESC LIMIT {}",
+            "SELECT * FROM t WHERE user_id = '{}' ORDER BY timestamp DESC LIMIT {}",
             user_id, limit
         );
         self.query_entries_sql(&sql).await.unwrap_or_default()
@@ -160,12 +194,27 @@ impl AuditActor {
 
     async fn handle_recent_events(&self, limit: usize) -> Vec<AuditEntry> {
         let sql = format!(
-            "SELECT * FROM audit_log ORDER BY timestamp DESC LIMIT {}",
+            "SELECT * FROM t ORDER BY timestamp DESC LIMIT {}",
             limit
         );
         self.query_entries_sql(&sql).await.unwrap_or_default()
     }
 
+    async fn handle_events_page(&self, before_timestamp: Option<&str>, limit: usize) -> Vec<AuditEntry> {
+        let sql = match before_timestamp {
+            // Cursors are entry timestamps; anything else never reaches the SQL
+            Some(cursor) if DateTime::parse_from_rfc3339(cursor).is_err() => {
+                warn!(cursor, "Rejected audit page cursor that is not an RFC 3339 timestamp");
+                return vec![];
+            }
+            Some(cursor) => format!(
+                "SELECT * FROM t WHERE timestamp < '{cursor}' ORDER BY timestamp DESC LIMIT {limit}"
+            ),
+            None => format!("SELECT * FROM t ORDER BY timestamp DESC LIMIT {limit}"),
+        };
+        self.query_entries_sql(&sql).await.unwrap_or_default()
+    }
+
     async fn handle_billing_summary(
         &self,
         user_id: &str,
@@ -177,7 +226,7 @@ impl AuditActor {
             r#"SELECT
                 action,
                 COUNT(*) as cnt
-            FROM audit_log
+            FROM t
             WHERE user_id = '{user_id}'
                 AND date_partition >= '{start_date}'
                 AND date_partition <= '{end_date}'
@@ -196,6 +245,7 @@ impl AuditActor {
             total_backtests: 0,
             total_live_trades: 0,
             total_actions: 0,
+            total_credits: 0.0,
         };
 
         for batch in &batches {
@@ -211,6 +261,7 @@ impl AuditActor {
                     let action = actions.value(i);
                     let count = counts.value(i);
                     summary.total_actions += count;
+                    summary.total_credits += count as f64 * ActionType::from_str(action).credit_weight();
 
                     match action {
                         "query_executed" => summary.total_queries += count,
@@ -224,37 +275,124 @@ impl AuditActor {
             }
         }
 
+        summary.total_credits += self.metered_credits(user_id, start_date, end_date).await?;
         Ok(summary)
     }
 
-    fn extract_entry_from_batch(batch: &RecordBatch, i: usize) -> Option<AuditEntry> {
-        let get_str = |col: usize| -> &str {
-            batch.column(col)
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .map(|a| a.value(i))
-                .unwrap_or("")
-        };
+    /// Credits for compute time and rows scanned, from `user_actions`
+    async fn metered_credits(&self, user_id: &str, start_date: &str, end_date: &str) -> Result<f64> {
+        let sql = format!(
+            r#"SELECT
+                SUM(compute_time_ms) as compute_ms,
+                SUM(row_count) as rows
+            FROM t
+            WHERE user_id = '{user_id}'
+                AND date_partition >= '{start_date}'
+                AND date_partition <= '{end_date}'"#,
+        );
+
+        let batches = self.store.sql(schema::TABLE_USER_ACTIONS, &sql).await?;
+
+        let mut credits = 0.0;
+        for batch in &batches {
+            let compute_ms = batch.column(0).as_any().downcast_ref::<Float64Array>();
+            let rows = batch.column(1).as_any().downcast_ref::<Int64Array>();
 
-        let get_opt = |col: usize| -> Option<String> {
-            batch.column(col)
+            for i in 0..batch.num_rows() {
+                // SUM over no rows (or only nulls) is null
+                if let Some(ms) = compute_ms.filter(|a| !a.is_null(i)) {
+                    credits += ms.value(i) / 1000.0 * CREDITS_PER_COMPUTE_SECOND;
+                }
+                if let Some(rows) = rows.filter(|a| !a.is_null(i)) {
+                    credits += rows.value(i) as f64 / 1_000_000.0 * CREDITS_PER_MILLION_ROWS;
+                }
+            }
+        }
+        Ok(credits)
+    }
+
+    async fn handle_export(
+        &self,
+        user_id: Option<&str>,
+        start_date: &str,
+        end_date: &str,
+        format: ExportFormat,
+    ) -> Result<Vec<u8>> {
+        for date in [start_date, end_date] {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(|_| {
+                LakehouseError::InvalidAuditQuery(format!("expected a YYYY-MM-DD date, got {date:?}"))
+            })?;
+        }
+
+        // date_partition bounds prune whole partitions before any file is read
+        let mut sql = format!(
+            "SELECT * FROM t WHERE date_partition >= '{start_date}' AND date_partition <= '{end_date}'"
+        );
+        if let Some(user_id) = user_id {
+            let user_id = user_id.replace('\'', "''");
+            sql.push_str(&format!(" AND user_id = '{user_id}'"));
+        }
+        sql.push_str(" ORDER BY timestamp");
+
+        let batches = self.store.sql(schema::TABLE_AUDIT_LOG, &sql).await?;
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+
+        let mut buf = Vec::new();
+        match format {
+            ExportFormat::Csv => {
+                let mut writer = WriterBuilder::new().with_header(true).build(&mut buf);
+                for batch in &batches {
+                    writer.write(batch)?;
+                }
+            }
+            ExportFormat::Parquet => {
+                let schema = batches
+                    .first()
+                    .map(|b| b.schema())
+                    .unwrap_or_else(|| Arc::new(schema::audit_log_arrow_schema()));
+                let to_err = |e: ParquetError| LakehouseError::Serialization(e.to_string());
+                let mut writer = ArrowWriter::try_new(&mut buf, schema, None).map_err(to_err)?;
+                for batch in &batches {
+                    writer.write(batch).map_err(to_err)?;
+                }
+                writer.close().map_err(to_err)?;
+            }
+        }
+
+        info!(rows, bytes = buf.len(), format = ?format, "Exported audit log");
+        Ok(buf)
+    }
+
+    fn extract_entry_from_batch(batch: &RecordBatch, i: usize) -> Option<AuditEntry> {
+        // Look columns up by name; partition columns may be reordered on read
+        let get_opt = |name: &str| -> Option<String> {
+            batch.column_by_name(name)?
                 .as_any()
                 .downcast_ref::<StringArray>()
                 .and_then(|a| {
                     if a.is_null(i) { None } else { Some(a.value(i).to_string()) }
                 })
         };
+        let get_str = |name: &str| -> String { get_opt(name).unwrap_or_default() };
+
+        let details: serde_json::Value = get_opt("details_json")
+            .and_then(|d| serde_json::from_str(&d).ok())
+            .unwrap_or_default();
+        let detail_field = |key: &str| -> String {
+            details.get(key).and_then(|v| v.as_str()).unwrap_or("").to_string()
+        };
 
         Some(AuditEntry {
-            event_id: get_str(0).to_string(),
-            user_id: get_str(1).to_string(),
-            username: get_str(2).to_string(),
-            action: ActionType::from_str(get_str(3)),
-            resource: get_opt(4),
-            detail: get_str(5).to_string(),
-            ip_address: get_opt(6),
-            timestamp: get_str(7).to_string(),
-            date_partition: get_str(8).to_string(),
+            event_id: get_str("event_id"),
+            user_id: get_str("user_id"),
+            username: detail_field("username"),
+            action: ActionType::from_str(&get_str("action")),
+            resource: get_opt("resource"),
+            detail: detail_field("detail"),
+            ip_address: get_opt("ip_address"),
+            user_agent: get_opt("user_agent"),
+            timestamp: get_str("timestamp"),
+            date_partition: get_str("date_partition"),
         })
     }
 
@@ -282,6 +420,7 @@ pub struct AuditHandle {
 
 impl AuditHandle {
     /// Log an audit event (fire-and-forget — does not block)
+    #[allow(clippy::too_many_arguments)]
     pub async fn log(
         &self,
         user_id: String,
@@ -290,9 +429,10 @@ impl AuditHandle {
         resource: Option<String>,
         detail: String,
         ip_address: Option<String>,
+        user_agent: Option<String>,
     ) {
         let _ = self.tx.send(AuditMsg::Log {
-            user_id, username, action, resource, detail, ip_address,
+            user_id, username, action, resource, detail, ip_address, user_agent,
         }).await;
     }
 
@@ -329,4 +469,45 @@ impl AuditHandle {
         }
         rx.await.unwrap_or_default()
     }
+    /// Get one page of events, newest first, strictly older than `before_timestamp`
+    ///
+    /// Start with `None`, then pass the `timestamp` of the last entry of the
+    /// previous page to walk backward in time. A cursor that isn't an RFC 3339
+    /// timestamp yields an empty page.
+    pub async fn get_events_page(
+        &self,
+        before_timestamp: Option<String>,
+        limit: usize,
+    ) -> Vec<AuditEntry> {
+        let (reply, rx) = oneshot::channel();
+        if self
+            .tx
+            .send(AuditMsg::GetEventsPage { before_timestamp, limit, reply })
+            .await
+            .is_err()
+        {
+            return vec![];
+        }
+        rx.await.unwrap_or_default()
+    }
+
+    /// Export audit rows in a date range (YYYY-MM-DD, inclusive) as CSV or Parquet
+    ///
+    /// Pass `user_id` to restrict the export to one user. Dates in any other
+    /// format fail with [`LakehouseError::InvalidAuditQuery`].
+    pub async fn export(
+        &self,
+        user_id: Option<String>,
+        start_date: String,
+        end_date: String,
+        format: ExportFormat,
+    ) -> Result<Vec<u8>> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(AuditMsg::Export { user_id, start_date, end_date, format, reply })
+            .await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuditActor".into()))?;
+        rx.await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuditActor dropped".into()))?
+    }
 }
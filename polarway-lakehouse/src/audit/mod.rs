@@ -7,4 +7,4 @@ pub mod types;
 pub mod actor;
 
 pub use actor::{AuditActor, AuditHandle};
-pub use types::{ActionType, AuditEntry};
+pub use types::{ActionType, AuditEntry, ExportFormat};
@@ -96,8 +96,26 @@ impl ActionType {
                 | Self::LiveTradeStart
         )
     }
+
+    /// Compute credits charged per occurrence (0 for non-billable actions)
+    pub fn credit_weight(&self) -> f64 {
+        match self {
+            Self::QueryExecuted => 1.0,
+            Self::DataUpload => 2.0,
+            Self::DataExport => 2.0,
+            Self::BacktestRun => 10.0,
+            Self::LiveTradeStart => 25.0,
+            _ => 0.0,
+        }
+    }
 }
 
+/// Credits charged per second of `compute_time_ms` recorded in `user_actions`
+pub const CREDITS_PER_COMPUTE_SECOND: f64 = 0.1;
+
+/// Credits charged per million rows of `row_count` recorded in `user_actions`
+pub const CREDITS_PER_MILLION_ROWS: f64 = 1.0;
+
 impl std::fmt::Display for ActionType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.as_str())
@@ -114,6 +132,7 @@ pub struct AuditEntry {
     pub resource: Option<String>,
     pub detail: String,
     pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
     pub timestamp: String,
     pub date_partition: String,
 }
@@ -130,6 +149,18 @@ pub struct BillingSummary {
     pub total_backtests: u64,
     pub total_live_trades: u64,
     pub total_actions: u64,
+    /// Weighted charge: `count * credit_weight` per action plus metered usage
+    pub total_credits: f64,
+}
+
+/// File format for [`AuditHandle::export`](super::AuditHandle::export)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// CSV with a header row
+    Csv,
+    /// Single Parquet file
+    Parquet,
 }
 
 #[cfg(test)]
@@ -144,6 +175,13 @@ mod tests {
         assert!(!ActionType::Logout.is_billable());
     }
 
+    #[test]
+    fn test_credit_weight_matches_billable() {
+        assert!(ActionType::BacktestRun.credit_weight() > ActionType::QueryExecuted.credit_weight());
+        assert_eq!(ActionType::Login.credit_weight(), 0.0);
+        assert_eq!(ActionType::AdminAction.credit_weight(), 0.0);
+    }
+
     #[test]
     fn test_action_roundtrip() {
         let action = ActionType::BacktestRun;
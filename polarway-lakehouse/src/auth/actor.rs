@@ -23,13 +23,19 @@
 //!         "Alice".into(), "Smith".into(), SubscriptionTier::Pioneer,
 //!     ).await?;
 //!
-//!     // Login → JWT token
-//!     let (token, user) = handle.login("alice".into(), "SecureP@ss1".into(), false).await?;
+//!     // Confirm the email with the token sent to the user
+//!     handle.verify_email(user.email_verification_token.clone().unwrap()).await?;
+//!
+//!     // Login → JWT access token + refresh token
+//!     let (tokens, user) = handle.login("alice".into(), "SecureP@ss1".into(), false).await?;
 //!
 //!     // Verify on each request
-//!     let verified = handle.verify_token(token.clone()).await;
+//!     let verified = handle.verify_token(tokens.access_token.clone()).await;
 //!     assert!(verified.is_some());
 //!
+//!     // Rotate before the access token expires
+//!     let tokens = handle.refresh(tokens.refresh_token).await?;
+//!
 //!     Ok(())
 //! }
 //! ```
@@ -41,7 +47,7 @@ use argon2::{
     Argon2,
 };
 use chrono::{Duration, Utc};
-use deltalake::arrow::array::{Array, ArrayRef, BooleanArray, RecordBatch, StringArray};
+use deltalake::arrow::array::{new_null_array, Array, ArrayRef, BooleanArray, RecordBatch, StringArray};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
 use sha2::{Digest, Sha256};
 use tokio::sync::{mpsc, oneshot};
@@ -71,16 +77,32 @@ enum AuthMsg {
         username: String,
         password: String,
         remember_me: bool,
-        reply: oneshot::Sender<Result<(String, UserRecord)>>,
+        reply: oneshot::Sender<Result<(TokenPair, UserRecord)>>,
+    },
+    Refresh {
+        refresh_token: String,
+        reply: oneshot::Sender<Result<TokenPair>>,
     },
     VerifyToken {
         token: String,
         reply: oneshot::Sender<Option<UserRecord>>,
     },
+    VerifyEmail {
+        token: String,
+        reply: oneshot::Sender<Result<UserRecord>>,
+    },
     Logout {
         token: String,
         reply: oneshot::Sender<bool>,
     },
+    ListSessions {
+        user_id: String,
+        reply: oneshot::Sender<Result<Vec<SessionInfo>>>,
+    },
+    RevokeAllSessions {
+        user_id: String,
+        reply: oneshot::Sender<Result<usize>>,
+    },
     ApproveUser {
         user_id: String,
         tier: SubscriptionTier,
@@ -119,6 +141,7 @@ pub struct AuthActor {
     store: Arc<DeltaStore>,
     jwt_secret: String,
     session_expiry_days: u32,
+    access_token_ttl: Duration,
     rx: mpsc::Receiver<AuthMsg>,
 }
 
@@ -127,6 +150,7 @@ impl AuthActor {
     pub async fn spawn(config: LakehouseConfig) -> Result<AuthHandle> {
         let jwt_secret = config.jwt_secret.clone();
         let session_expiry_days = config.session_expiry_days;
+        let access_token_ttl = Duration::minutes(config.access_token_ttl_minutes as i64);
         let store = Arc::new(DeltaStore::new(config).await?);
 
         let (tx, rx) = mpsc::channel(256);
@@ -134,6 +158,7 @@ impl AuthActor {
             store,
             jwt_secret,
             session_expiry_days,
+            access_token_ttl,
             rx,
         };
 
@@ -143,16 +168,20 @@ impl AuthActor {
     }
 
     /// Spawn with an existing DeltaStore (for sharing with AuditActor)
+    ///
+    /// The access token lifetime comes from the store's config.
     pub async fn spawn_with_store(
         store: Arc<DeltaStore>,
         jwt_secret: String,
         session_expiry_days: u32,
     ) -> Result<AuthHandle> {
+        let access_token_ttl = Duration::minutes(store.config().access_token_ttl_minutes as i64);
         let (tx, rx) = mpsc::channel(256);
         let actor = Self {
             store,
             jwt_secret,
             session_expiry_days,
+            access_token_ttl,
             rx,
         };
 
@@ -171,12 +200,24 @@ impl AuthActor {
                 AuthMsg::Login { username, password, remember_me, reply } => {
                     let _ = reply.send(self.handle_login(username, password, remember_me).await);
                 }
+                AuthMsg::Refresh { refresh_token, reply } => {
+                    let _ = reply.send(self.handle_refresh(&refresh_token).await);
+                }
                 AuthMsg::VerifyToken { token, reply } => {
                     let _ = reply.send(self.handle_verify_token(&token).await);
                 }
+                AuthMsg::VerifyEmail { token, reply } => {
+                    let _ = reply.send(self.handle_verify_email(&token).await);
+                }
                 AuthMsg::Logout { token, reply } => {
                     let _ = reply.send(self.handle_logout(&token).await);
                 }
+                AuthMsg::ListSessions { user_id, reply } => {
+                    let _ = reply.send(self.handle_list_sessions(&user_id).await);
+                }
+                AuthMsg::RevokeAllSessions { user_id, reply } => {
+                    let _ = reply.send(self.handle_revoke_all_sessions(&user_id).await);
+                }
                 AuthMsg::ApproveUser { user_id, tier, reply } => {
                     let _ = reply.send(self.handle_approve(&user_id, tier).await);
                 }
@@ -258,6 +299,10 @@ impl AuthActor {
         let user_id = Uuid::new_v4().to_string();
         let now = Utc::now().to_rfc3339();
 
+        // Only the hash of the verification token is stored
+        let verification_token = Uuid::new_v4().simple().to_string();
+        let verification_hash = format!("{:x}", Sha256::digest(verification_token.as_bytes()));
+
         // Build RecordBatch
         let batch = RecordBatch::try_new(
             Arc::new(schema::users_arrow_schema()),
@@ -274,6 +319,8 @@ impl AuthActor {
                 Arc::new(StringArray::from(vec![now.as_str()])),
                 Arc::new(StringArray::from(vec![None::<&str>])),
                 Arc::new(StringArray::from(vec![Some("{}")])),
+                Arc::new(BooleanArray::from(vec![false])),
+                Arc::new(StringArray::from(vec![Some(verification_hash.as_str())])),
             ],
         )?;
 
@@ -291,15 +338,45 @@ impl AuthActor {
             is_active: true,
             created_at: now,
             last_login: None,
+            email_verified: false,
+            email_verification_token: Some(verification_token),
         })
     }
 
+    async fn handle_verify_email(&self, token: &str) -> Result<UserRecord> {
+        let token_hash = format!("{:x}", Sha256::digest(token.as_bytes()));
+        let batches = self
+            .store
+            .query(
+                schema::TABLE_USERS,
+                &format!("email_verification_hash = '{token_hash}'"),
+            )
+            .await?;
+
+        let (batch, i) = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(move |i| (b, i)))
+            .next()
+            .ok_or_else(|| LakehouseError::TokenInvalid("Unknown email verification token".into()))?;
+
+        // Mark verified and drop the token so it can't be reused
+        self.rewrite_user_row(batch, i, vec![
+            ("email_verified", Arc::new(BooleanArray::from(vec![true])) as ArrayRef),
+            ("email_verification_hash", Arc::new(StringArray::from(vec![None::<&str>]))),
+        ]).await?;
+
+        let mut user = self.extract_user_from_batch(batch, i)?;
+        user.email_verified = true;
+        info!(user_id = %user.user_id, "Email verified");
+        Ok(user)
+    }
+
     async fn handle_login(
         &self,
         username: String,
         password: String,
         remember_me: bool,
-    ) -> Result<(String, UserRecord)> {
+    ) -> Result<(TokenPair, UserRecord)> {
         // Find user
         let batches = self
             .store
@@ -313,12 +390,8 @@ impl AuthActor {
             .ok_or(LakehouseError::InvalidCredentials)?;
 
         // Extract password hash
-        let stored_hash = batch
-            .column(3)
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .ok_or_else(|| LakehouseError::Internal("Schema error: password_hash".into()))?
-            .value(row_idx);
+        let stored_hash = str_at(batch, "password_hash", row_idx)
+            .ok_or_else(|| LakehouseError::Internal("Schema error: password_hash".into()))?;
 
         // Verify Argon2 password
         let parsed_hash = PasswordHash::new(stored_hash)
@@ -327,42 +400,119 @@ impl AuthActor {
             .verify_password(password.as_bytes(), &parsed_hash)
             .map_err(|_| LakehouseError::InvalidCredentials)?;
 
-        // Check is_active
-        let is_active = batch.column(8)
-            .as_any()
-            .downcast_ref::<BooleanArray>()
-            .map(|a| a.value(row_idx))
-            .unwrap_or(true);
-        if !is_active {
+        let mut user = self.extract_user_from_batch(batch, row_idx)?;
+        if !user.is_active {
             return Err(LakehouseError::AccountDisabled(username));
         }
+        if !user.email_verified {
+            return Err(LakehouseError::EmailNotVerified(username));
+        }
 
-        // Extract user record
-        let user = self.extract_user_from_batch(batch, row_idx)?;
+        // Stamp the login
+        user.last_login = Some(self.touch_last_login(batch, row_idx).await?);
 
-        // Generate JWT
         let expiry_days = if remember_me { 30 } else { self.session_expiry_days as i64 };
-        let exp = (Utc::now() + Duration::days(expiry_days)).timestamp() as usize;
-        let iat = Utc::now().timestamp() as usize;
+        let family_id = Uuid::new_v4().to_string();
+        let tokens = self.issue_session(&user, Duration::days(expiry_days), &family_id).await?;
+
+        info!(username = %username, "Login successful");
+        Ok((tokens, user))
+    }
+
+    async fn handle_refresh(&self, refresh_token: &str) -> Result<TokenPair> {
+        let refresh_hash = format!("{:x}", Sha256::digest(refresh_token.as_bytes()));
+        let batches = self
+            .store
+            .query(
+                schema::TABLE_SESSIONS,
+                &format!("refresh_token_hash = '{refresh_hash}'"),
+            )
+            .await?;
+
+        let (batch, i) = batches
+            .iter()
+            .flat_map(|b| (0..b.num_rows()).map(move |i| (b, i)))
+            .next()
+            .ok_or_else(|| LakehouseError::TokenInvalid("Unknown refresh token".into()))?;
 
+        let get_str = |name: &str| -> Result<String> {
+            str_at(batch, name, i)
+                .map(str::to_string)
+                .ok_or_else(|| LakehouseError::Internal(format!("Schema error: {name}")))
+        };
+        let user_id = get_str("user_id")?;
+        let family_id = get_str("family_id")?;
+        let created_at = get_str("created_at")?;
+        let expires_at = get_str("expires_at")?;
+        let is_revoked = bool_at(batch, "is_revoked", i).unwrap_or(true);
+
+        // A consumed token coming back means it was copied — drop the whole chain
+        if is_revoked {
+            self.store
+                .delete(schema::TABLE_SESSIONS, &format!("family_id = '{family_id}'"))
+                .await?;
+            warn!(user_id = %user_id, family_id = %family_id, "Refresh token reuse detected, session chain revoked");
+            return Err(LakehouseError::TokenInvalid("Refresh token already used".into()));
+        }
+
+        let parse = |ts: &str| {
+            chrono::DateTime::parse_from_rfc3339(ts)
+                .map_err(|e| LakehouseError::Internal(format!("Bad session timestamp: {e}")))
+        };
+        let created = parse(&created_at)?;
+        let expires = parse(&expires_at)?;
+        if expires < Utc::now() {
+            return Err(LakehouseError::TokenExpired);
+        }
+
+        let user = self
+            .handle_get_user(&user_id)
+            .await
+            .ok_or_else(|| LakehouseError::UserNotFound(user_id.clone()))?;
+        if !user.is_active {
+            return Err(LakehouseError::AccountDisabled(user.username));
+        }
+
+        // Consume the old pair, then issue a new one with the same session lifetime
+        self.revoke_session_row(batch, i).await?;
+        let tokens = self.issue_session(&user, expires - created, &family_id).await?;
+
+        debug!(user_id = %user_id, "Session refreshed");
+        Ok(tokens)
+    }
+
+    /// Sign an access token, mint a refresh token and persist both as one session row
+    ///
+    /// The session (and so the refresh token) lasts `lifetime`; the access
+    /// token expires after `access_token_ttl`, or with the session if sooner.
+    async fn issue_session(
+        &self,
+        user: &UserRecord,
+        lifetime: Duration,
+        family_id: &str,
+    ) -> Result<TokenPair> {
+        let now = Utc::now();
         let claims = JwtClaims {
             sub: user.user_id.clone(),
             username: user.username.clone(),
             role: user.role.as_str().to_string(),
-            exp,
-            iat,
+            exp: (now + self.access_token_ttl.min(lifetime)).timestamp() as usize,
+            iat: now.timestamp() as usize,
+            jti: Uuid::new_v4().to_string(),
         };
 
-        let token = encode(
+        let access_token = encode(
             &Header::default(),
             &claims,
             &EncodingKey::from_secret(self.jwt_secret.as_bytes()),
         )?;
+        let refresh_token = format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple());
 
-        // Persist session to Delta
-        let token_hash = format!("{:x}", Sha256::digest(token.as_bytes()));
-        let now = Utc::now().to_rfc3339();
-        let expires = (Utc::now() + Duration::days(expiry_days)).to_rfc3339();
+        // Persist session to Delta — only hashes are stored
+        let token_hash = format!("{:x}", Sha256::digest(access_token.as_bytes()));
+        let refresh_hash = format!("{:x}", Sha256::digest(refresh_token.as_bytes()));
+        let created = now.to_rfc3339();
+        let expires = (now + lifetime).to_rfc3339();
 
         let session_batch = RecordBatch::try_new(
             Arc::new(schema::sessions_arrow_schema()),
@@ -371,9 +521,11 @@ impl AuthActor {
                 Arc::new(StringArray::from(vec![user.user_id.as_str()])),
                 Arc::new(StringArray::from(vec![user.username.as_str()])),
                 Arc::new(StringArray::from(vec![user.role.as_str()])),
-                Arc::new(StringArray::from(vec![now.as_str()])),
+                Arc::new(StringArray::from(vec![created.as_str()])),
                 Arc::new(StringArray::from(vec![expires.as_str()])),
                 Arc::new(BooleanArray::from(vec![false])),
+                Arc::new(StringArray::from(vec![Some(refresh_hash.as_str())])),
+                Arc::new(StringArray::from(vec![Some(family_id)])),
             ],
         )?;
 
@@ -381,8 +533,35 @@ impl AuthActor {
             .append(schema::TABLE_SESSIONS, session_batch)
             .await?;
 
-        info!(username = %username, "Login successful");
-        Ok((token, user))
+        Ok(TokenPair { access_token, refresh_token })
+    }
+
+    /// Mark row `i` of a `sessions` batch as revoked, keeping it for reuse detection
+    async fn revoke_session_row(&self, batch: &RecordBatch, i: usize) -> Result<()> {
+        let row = batch.slice(i, 1);
+        let token_hash = str_at(&row, "token_hash", 0)
+            .ok_or_else(|| LakehouseError::Internal("Schema error: token_hash".into()))?
+            .to_string();
+
+        let sessions_schema = schema::sessions_arrow_schema();
+        let columns: Vec<ArrayRef> = sessions_schema
+            .fields()
+            .iter()
+            .map(|field| match field.name().as_str() {
+                "is_revoked" => Arc::new(BooleanArray::from(vec![true])) as ArrayRef,
+                name => row
+                    .column_by_name(name)
+                    .cloned()
+                    .unwrap_or_else(|| new_null_array(field.data_type(), 1)),
+            })
+            .collect();
+        let updated = RecordBatch::try_new(Arc::new(sessions_schema), columns)?;
+
+        self.store
+            .delete(schema::TABLE_SESSIONS, &format!("token_hash = '{token_hash}'"))
+            .await?;
+        self.store.append(schema::TABLE_SESSIONS, updated).await?;
+        Ok(())
     }
 
     async fn handle_verify_token(&self, token: &str) -> Option<UserRecord> {
@@ -436,54 +615,72 @@ impl AuthActor {
         }
     }
 
-    async fn handle_approve(&self, user_id: &str, tier: SubscriptionTier) -> Result<UserRecord> {
-        // Get current user
-        let user = self
-            .handle_get_user(user_id)
-            .await
-            .ok_or_else(|| LakehouseError::UserNotFound(user_id.to_string()))?;
+    async fn handle_list_sessions(&self, user_id: &str) -> Result<Vec<SessionInfo>> {
+        let batches = self
+            .store
+            .query(schema::TABLE_SESSIONS, &format!("user_id = '{user_id}'"))
+            .await?;
 
-        // Delete old record
-        self.store
-            .delete(schema::TABLE_USERS, &format!("user_id = '{user_id}'"))
+        let mut sessions = Vec::new();
+        for batch in &batches {
+            let get_str = |name: &str| {
+                batch.column_by_name(name)
+                    .and_then(|c| c.as_any().downcast_ref::<StringArray>())
+                    .ok_or_else(|| LakehouseError::Internal(format!("Schema error: {name}")))
+            };
+            let token_hashes = get_str("token_hash")?;
+            let created = get_str("created_at")?;
+            let expires = get_str("expires_at")?;
+            let revoked = batch.column_by_name("is_revoked")
+                .and_then(|c| c.as_any().downcast_ref::<BooleanArray>())
+                .ok_or_else(|| LakehouseError::Internal("Schema error: is_revoked".into()))?;
+
+            for i in 0..batch.num_rows() {
+                sessions.push(SessionInfo {
+                    token_hash_prefix: token_hashes.value(i).chars().take(12).collect(),
+                    created_at: created.value(i).to_string(),
+                    expires_at: expires.value(i).to_string(),
+                    is_revoked: revoked.value(i),
+                });
+            }
+        }
+
+        sessions.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(sessions)
+    }
+
+    async fn handle_revoke_all_sessions(&self, user_id: &str) -> Result<usize> {
+        let metrics = self
+            .store
+            .delete(schema::TABLE_SESSIONS, &format!("user_id = '{user_id}'"))
             .await?;
 
-        // Re-insert with new role
+        info!(user_id, revoked = metrics.num_deleted_rows, "All sessions revoked");
+        Ok(metrics.num_deleted_rows)
+    }
+
+    async fn handle_approve(&self, user_id: &str, tier: SubscriptionTier) -> Result<UserRecord> {
+        // Get current row — everything but role, tier and status is kept as is
+        let (row, i) = self.find_user_row(user_id).await?;
+        let user = self.extract_user_from_batch(&row, i)?;
+
         let new_role = tier.default_role();
         let now = Utc::now().to_rfc3339();
 
-        let batch = RecordBatch::try_new(
-            Arc::new(schema::users_arrow_schema()),
-            vec![
-                Arc::new(StringArray::from(vec![user_id])) as ArrayRef,
-                Arc::new(StringArray::from(vec![user.username.as_str()])),
-                Arc::new(StringArray::from(vec![user.email.as_str()])),
-                Arc::new(StringArray::from(vec!["APPROVED_USER"])), // password_hash preserved in real impl
-                Arc::new(StringArray::from(vec![new_role.as_str()])),
-                Arc::new(StringArray::from(vec![Some(tier.as_str())])),
-                Arc::new(StringArray::from(vec![Some(user.first_name.as_str())])),
-                Arc::new(StringArray::from(vec![Some(user.last_name.as_str())])),
-                Arc::new(BooleanArray::from(vec![true])),
-                Arc::new(StringArray::from(vec![user.created_at.as_str()])),
-                Arc::new(StringArray::from(vec![Some(now.as_str())])),
-                Arc::new(StringArray::from(vec![Some("{}")])),
-            ],
-        )?;
-
-        self.store.append(schema::TABLE_USERS, batch).await?;
+        self.rewrite_user_row(&row, i, vec![
+            ("role", Arc::new(StringArray::from(vec![new_role.as_str()])) as ArrayRef),
+            ("subscription_tier", Arc::new(StringArray::from(vec![Some(tier.as_str())]))),
+            ("is_active", Arc::new(BooleanArray::from(vec![true]))),
+            ("last_login", Arc::new(StringArray::from(vec![Some(now.as_str())]))),
+        ]).await?;
         info!(user_id, role = %new_role, tier = %tier, "User approved");
 
         Ok(UserRecord {
-            user_id: user_id.to_string(),
-            username: user.username,
-            email: user.email,
             role: new_role,
             subscription_tier: Some(tier),
-            first_name: user.first_name,
-            last_name: user.last_name,
             is_active: true,
-            created_at: user.created_at,
             last_login: Some(now),
+            ..user
         })
     }
 
@@ -543,11 +740,8 @@ impl AuthActor {
             .next()
             .ok_or_else(|| LakehouseError::UserNotFound(user_id.to_string()))?;
 
-        let stored_hash = batch.column(3)
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .ok_or_else(|| LakehouseError::Internal("Schema error".into()))?
-            .value(i);
+        let stored_hash = str_at(batch, "password_hash", i)
+            .ok_or_else(|| LakehouseError::Internal("Schema error: password_hash".into()))?;
 
         let parsed = PasswordHash::new(stored_hash)
             .map_err(|e| LakehouseError::Internal(e.to_string()))?;
@@ -562,77 +756,96 @@ impl AuthActor {
             .map_err(|e| LakehouseError::Internal(e.to_string()))?
             .to_string();
 
-        // Delete old record, insert updated
-        self.store
-            .delete(schema::TABLE_USERS, &format!("user_id = '{user_id}'"))
-            .await?;
-
-        let user = self.extract_user_from_batch(batch, i)?;
-
-        let updated = RecordBatch::try_new(
-            Arc::new(schema::users_arrow_schema()),
-            vec![
-                Arc::new(StringArray::from(vec![user_id])) as ArrayRef,
-                Arc::new(StringArray::from(vec![user.username.as_str()])),
-                Arc::new(StringArray::from(vec![user.email.as_str()])),
-                Arc::new(StringArray::from(vec![new_hash.as_str()])),
-                Arc::new(StringArray::from(vec![user.role.as_str()])),
-                Arc::new(StringArray::from(vec![user.subscription_tier.as_ref().map(|t| t.as_str())])),
-                Arc::new(StringArray::from(vec![Some(user.first_name.as_str())])),
-                Arc::new(StringArray::from(vec![Some(user.last_name.as_str())])),
-                Arc::new(BooleanArray::from(vec![user.is_active])),
-                Arc::new(StringArray::from(vec![user.created_at.as_str()])),
-                Arc::new(StringArray::from(vec![user.last_login.as_deref()])),
-                Arc::new(StringArray::from(vec![Some("{}")])),
-            ],
-        )?;
+        self.rewrite_user_row(batch, i, vec![
+            ("password_hash", Arc::new(StringArray::from(vec![new_hash.as_str()])) as ArrayRef),
+        ]).await?;
 
-        self.store.append(schema::TABLE_USERS, updated).await?;
         info!(user_id, "Password changed");
         Ok(())
     }
 
     // ─── Helpers ───
 
-    fn extract_user_from_batch(&self, batch: &RecordBatch, i: usize) -> Result<UserRecord> {
-        let get_str = |col: usize| -> &str {
-            batch.column(col)
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .map(|a| a.value(i))
-                .unwrap_or("")
-        };
+    /// Set `last_login` to now, leaving every other column of the row as is
+    ///
+    /// Returns the new timestamp.
+    async fn touch_last_login(&self, batch: &RecordBatch, i: usize) -> Result<String> {
+        let now = Utc::now().to_rfc3339();
+        self.rewrite_user_row(batch, i, vec![
+            ("last_login", Arc::new(StringArray::from(vec![Some(now.as_str())])) as ArrayRef),
+        ]).await?;
+        Ok(now)
+    }
 
-        let get_opt_str = |col: usize| -> Option<String> {
-            batch.column(col)
-                .as_any()
-                .downcast_ref::<StringArray>()
-                .and_then(|a| {
-                    if a.is_null(i) {
-                        None
-                    } else {
-                        Some(a.value(i).to_string())
-                    }
-                })
-        };
+    /// Replace row `i` of `batch` in the `users` table with some columns swapped
+    ///
+    /// `updates` maps column names to single-value arrays; all other
+    /// columns are copied from the current row, or null if it lacks them.
+    async fn rewrite_user_row(
+        &self,
+        batch: &RecordBatch,
+        i: usize,
+        updates: Vec<(&str, ArrayRef)>,
+    ) -> Result<()> {
+        let row = batch.slice(i, 1);
+        let user_id = str_at(&row, "user_id", 0)
+            .ok_or_else(|| LakehouseError::Internal("Schema error: user_id".into()))?
+            .to_string();
 
-        let is_active = batch.column(8)
-            .as_any()
-            .downcast_ref::<BooleanArray>()
-            .map(|a| a.value(i))
-            .unwrap_or(true);
+        let users_schema = schema::users_arrow_schema();
+        let columns: Vec<ArrayRef> = users_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                updates
+                    .iter()
+                    .find(|(name, _)| *name == field.name().as_str())
+                    .map(|(_, value)| value.clone())
+                    .or_else(|| row.column_by_name(field.name()).cloned())
+                    .unwrap_or_else(|| new_null_array(field.data_type(), 1))
+            })
+            .collect();
+        let updated = RecordBatch::try_new(Arc::new(users_schema), columns)?;
+
+        self.store
+            .delete(schema::TABLE_USERS, &format!("user_id = '{user_id}'"))
+            .await?;
+        self.store.append(schema::TABLE_USERS, updated).await?;
+        Ok(())
+    }
+
+    /// Find the raw `users` row for `user_id`
+    async fn find_user_row(&self, user_id: &str) -> Result<(RecordBatch, usize)> {
+        let batches = self
+            .store
+            .query(schema::TABLE_USERS, &format!("user_id = '{user_id}'"))
+            .await?;
+
+        batches
+            .into_iter()
+            .find(|b| b.num_rows() > 0)
+            .map(|b| (b, 0))
+            .ok_or_else(|| LakehouseError::UserNotFound(user_id.to_string()))
+    }
+
+    fn extract_user_from_batch(&self, batch: &RecordBatch, i: usize) -> Result<UserRecord> {
+        let get_str = |name: &str| str_at(batch, name, i).unwrap_or("");
+        let get_opt_str = |name: &str| str_at(batch, name, i).map(str::to_string);
 
         Ok(UserRecord {
-            user_id: get_str(0).to_string(),
-            username: get_str(1).to_string(),
-            email: get_str(2).to_string(),
-            role: UserRole::from_str(get_str(4)),
-            subscription_tier: get_opt_str(5).map(|s| SubscriptionTier::from_str(&s)),
-            first_name: get_opt_str(6).unwrap_or_default(),
-            last_name: get_opt_str(7).unwrap_or_default(),
-            is_active,
-            created_at: get_str(9).to_string(),
-            last_login: get_opt_str(10),
+            user_id: get_str("user_id").to_string(),
+            username: get_str("username").to_string(),
+            email: get_str("email").to_string(),
+            role: UserRole::from_str(get_str("role")),
+            subscription_tier: get_opt_str("subscription_tier").map(|s| SubscriptionTier::from_str(&s)),
+            first_name: get_opt_str("first_name").unwrap_or_default(),
+            last_name: get_opt_str("last_name").unwrap_or_default(),
+            is_active: bool_at(batch, "is_active", i).unwrap_or(true),
+            created_at: get_str("created_at").to_string(),
+            last_login: get_opt_str("last_login"),
+            // Null for accounts created before email verification existed
+            email_verified: bool_at(batch, "email_verified", i).unwrap_or(true),
+            email_verification_token: None,
         })
     }
 
@@ -650,6 +863,26 @@ impl AuthActor {
     }
 }
 
+/// String in column `name` at row `i`; `None` if the column is missing or null
+fn str_at<'a>(batch: &'a RecordBatch, name: &str, i: usize) -> Option<&'a str> {
+    batch
+        .column_by_name(name)?
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .filter(|a| a.is_valid(i))
+        .map(|a| a.value(i))
+}
+
+/// Boolean in column `name` at row `i`; `None` if the column is missing or null
+fn bool_at(batch: &RecordBatch, name: &str, i: usize) -> Option<bool> {
+    batch
+        .column_by_name(name)?
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .filter(|a| a.is_valid(i))
+        .map(|a| a.value(i))
+}
+
 // ─── Handle (client-facing API) ───
 
 /// Thread-safe handle to communicate with the AuthActor
@@ -684,7 +917,7 @@ impl AuthHandle {
         username: String,
         password: String,
         remember_me: bool,
-    ) -> Result<(String, UserRecord)> {
+    ) -> Result<(TokenPair, UserRecord)> {
         let (reply, rx) = oneshot::channel();
         self.tx
             .send(AuthMsg::Login { username, password, remember_me, reply })
@@ -694,12 +927,37 @@ impl AuthHandle {
             .map_err(|_| LakehouseError::ActorUnavailable("AuthActor dropped".into()))?
     }
 
+    /// Exchange a refresh token for a new token pair
+    ///
+    /// The old pair stops working. Presenting an already-used refresh token
+    /// revokes every session descended from the same login.
+    pub async fn refresh(&self, refresh_token: String) -> Result<TokenPair> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(AuthMsg::Refresh { refresh_token, reply })
+            .await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor".into()))?;
+        rx.await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor dropped".into()))?
+    }
+
     pub async fn verify_token(&self, token: String) -> Option<UserRecord> {
         let (reply, rx) = oneshot::channel();
         self.tx.send(AuthMsg::VerifyToken { token, reply }).await.ok()?;
         rx.await.ok()?
     }
 
+    /// Confirm a user's email with the token issued at registration
+    pub async fn verify_email(&self, token: String) -> Result<UserRecord> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(AuthMsg::VerifyEmail { token, reply })
+            .await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor".into()))?;
+        rx.await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor dropped".into()))?
+    }
+
     pub async fn logout(&self, token: String) -> bool {
         let (reply, rx) = oneshot::channel();
         if self.tx.send(AuthMsg::Logout { token, reply }).await.is_err() {
@@ -708,6 +966,28 @@ impl AuthHandle {
         rx.await.unwrap_or(false)
     }
 
+    /// List a user's sessions, oldest first
+    pub async fn list_sessions(&self, user_id: String) -> Result<Vec<SessionInfo>> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(AuthMsg::ListSessions { user_id, reply })
+            .await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor".into()))?;
+        rx.await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor dropped".into()))?
+    }
+
+    /// Revoke every session of a user; returns how many were revoked
+    pub async fn revoke_all_sessions(&self, user_id: String) -> Result<usize> {
+        let (reply, rx) = oneshot::channel();
+        self.tx
+            .send(AuthMsg::RevokeAllSessions { user_id, reply })
+            .await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor".into()))?;
+        rx.await
+            .map_err(|_| LakehouseError::ActorUnavailable("AuthActor dropped".into()))?
+    }
+
     pub async fn approve_user(
         &self,
         user_id: String,
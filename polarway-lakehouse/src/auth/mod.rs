@@ -6,4 +6,4 @@ pub mod types;
 pub mod actor;
 
 pub use actor::{AuthActor, AuthHandle};
-pub use types::{UserRecord, UserRole, SubscriptionTier};
+pub use types::{SessionInfo, TokenPair, UserRecord, UserRole, SubscriptionTier};
@@ -129,6 +129,10 @@ pub struct UserRecord {
     pub is_active: bool,
     pub created_at: String,
     pub last_login: Option<String>,
+    pub email_verified: bool,
+    /// Plain verification token — only set on the record returned by `register`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_verification_token: Option<String>,
 }
 
 impl UserRecord {
@@ -147,6 +151,27 @@ impl UserRecord {
     }
 }
 
+/// Session summary as stored in the Delta `sessions` table
+///
+/// Only a prefix of the token hash is exposed, enough to tell sessions apart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionInfo {
+    pub token_hash_prefix: String,
+    pub created_at: String,
+    pub expires_at: String,
+    pub is_revoked: bool,
+}
+
+/// Tokens issued on login and on every refresh
+///
+/// The refresh token is single-use: `AuthHandle::refresh` consumes it and
+/// returns a new pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenPair {
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
 /// JWT claims for session tokens
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JwtClaims {
@@ -160,6 +185,9 @@ pub struct JwtClaims {
     pub exp: usize,
     /// Issued at (Unix timestamp)
     pub iat: usize,
+    /// Unique token id, so tokens issued within the same second differ
+    #[serde(default)]
+    pub jti: String,
 }
 
 #[cfg(test)]
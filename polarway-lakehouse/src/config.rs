@@ -2,22 +2,32 @@
 
 use std::path::{Path, PathBuf};
 
+use url::Url;
+
+use crate::error::{LakehouseError, Result};
+
 /// Lakehouse configuration
 #[derive(Debug, Clone)]
 pub struct LakehouseConfig {
-    /// Root path for all Delta tables
+    /// Root path for all Delta tables — a local directory or an
+    /// object-store URL (`s3://bucket/prefix`, `gs://bucket/prefix`, `file:///dir`)
     pub base_path: PathBuf,
 
     /// JWT secret for token signing (auth feature)
     pub jwt_secret: String,
 
-    /// Default session expiry in days
+    /// Default session expiry in days — how long the refresh token lasts
     pub session_expiry_days: u32,
 
+    /// Access token (JWT) lifetime in minutes; clients refresh after this
+    pub access_token_ttl_minutes: u32,
+
     /// Vacuum retention in hours (default: 168 = 7 days)
     pub vacuum_retention_hours: u64,
 
-    /// Auto-compact threshold: compact when file count exceeds this
+    /// Auto-compact threshold: compact in the background once writes have
+    /// added more files than this since the last compaction (0, the
+    /// default, disables it)
     pub auto_compact_threshold: usize,
 
     /// Z-order columns for sessions table (for fast lookups)
@@ -49,8 +59,9 @@ impl LakehouseConfig {
             jwt_secret: std::env::var("POLARWAY_JWT_SECRET")
                 .unwrap_or_else(|_| "polarway-lakehouse-default-secret-change-me".to_string()),
             session_expiry_days: 7,
+            access_token_ttl_minutes: 15,
             vacuum_retention_hours: 168, // 7 days
-            auto_compact_threshold: 50,
+            auto_compact_threshold: 0,
             session_z_order_columns: vec!["user_id".to_string()],
             audit_z_order_columns: vec!["user_id".to_string(), "action".to_string()],
             max_concurrent_writers: 4,
@@ -69,12 +80,24 @@ impl LakehouseConfig {
         self
     }
 
+    /// Override access token lifetime
+    pub fn with_access_token_ttl_minutes(mut self, minutes: u32) -> Self {
+        self.access_token_ttl_minutes = minutes;
+        self
+    }
+
     /// Override vacuum retention
     pub fn with_vacuum_retention_hours(mut self, hours: u64) -> Self {
         self.vacuum_retention_hours = hours;
         self
     }
 
+    /// Override auto-compact threshold (0 disables auto-compaction)
+    pub fn with_auto_compact_threshold(mut self, files: usize) -> Self {
+        self.auto_compact_threshold = files;
+        self
+    }
+
     /// Get path for a specific table
     pub fn table_path(&self, table_name: &str) -> PathBuf {
         self.base_path.join(table_name)
@@ -84,6 +107,43 @@ impl LakehouseConfig {
     pub fn table_uri(&self, table_name: &str) -> String {
         self.table_path(table_name).to_string_lossy().to_string()
     }
+
+    /// `base_path` parsed as a URL, if it is one
+    pub fn base_url(&self) -> Option<Url> {
+        let url = Url::parse(self.base_path.to_str()?).ok()?;
+        // Single-letter schemes are Windows drive letters (`C:\data`), not URLs
+        (url.scheme().len() > 1).then_some(url)
+    }
+
+    /// Whether tables live on the local filesystem
+    pub fn is_local(&self) -> bool {
+        self.base_url().is_none_or(|url| url.scheme() == "file")
+    }
+
+    /// Local directory of a table, `None` for object-store base paths
+    pub fn local_table_path(&self, table_name: &str) -> Option<PathBuf> {
+        match self.base_url() {
+            None => Some(self.table_path(table_name)),
+            Some(url) if url.scheme() == "file" => {
+                url.to_file_path().ok().map(|dir| dir.join(table_name))
+            }
+            Some(_) => None,
+        }
+    }
+
+    /// Get the table URL for delta-rs
+    pub fn table_url(&self, table_name: &str) -> Result<Url> {
+        if let Some(path) = self.local_table_path(table_name) {
+            return Url::from_directory_path(&path).map_err(|_| {
+                LakehouseError::Config(format!("Invalid table path: {}", path.display()))
+            });
+        }
+
+        let base = self.base_path.to_string_lossy();
+        let raw = format!("{}/{table_name}", base.trim_end_matches('/'));
+        Url::parse(&raw)
+            .map_err(|e| LakehouseError::Config(format!("Invalid table URL {raw}: {e}")))
+    }
 }
 
 #[cfg(test)]
@@ -94,6 +154,8 @@ mod tests {
     fn test_default_config() {
         let cfg = LakehouseConfig::new("/tmp/test_lakehouse");
         assert_eq!(cfg.session_expiry_days, 7);
+        assert_eq!(cfg.access_token_ttl_minutes, 15);
+        assert_eq!(cfg.auto_compact_threshold, 0);
         assert_eq!(cfg.vacuum_retention_hours, 168);
         assert_eq!(cfg.table_uri("users"), "/tmp/test_lakehouse/users");
     }
@@ -109,4 +171,23 @@ mod tests {
         assert_eq!(cfg.session_expiry_days, 30);
         assert_eq!(cfg.vacuum_retention_hours, 24);
     }
+
+    #[test]
+    fn test_object_store_table_url() {
+        let cfg = LakehouseConfig::new("s3://bucket/lakehouse");
+        assert!(!cfg.is_local());
+        assert!(cfg.local_table_path("users").is_none());
+        assert_eq!(cfg.table_url("users").unwrap().as_str(), "s3://bucket/lakehouse/users");
+
+        let cfg = LakehouseConfig::new("gs://bucket/lakehouse/");
+        assert_eq!(cfg.table_url("audit_log").unwrap().as_str(), "gs://bucket/lakehouse/audit_log");
+
+        let cfg = LakehouseConfig::new("file:///tmp/test_lakehouse");
+        assert!(cfg.is_local());
+        assert_eq!(cfg.table_url("users").unwrap().as_str(), "file:///tmp/test_lakehouse/users/");
+
+        let cfg = LakehouseConfig::new("/tmp/test_lakehouse");
+        assert!(cfg.is_local());
+        assert_eq!(cfg.table_url("users").unwrap().as_str(), "file:///tmp/test_lakehouse/users/");
+    }
 }
@@ -42,6 +42,9 @@ pub enum LakehouseError {
     #[error("Account disabled: {0}")]
     AccountDisabled(String),
 
+    #[error("Email not verified: {0}")]
+    EmailNotVerified(String),
+
     #[error("Token expired")]
     TokenExpired,
 
@@ -59,6 +62,9 @@ pub enum LakehouseError {
     #[error("Audit write failed: {0}")]
     AuditWriteFailed(String),
 
+    #[error("Invalid audit query: {0}")]
+    InvalidAuditQuery(String),
+
     // ─── Infrastructure Errors ───
 
     #[error("IO error: {0}")]
@@ -66,7 +66,7 @@ pub mod audit;
 pub use config::LakehouseConfig;
 pub use error::{LakehouseError, Result};
 pub use store::DeltaStore;
-pub use maintenance::MaintenanceScheduler;
+pub use maintenance::{MaintenanceReport, MaintenanceSchedule, MaintenanceScheduler, MaintenanceStore, TableSchedule};
 
 #[cfg(feature = "auth")]
 pub use auth::{AuthActor, AuthHandle, UserRecord, UserRole, SubscriptionTier};
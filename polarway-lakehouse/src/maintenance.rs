@@ -5,44 +5,201 @@
 //! - Z-order optimization
 //! - Vacuum (remove old files)
 //! - Expired session cleanup
+//!
+//! Intervals and Z-order columns are set per table with a
+//! [`MaintenanceSchedule`]; tables without an entry use the defaults.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
+use async_trait::async_trait;
 use chrono::Utc;
 use tokio::task::JoinHandle;
 use tracing::{error, info};
 
 use crate::error::Result;
 use crate::schema;
-use crate::store::DeltaStore;
+use crate::store::{CompactMetrics, DeleteMetrics, DeltaStore, VacuumMetrics};
+
+/// Store operations the scheduler drives
+///
+/// Implemented by [`DeltaStore`]; tests can substitute their own.
+#[async_trait]
+pub trait MaintenanceStore: Send + Sync + 'static {
+    async fn compact(&self, table_name: &str) -> Result<CompactMetrics>;
+    async fn z_order(&self, table_name: &str, columns: &[&str]) -> Result<CompactMetrics>;
+    async fn vacuum(&self, table_name: &str, retention_hours: u64, dry_run: bool) -> Result<VacuumMetrics>;
+    async fn delete(&self, table_name: &str, predicate: &str) -> Result<DeleteMetrics>;
+}
+
+#[async_trait]
+impl MaintenanceStore for DeltaStore {
+    async fn compact(&self, table_name: &str) -> Result<CompactMetrics> {
+        DeltaStore::compact(self, table_name).await
+    }
+
+    async fn z_order(&self, table_name: &str, columns: &[&str]) -> Result<CompactMetrics> {
+        DeltaStore::z_order(self, table_name, columns).await
+    }
+
+    async fn vacuum(&self, table_name: &str, retention_hours: u64, dry_run: bool) -> Result<VacuumMetrics> {
+        DeltaStore::vacuum(self, table_name, retention_hours, dry_run).await
+    }
+
+    async fn delete(&self, table_name: &str, predicate: &str) -> Result<DeleteMetrics> {
+        DeltaStore::delete(self, table_name, predicate).await
+    }
+}
+
+/// Maintenance intervals for one table
+#[derive(Debug, Clone)]
+pub struct TableSchedule {
+    pub compact_interval: Duration,
+    pub z_order_interval: Duration,
+    /// Empty disables Z-ordering for the table
+    pub z_order_columns: Vec<String>,
+    pub vacuum_interval: Duration,
+}
+
+impl TableSchedule {
+    /// Default schedule for a table
+    ///
+    /// - Compaction: every 6 hours
+    /// - Z-order: every 24 hours (sessions, audit_log, user_actions)
+    /// - Vacuum: every 24 hours
+    pub fn default_for(table_name: &str) -> Self {
+        let z_order_columns: &[&str] = match table_name {
+            schema::TABLE_SESSIONS => &["user_id"],
+            schema::TABLE_AUDIT_LOG => &["user_id", "action"],
+            schema::TABLE_USER_ACTIONS => &["user_id", "action_type"],
+            _ => &[],
+        };
+        Self {
+            compact_interval: Duration::from_secs(6 * 3600),
+            z_order_interval: Duration::from_secs(24 * 3600),
+            z_order_columns: z_order_columns.iter().map(|c| c.to_string()).collect(),
+            vacuum_interval: Duration::from_secs(24 * 3600),
+        }
+    }
+}
+
+/// Per-table maintenance configuration
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use polarway_lakehouse::maintenance::{MaintenanceSchedule, TableSchedule};
+///
+/// // audit_log is append-heavy: compact it every 15 minutes
+/// let schedule = MaintenanceSchedule::new().with_table("audit_log", TableSchedule {
+///     compact_interval: Duration::from_secs(15 * 60),
+///     ..TableSchedule::default_for("audit_log")
+/// });
+/// assert_eq!(schedule.for_table("audit_log").compact_interval, Duration::from_secs(900));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MaintenanceSchedule {
+    /// Interval for expired session cleanup
+    pub session_cleanup_interval: Duration,
+    tables: HashMap<String, TableSchedule>,
+}
+
+impl Default for MaintenanceSchedule {
+    fn default() -> Self {
+        Self {
+            session_cleanup_interval: Duration::from_secs(3600),
+            tables: HashMap::new(),
+        }
+    }
+}
+
+impl MaintenanceSchedule {
+    /// Schedule with default intervals for every table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the schedule of one table
+    pub fn with_table(mut self, table_name: impl Into<String>, schedule: TableSchedule) -> Self {
+        self.tables.insert(table_name.into(), schedule);
+        self
+    }
+
+    /// Override the session cleanup interval
+    pub fn with_session_cleanup_interval(mut self, interval: Duration) -> Self {
+        self.session_cleanup_interval = interval;
+        self
+    }
+
+    /// Schedule for a table, falling back to [`TableSchedule::default_for`]
+    pub fn for_table(&self, table_name: &str) -> TableSchedule {
+        self.tables
+            .get(table_name)
+            .cloned()
+            .unwrap_or_else(|| TableSchedule::default_for(table_name))
+    }
+}
+
+/// Outcome of [`MaintenanceScheduler::run_once`]
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceReport {
+    pub sessions_deleted: usize,
+    pub per_table_compaction: Vec<(String, CompactMetrics)>,
+    pub per_table_vacuum: Vec<(String, VacuumMetrics)>,
+    /// `(table, error message)` for every step that failed
+    pub errors: Vec<(String, String)>,
+}
 
 /// Background maintenance scheduler
 pub struct MaintenanceScheduler {
-    store: Arc<DeltaStore>,
+    store: Arc<dyn MaintenanceStore>,
+    schedule: MaintenanceSchedule,
+    vacuum_retention_hours: u64,
     handles: Vec<JoinHandle<()>>,
 }
 
 impl MaintenanceScheduler {
     /// Create a new scheduler tied to a DeltaStore
     pub fn new(store: Arc<DeltaStore>) -> Self {
+        let retention_hours = store.config().vacuum_retention_hours;
+        Self::with_store(store).with_vacuum_retention_hours(retention_hours)
+    }
+
+    /// Create a scheduler over any [`MaintenanceStore`]
+    pub fn with_store(store: Arc<dyn MaintenanceStore>) -> Self {
         Self {
             store,
+            schedule: MaintenanceSchedule::default(),
+            vacuum_retention_hours: 168,
             handles: Vec::new(),
         }
     }
 
+    /// Override the per-table schedule used by [`start`](Self::start)
+    pub fn with_schedule(mut self, schedule: MaintenanceSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Override vacuum retention
+    pub fn with_vacuum_retention_hours(mut self, hours: u64) -> Self {
+        self.vacuum_retention_hours = hours;
+        self
+    }
+
     /// Start all background maintenance tasks
     ///
-    /// - Session cleanup: every 1 hour
-    /// - Compaction: every 6 hours
-    /// - Z-order: every 24 hours
-    /// - Vacuum: every 24 hours
+    /// Each table gets its own compaction, Z-order and vacuum tasks on the
+    /// intervals from its [`TableSchedule`].
     pub fn start(&mut self) {
-        self.start_session_cleanup(Duration::from_secs(3600));
-        self.start_compaction(Duration::from_secs(6 * 3600));
-        self.start_z_order(Duration::from_secs(24 * 3600));
-        self.start_vacuum(Duration::from_secs(24 * 3600));
+        self.start_session_cleanup(self.schedule.session_cleanup_interval);
+        for table_def in schema::all_tables() {
+            let table = self.schedule.for_table(table_def.name);
+            self.start_table_compaction(table_def.name, table.compact_interval);
+            self.start_table_z_order(table_def.name, table.z_order_columns, table.z_order_interval);
+            self.start_table_vacuum(table_def.name, table.vacuum_interval);
+        }
 
         info!("Maintenance scheduler started");
     }
@@ -71,97 +228,107 @@ impl MaintenanceScheduler {
         self.handles.push(handle);
     }
 
-    /// Start periodic compaction for all tables
+    /// Start periodic compaction for all tables on one interval
     pub fn start_compaction(&mut self, interval: Duration) {
+        for table_def in schema::all_tables() {
+            self.start_table_compaction(table_def.name, interval);
+        }
+    }
+
+    /// Start periodic compaction for one table
+    pub fn start_table_compaction(&mut self, table_name: &'static str, interval: Duration) {
         let store = Arc::clone(&self.store);
         let handle = tokio::spawn(async move {
             let mut ticker = tokio::time::interval(interval);
             loop {
                 ticker.tick().await;
-                for table_def in schema::all_tables() {
-                    match store.compact(table_def.name).await {
-                        Ok(m) => {
-                            if m.files_removed > 0 {
-                                info!(
-                                    table = table_def.name,
-                                    added = m.files_added,
-                                    removed = m.files_removed,
-                                    "Compaction done"
-                                );
-                            }
+                match store.compact(table_name).await {
+                    Ok(m) => {
+                        if m.files_removed > 0 {
+                            info!(
+                                table = table_name,
+                                added = m.files_added,
+                                removed = m.files_removed,
+                                "Compaction done"
+                            );
                         }
-                        Err(e) => error!(
-                            table = table_def.name,
-                            error = ?e,
-                            "Compaction failed"
-                        ),
                     }
+                    Err(e) => error!(
+                        table = table_name,
+                        error = ?e,
+                        "Compaction failed"
+                    ),
                 }
             }
         });
         self.handles.push(handle);
     }
 
-    /// Start periodic Z-order optimization
+    /// Start periodic Z-order optimization for all tables on one interval
+    ///
+    /// Columns come from the schedule (or the table defaults).
     pub fn start_z_order(&mut self, interval: Duration) {
+        for table_def in schema::all_tables() {
+            let columns = self.schedule.for_table(table_def.name).z_order_columns;
+            self.start_table_z_order(table_def.name, columns, interval);
+        }
+    }
+
+    /// Start periodic Z-order optimization for one table (no-op without columns)
+    pub fn start_table_z_order(
+        &mut self,
+        table_name: &'static str,
+        columns: Vec<String>,
+        interval: Duration,
+    ) {
+        if columns.is_empty() {
+            return;
+        }
         let store = Arc::clone(&self.store);
         let handle = tokio::spawn(async move {
+            let columns: Vec<&str> = columns.iter().map(String::as_str).collect();
             let mut ticker = tokio::time::interval(interval);
             loop {
                 ticker.tick().await;
-
-                // Z-order sessions by user_id for fast lookups
-                if let Err(e) = store.z_order(schema::TABLE_SESSIONS, &["user_id"]).await {
-                    error!(error = ?e, "Z-order sessions failed");
-                }
-
-                // Z-order audit_log by user_id + action
-                if let Err(e) = store
-                    .z_order(schema::TABLE_AUDIT_LOG, &["user_id", "action"])
-                    .await
-                {
-                    error!(error = ?e, "Z-order audit_log failed");
+                match store.z_order(table_name, &columns).await {
+                    Ok(_) => info!(table = table_name, "Z-order optimization done"),
+                    Err(e) => error!(table = table_name, error = ?e, "Z-order failed"),
                 }
-
-                // Z-order user_actions by user_id + action_type
-                if let Err(e) = store
-                    .z_order(schema::TABLE_USER_ACTIONS, &["user_id", "action_type"])
-                    .await
-                {
-                    error!(error = ?e, "Z-order user_actions failed");
-                }
-
-                info!("Z-order optimization cycle complete");
             }
         });
         self.handles.push(handle);
     }
 
-    /// Start periodic vacuum (cleanup old files)
+    /// Start periodic vacuum (cleanup old files) for all tables on one interval
     pub fn start_vacuum(&mut self, interval: Duration) {
+        for table_def in schema::all_tables() {
+            self.start_table_vacuum(table_def.name, interval);
+        }
+    }
+
+    /// Start periodic vacuum for one table
+    pub fn start_table_vacuum(&mut self, table_name: &'static str, interval: Duration) {
         let store = Arc::clone(&self.store);
-        let retention_hours = store.config().vacuum_retention_hours;
+        let retention_hours = self.vacuum_retention_hours;
         let handle = tokio::spawn(async move {
             let mut ticker = tokio::time::interval(interval);
             loop {
                 ticker.tick().await;
-                for table_def in schema::all_tables() {
-                    match store.vacuum(table_def.name, retention_hours, false).await {
-                        Ok(m) => {
-                            if m.files_deleted > 0 {
-                                info!(
-                                    table = table_def.name,
-                                    deleted = m.files_deleted,
-                                    "Vacuum done"
-                                );
-                            }
+                match store.vacuum(table_name, retention_hours, false).await {
+                    Ok(m) => {
+                        if m.files_deleted > 0 {
+                            info!(
+                                table = table_name,
+                                deleted = m.files_deleted,
+                                "Vacuum done"
+                            );
                         }
-                        Err(e) => error!(
-                            table = table_def.name,
-                            error = ?e,
-                            "Vacuum failed"
-                        ),
                     }
+                    Err(e) => error!(
+                        table = table_name,
+                        error = ?e,
+                        "Vacuum failed"
+                    ),
                 }
             }
         });
@@ -169,28 +336,42 @@ impl MaintenanceScheduler {
     }
 
     /// Run a one-shot maintenance cycle (useful for CLI or tests)
-    pub async fn run_once(store: &DeltaStore) -> Result<()> {
+    ///
+    /// Failures don't stop the cycle; they are collected in
+    /// [`MaintenanceReport::errors`].
+    pub async fn run_once(store: &DeltaStore) -> Result<MaintenanceReport> {
         info!("Running one-shot maintenance cycle");
+        let mut report = MaintenanceReport::default();
 
         // Cleanup expired sessions
         let now = Utc::now().to_rfc3339();
-        let _ = store
+        match store
             .delete(schema::TABLE_SESSIONS, &format!("expires_at < '{now}'"))
-            .await;
+            .await
+        {
+            Ok(m) => report.sessions_deleted = m.num_deleted_rows,
+            Err(e) => report.errors.push((schema::TABLE_SESSIONS.to_string(), e.to_string())),
+        }
 
         // Compact all tables
         for table_def in schema::all_tables() {
-            let _ = store.compact(table_def.name).await;
+            match store.compact(table_def.name).await {
+                Ok(m) => report.per_table_compaction.push((table_def.name.to_string(), m)),
+                Err(e) => report.errors.push((table_def.name.to_string(), e.to_string())),
+            }
         }
 
         // Vacuum
         let retention = store.config().vacuum_retention_hours;
         for table_def in schema::all_tables() {
-            let _ = store.vacuum(table_def.name, retention, false).await;
+            match store.vacuum(table_def.name, retention, false).await {
+                Ok(m) => report.per_table_vacuum.push((table_def.name.to_string(), m)),
+                Err(e) => report.errors.push((table_def.name.to_string(), e.to_string())),
+            }
         }
 
-        info!("Maintenance cycle complete");
-        Ok(())
+        info!(errors = report.errors.len(), "Maintenance cycle complete");
+        Ok(report)
     }
 
     /// Stop all background tasks
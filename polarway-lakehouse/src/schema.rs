@@ -32,6 +32,9 @@ pub fn users_arrow_schema() -> Schema {
         Field::new("created_at", DataType::Utf8, false),
         Field::new("last_login", DataType::Utf8, true),
         Field::new("preferences_json", DataType::Utf8, true),
+        // Null on rows from before email verification; read as verified
+        Field::new("email_verified", DataType::Boolean, true),
+        Field::new("email_verification_hash", DataType::Utf8, true),
     ])
 }
 
@@ -50,6 +53,8 @@ pub fn users_delta_fields() -> Vec<StructField> {
         StructField::new("created_at", DeltaDataType::Primitive(PrimitiveType::String), false),
         StructField::new("last_login", DeltaDataType::Primitive(PrimitiveType::String), true),
         StructField::new("preferences_json", DeltaDataType::Primitive(PrimitiveType::String), true),
+        StructField::new("email_verified", DeltaDataType::Primitive(PrimitiveType::Boolean), true),
+        StructField::new("email_verification_hash", DeltaDataType::Primitive(PrimitiveType::String), true),
     ]
 }
 
@@ -69,6 +74,8 @@ pub fn sessions_arrow_schema() -> Schema {
         Field::new("created_at", DataType::Utf8, false),
         Field::new("expires_at", DataType::Utf8, false),
         Field::new("is_revoked", DataType::Boolean, false),
+        Field::new("refresh_token_hash", DataType::Utf8, true),
+        Field::new("family_id", DataType::Utf8, true),
     ])
 }
 
@@ -82,6 +89,8 @@ pub fn sessions_delta_fields() -> Vec<StructField> {
         StructField::new("created_at", DeltaDataType::Primitive(PrimitiveType::String), false),
         StructField::new("expires_at", DeltaDataType::Primitive(PrimitiveType::String), false),
         StructField::new("is_revoked", DeltaDataType::Primitive(PrimitiveType::Boolean), false),
+        StructField::new("refresh_token_hash", DeltaDataType::Primitive(PrimitiveType::String), true),
+        StructField::new("family_id", DeltaDataType::Primitive(PrimitiveType::String), true),
     ]
 }
 
@@ -28,13 +28,16 @@
 //! }
 //! ```
 
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 use deltalake::arrow::array::RecordBatch;
+use deltalake::datafusion::prelude::SessionContext;
 use deltalake::kernel::StructField;
 use deltalake::protocol::SaveMode;
 use deltalake::writer::{DeltaWriter, RecordBatchWriter};
 use deltalake::{open_table, open_table_with_ds, open_table_with_version, DeltaTable};
+use futures::{Stream, StreamExt};
 use tracing::{debug, info, warn};
 use url::Url;
 
@@ -73,11 +76,46 @@ pub struct VacuumMetrics {
     pub dry_run: bool,
 }
 
+/// Make sure delta-rs has a handler for the scheme of `base_path`
+///
+/// Cloud handlers are behind the `s3` / `gcs` cargo features.
+fn register_object_store(config: &LakehouseConfig) -> Result<()> {
+    let Some(url) = config.base_url() else {
+        return Ok(());
+    };
+    match url.scheme() {
+        "file" => Ok(()),
+        #[cfg(feature = "s3")]
+        "s3" | "s3a" => {
+            deltalake::aws::register_handlers(None);
+            Ok(())
+        }
+        #[cfg(feature = "gcs")]
+        "gs" => {
+            deltalake::gcp::register_handlers(None);
+            Ok(())
+        }
+        scheme => Err(LakehouseError::Config(format!(
+            "No object store handler for '{scheme}://' (is the matching feature enabled?)"
+        ))),
+    }
+}
+
 /// Core Delta Lake store — manages all tables under a base path
 ///
 /// Thread-safe: can be shared across tokio tasks via `Arc<DeltaStore>`.
 pub struct DeltaStore {
     config: LakehouseConfig,
+    auto_compact: Arc<Mutex<AutoCompactState>>,
+}
+
+/// Per-table bookkeeping for auto-compaction
+#[derive(Default)]
+struct AutoCompactState {
+    /// File count right after the last compaction this store ran
+    baseline: HashMap<String, usize>,
+    /// Tables with a compaction in flight
+    running: HashSet<String>,
 }
 
 impl DeltaStore {
@@ -92,7 +130,11 @@ impl DeltaStore {
     /// └── user_actions/   (partitioned by date)
     /// ```
     pub async fn new(config: LakehouseConfig) -> Result<Self> {
-        let store = Self { config };
+        register_object_store(&config)?;
+        let store = Self {
+            config,
+            auto_compact: Arc::default(),
+        };
         store.init_all_tables().await?;
         info!(
             path = %store.config.base_path.display(),
@@ -103,10 +145,7 @@ impl DeltaStore {
 
     /// Convert a table name to a `Url` pointing at the table directory
     fn table_url(&self, name: &str) -> Result<Url> {
-        let path = self.config.table_path(name);
-        Url::from_directory_path(&path).map_err(|_| {
-            LakehouseError::Config(format!("Invalid table path: {}", path.display()))
-        })
+        self.config.table_url(name)
     }
 
     /// Initialize all Delta tables (idempotent — safe to call multiple times)
@@ -123,6 +162,10 @@ impl DeltaStore {
     }
 
     /// Create a Delta table if it doesn't exist
+    ///
+    /// An existing table gets any of `fields` it lacks via
+    /// [`add_column`](Self::add_column), so tables created by older versions
+    /// pick up new columns (as nullable) on startup.
     pub async fn ensure_table(
         &self,
         name: &str,
@@ -130,17 +173,28 @@ impl DeltaStore {
         partition_columns: Vec<String>,
     ) -> Result<()> {
         let url = self.table_url(name)?;
-        let path = self.config.table_path(name);
 
         // Try to open existing table first
         match open_table(url.clone()).await {
             Ok(table) => {
                 debug!(table = name, version = ?table.version(), "Table already exists");
+                let missing: Vec<StructField> = {
+                    let existing = table.snapshot()?.schema();
+                    fields
+                        .into_iter()
+                        .filter(|field| existing.field(field.name()).is_none())
+                        .collect()
+                };
+                for field in missing {
+                    self.add_column(name, field).await?;
+                }
                 Ok(())
             }
             Err(_) => {
-                // Create directory and table
-                std::fs::create_dir_all(&path)?;
+                // Create directory (local only — object stores have no directories) and table
+                if let Some(path) = self.config.local_table_path(name) {
+                    std::fs::create_dir_all(&path)?;
+                }
 
                 let table = DeltaTable::try_from_url(url).await?;
                 let mut builder = table
@@ -174,9 +228,62 @@ impl DeltaStore {
         let version = writer.flush_and_commit(&mut table).await?;
 
         debug!(table = table_name, version, "Appended records");
+        self.maybe_auto_compact(table_name, &table).await;
         Ok(version as i64)
     }
 
+    /// Start a background compaction of `table_name` once more than
+    /// `auto_compact_threshold` files were added since its last one
+    ///
+    /// Counting added files rather than all files keeps partitioned tables,
+    /// which never compact below one file per partition, from compacting on
+    /// every write. At most one compaction per table runs at a time. The
+    /// write has already committed, so failures are logged, not returned.
+    async fn maybe_auto_compact(&self, table_name: &str, table: &DeltaTable) {
+        let threshold = self.config.auto_compact_threshold;
+        if threshold == 0 {
+            return;
+        }
+        let files = match table.get_file_uris() {
+            Ok(uris) => uris.count(),
+            Err(e) => {
+                warn!(table = table_name, error = ?e, "Could not count files for auto-compaction");
+                return;
+            }
+        };
+
+        {
+            let mut state = self.auto_compact.lock().unwrap_or_else(|e| e.into_inner());
+            let baseline = state.baseline.get(table_name).copied().unwrap_or(0);
+            let added = files.saturating_sub(baseline);
+            if added <= threshold || !state.running.insert(table_name.to_string()) {
+                return;
+            }
+            debug!(table = table_name, added, threshold, "Files added over threshold, compacting in background");
+        }
+
+        let store = Self {
+            config: self.config.clone(),
+            auto_compact: Arc::clone(&self.auto_compact),
+        };
+        let table_name = table_name.to_string();
+        tokio::spawn(async move {
+            let result = store.compact(&table_name).await;
+            let files_after = store.file_count(&table_name).await;
+
+            let mut state = store.auto_compact.lock().unwrap_or_else(|e| e.into_inner());
+            state.running.remove(&table_name);
+            match (result, files_after) {
+                (Ok(_), Ok(files)) => {
+                    state.baseline.insert(table_name, files);
+                }
+                (Err(e), _) | (_, Err(e)) => {
+                    warn!(table = %table_name, error = ?e, "Auto-compaction failed");
+                }
+            }
+        });
+    }
+
     /// Delete rows matching a SQL predicate
     ///
     /// # Example
@@ -214,22 +321,7 @@ impl DeltaStore {
 
     /// Read all rows from a table (current version)
     pub async fn scan(&self, table_name: &str) -> Result<Vec<RecordBatch>> {
-        let url = self.table_url(table_name)?;
-        let table = open_table(url).await?;
-        let table_provider: Arc<dyn deltalake::datafusion::catalog::TableProvider> = Arc::new(table);
-
-        let ctx = deltalake::datafusion::prelude::SessionContext::new();
-        ctx.register_table("t", table_provider)
-            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
-
-        let df = ctx
-            .sql("SELECT * FROM t")
-            .await
-            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
-        let batches = df
-            .collect()
-            .await
-            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+        let batches = self.select(table_name, None, None).await?;
 
         debug!(
             table = table_name,
@@ -239,36 +331,85 @@ impl DeltaStore {
         Ok(batches)
     }
 
-    /// Query a table with a SQL WHERE clause
+    /// Read only the given columns from a table (current version)
     ///
-    /// Uses DataFusion for predicate pushdown and efficient scanning.
+    /// Unselected columns are never decoded. Returns
+    /// `LakehouseError::SchemaMismatch` if a column is not in the table.
     ///
     /// # Example
     /// ```rust,no_run
     /// # use polarway_lakehouse::{DeltaStore, LakehouseConfig};
     /// # async fn example(store: &DeltaStore) -> polarway_lakehouse::Result<()> {
-    /// let users = store.query("users", "role = 'admin' AND is_active = true").await?;
+    /// let logins = store.scan_columns("audit_log", &["user_id", "timestamp"]).await?;
     /// # Ok(()) }
     /// ```
-    pub async fn query(&self, table_name: &str, sql_where: &str) -> Result<Vec<RecordBatch>> {
-        let url = self.table_url(table_name)?;
-        let table = open_table(url).await?;
-        let table_provider: Arc<dyn deltalake::datafusion::catalog::TableProvider> = Arc::new(table);
+    pub async fn scan_columns(
+        &self,
+        table_name: &str,
+        columns: &[&str],
+    ) -> Result<Vec<RecordBatch>> {
+        let batches = self.select(table_name, Some(columns), None).await?;
 
-        let ctx = deltalake::datafusion::prelude::SessionContext::new();
-        ctx.register_table("t", table_provider)
-            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+        debug!(
+            table = table_name,
+            columns = ?columns,
+            batches = batches.len(),
+            "Scanned table columns"
+        );
+        Ok(batches)
+    }
+
+    /// Stream all rows from a table (current version) batch by batch
+    ///
+    /// Unlike [`scan`](Self::scan), batches are produced lazily as the
+    /// consumer polls, so large tables like `audit_log` never have to fit
+    /// in memory at once.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use polarway_lakehouse::{DeltaStore, LakehouseConfig};
+    /// # async fn example(store: &DeltaStore) -> polarway_lakehouse::Result<()> {
+    /// use futures::StreamExt;
+    ///
+    /// let mut stream = store.scan_stream("audit_log").await?;
+    /// while let Some(batch) = stream.next().await {
+    ///     println!("{} rows", batch?.num_rows());
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub async fn scan_stream(
+        &self,
+        table_name: &str,
+    ) -> Result<impl Stream<Item = Result<RecordBatch>> + Send + Unpin> {
+        let ctx = self.table_context(table_name).await?;
 
-        let sql = format!("SELECT * FROM t WHERE {sql_where}");
         let df = ctx
-            .sql(&sql)
+            .sql("SELECT * FROM t")
             .await
             .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
-        let batches = df
-            .collect()
+        let stream = df
+            .execute_stream()
             .await
             .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
 
+        debug!(table = table_name, "Streaming table scan");
+        Ok(stream.map(|batch| batch.map_err(LakehouseError::from)))
+    }
+
+    /// Query a table with a SQL WHERE clause
+    ///
+    /// Uses DataFusion for predicate pushdown and efficient scanning.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use polarway_lakehouse::{DeltaStore, LakehouseConfig};
+    /// # async fn example(store: &DeltaStore) -> polarway_lakehouse::Result<()> {
+    /// let users = store.query("users", "role = 'admin' AND is_active = true").await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn query(&self, table_name: &str, sql_where: &str) -> Result<Vec<RecordBatch>> {
+        let batches = self.select(table_name, None, Some(sql_where)).await?;
+
         debug!(table = table_name, predicate = sql_where, "Query executed");
         Ok(batches)
     }
@@ -307,6 +448,73 @@ impl DeltaStore {
         Ok(batches)
     }
 
+    /// Run `SELECT <columns> FROM t [WHERE ...]` against the current version
+    ///
+    /// `None` selects all columns. Requested columns are checked against the
+    /// table's Arrow schema before any data is read.
+    async fn select(
+        &self,
+        table_name: &str,
+        columns: Option<&[&str]>,
+        sql_where: Option<&str>,
+    ) -> Result<Vec<RecordBatch>> {
+        let ctx = self.table_context(table_name).await?;
+
+        let projection = match columns {
+            Some(columns) => {
+                let table = ctx
+                    .table("t")
+                    .await
+                    .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+                let schema = table.schema();
+                let available: Vec<&str> =
+                    schema.fields().iter().map(|f| f.name().as_str()).collect();
+
+                if let Some(unknown) = columns.iter().find(|c| !available.contains(c)) {
+                    return Err(LakehouseError::SchemaMismatch {
+                        expected: format!("one of [{}]", available.join(", ")),
+                        actual: (*unknown).to_string(),
+                    });
+                }
+
+                // Quote so mixed-case names aren't folded to lowercase
+                columns
+                    .iter()
+                    .map(|c| format!("\"{}\"", c.replace('"', "\"\"")))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            }
+            None => "*".to_string(),
+        };
+
+        let sql = match sql_where {
+            Some(predicate) => format!("SELECT {projection} FROM t WHERE {predicate}"),
+            None => format!("SELECT {projection} FROM t"),
+        };
+        let df = ctx
+            .sql(&sql)
+            .await
+            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+        let batches = df
+            .collect()
+            .await
+            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+
+        Ok(batches)
+    }
+
+    /// Session with the current version of `table_name` registered as `t`
+    async fn table_context(&self, table_name: &str) -> Result<SessionContext> {
+        let url = self.table_url(table_name)?;
+        let table = open_table(url).await?;
+        let table_provider: Arc<dyn deltalake::datafusion::catalog::TableProvider> = Arc::new(table);
+
+        let ctx = SessionContext::new();
+        ctx.register_table("t", table_provider)
+            .map_err(|e| LakehouseError::DataFusion(e.to_string()))?;
+        Ok(ctx)
+    }
+
     // ─── Time-Travel ───
 
     /// Read a table at a specific version
@@ -393,7 +601,14 @@ impl DeltaStore {
         Ok(table.version().unwrap_or(0))
     }
 
-    /// Get version history for a table
+    /// Number of active data files in the current version of a table
+    pub async fn file_count(&self, table_name: &str) -> Result<usize> {
+        let url = self.table_url(table_name)?;
+        let table = open_table(url).await?;
+        Ok(table.get_file_uris()?.count())
+    }
+
+    /// Get version history for a table, newest first
     pub async fn history(
         &self,
         table_name: &str,
@@ -404,11 +619,15 @@ impl DeltaStore {
 
         let commits: Vec<_> = table.history(limit).await?.collect();
 
+        // Commits come back newest first, one per version down from the
+        // current one. `read_version` is the version a commit was based on,
+        // not its own, and is often absent — don't use it.
+        let current = table.version().unwrap_or(0);
         let versions: Vec<VersionInfo> = commits
             .into_iter()
             .enumerate()
             .map(|(idx, ci)| VersionInfo {
-                version: ci.read_version.unwrap_or(idx as i64),
+                version: current - idx as i64,
                 timestamp: ci.timestamp,
                 operation: ci.operation,
                 parameters: ci
@@ -420,6 +639,47 @@ impl DeltaStore {
         Ok(versions)
     }
 
+    // ─── Schema Evolution ───
+
+    /// Add a column to an existing table without rewriting its data
+    ///
+    /// The column is always added as nullable; existing rows read it as null.
+    /// Adding a column that already exists with the same type is a no-op,
+    /// with a different type it returns `LakehouseError::SchemaMismatch`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use polarway_lakehouse::{DeltaStore, LakehouseConfig};
+    /// # async fn example(store: &DeltaStore) -> polarway_lakehouse::Result<()> {
+    /// use deltalake::kernel::{DataType, PrimitiveType, StructField};
+    ///
+    /// let field = StructField::new("is_active", DataType::Primitive(PrimitiveType::Boolean), true);
+    /// let version = store.add_column("widgets", field).await?;
+    /// # Ok(()) }
+    /// ```
+    pub async fn add_column(&self, table_name: &str, field: StructField) -> Result<i64> {
+        let url = self.table_url(table_name)?;
+        let table = open_table(url).await?;
+
+        if let Some(existing) = table.snapshot()?.schema().field(field.name()) {
+            if existing.data_type() == field.data_type() {
+                debug!(table = table_name, column = field.name(), "Column already exists");
+                return Ok(table.version().unwrap_or(0));
+            }
+            return Err(LakehouseError::SchemaMismatch {
+                expected: format!("{}: {:?}", field.name(), existing.data_type()),
+                actual: format!("{}: {:?}", field.name(), field.data_type()),
+            });
+        }
+
+        let column = StructField::new(field.name(), field.data_type().clone(), true);
+        let new_table = table.add_columns().with_fields([column]).await?;
+        let version = new_table.version().unwrap_or(-1);
+
+        info!(table = table_name, column = field.name(), version, "Added column");
+        Ok(version)
+    }
+
     // ─── Optimization ───
 
     /// Compact small files into larger ones (improves read performance)
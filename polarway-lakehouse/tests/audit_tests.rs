@@ -0,0 +1,195 @@
+//! AuditActor integration tests — event logging and read-back
+
+use std::sync::Arc;
+
+use deltalake::arrow::array::{ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray};
+use tempfile::TempDir;
+
+use polarway_lakehouse::audit::{ActionType, AuditActor, ExportFormat};
+use polarway_lakehouse::config::LakehouseConfig;
+use polarway_lakehouse::schema;
+use polarway_lakehouse::store::DeltaStore;
+use polarway_lakehouse::LakehouseError;
+
+fn test_config(dir: &TempDir) -> LakehouseConfig {
+    LakehouseConfig::new(dir.path().to_str().unwrap())
+        .with_jwt_secret("test-secret-jwt-key-min-32-chars!!")
+}
+
+#[tokio::test]
+async fn test_log_and_read_back() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(DeltaStore::new(test_config(&dir)).await.unwrap());
+    let handle = AuditActor::spawn(store).await;
+
+    handle
+        .log(
+            "user-123".into(),
+            "alice".into(),
+            ActionType::BacktestRun,
+            Some("strategy-456".into()),
+            "Backtest on BTC/USD 1m".into(),
+            Some("10.0.0.1".into()),
+            Some("polarway-cli/1.0".into()),
+        )
+        .await;
+
+    // Messages are processed in order, so the log is written by now
+    let entries = handle.get_user_activity("user-123".into(), 10).await;
+    assert_eq!(entries.len(), 1);
+
+    let entry = &entries[0];
+    assert!(!entry.event_id.is_empty());
+    assert_eq!(entry.user_id, "user-123");
+    assert_eq!(entry.username, "alice");
+    assert_eq!(entry.action, ActionType::BacktestRun);
+    assert_eq!(entry.resource.as_deref(), Some("strategy-456"));
+    assert_eq!(entry.detail, "Backtest on BTC/USD 1m");
+    assert_eq!(entry.ip_address.as_deref(), Some("10.0.0.1"));
+    assert_eq!(entry.user_agent.as_deref(), Some("polarway-cli/1.0"));
+    assert!(chrono::DateTime::parse_from_rfc3339(&entry.timestamp).is_ok());
+    assert_eq!(entry.date_partition, &entry.timestamp[..10]);
+}
+
+#[tokio::test]
+async fn test_export_csv() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(DeltaStore::new(test_config(&dir)).await.unwrap());
+    let handle = AuditActor::spawn(store).await;
+
+    for (user_id, action) in [
+        ("user-1", ActionType::QueryExecuted),
+        ("user-1", ActionType::DataExport),
+        ("user-1", ActionType::BacktestRun),
+        ("user-2", ActionType::Login),
+    ] {
+        handle
+            .log(user_id.into(), "someone".into(), action, None, "seeded, with comma".into(), None, None)
+            .await;
+    }
+
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let csv = handle
+        .export(Some("user-1".into()), today.clone(), today.clone(), ExportFormat::Csv)
+        .await
+        .unwrap();
+
+    let mut reader = deltalake::arrow::csv::ReaderBuilder::new(Arc::new(
+        schema::audit_log_arrow_schema(),
+    ))
+    .with_header(true)
+    .build(std::io::Cursor::new(csv))
+    .unwrap();
+    let batch = reader.next().unwrap().unwrap();
+    assert_eq!(batch.num_rows(), 3);
+    assert!(reader.next().is_none());
+
+    // Whole range, all users
+    let csv = handle
+        .export(None, today.clone(), today.clone(), ExportFormat::Csv)
+        .await
+        .unwrap();
+    let text = String::from_utf8(csv).unwrap();
+    assert_eq!(text.lines().count(), 1 + 4);
+
+    // Nothing before the seeded range
+    let parquet = handle
+        .export(None, "2000-01-01".into(), "2000-12-31".into(), ExportFormat::Parquet)
+        .await
+        .unwrap();
+    assert_eq!(&parquet[..4], b"PAR1");
+
+    // Malformed dates are refused; quotes in user ids only match literally
+    let result = handle
+        .export(None, "2000-01-01' OR '1'='1".into(), today.clone(), ExportFormat::Csv)
+        .await;
+    assert!(matches!(result, Err(LakehouseError::InvalidAuditQuery(_))));
+    let csv = handle
+        .export(Some("x' OR '1'='1".into()), today.clone(), today, ExportFormat::Csv)
+        .await
+        .unwrap();
+    // No rows; at most a header line
+    assert!(String::from_utf8(csv).unwrap().lines().count() <= 1);
+}
+
+#[tokio::test]
+async fn test_events_page_keyset_pagination() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(DeltaStore::new(test_config(&dir)).await.unwrap());
+    let handle = AuditActor::spawn(store).await;
+
+    for i in 0..5 {
+        handle
+            .log(format!("user-{i}"), "someone".into(), ActionType::QueryExecuted, None, format!("event {i}"), None, None)
+            .await;
+    }
+
+    let mut seen = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = handle.get_events_page(cursor.clone(), 2).await;
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 2);
+        cursor = Some(page.last().unwrap().timestamp.clone());
+        seen.extend(page);
+    }
+
+    // Newest first, every event exactly once
+    let details: Vec<&str> = seen.iter().map(|e| e.detail.as_str()).collect();
+    assert_eq!(details, vec!["event 4", "event 3", "event 2", "event 1", "event 0"]);
+    assert!(seen.windows(2).all(|w| w[0].timestamp > w[1].timestamp));
+
+    // Only timestamps are accepted as cursors
+    let injected = handle.get_events_page(Some("9999' OR '1'='1".into()), 10).await;
+    assert!(injected.is_empty());
+}
+
+#[tokio::test]
+async fn test_billing_summary_weights_credits() {
+    let dir = TempDir::new().unwrap();
+    let store = Arc::new(DeltaStore::new(test_config(&dir)).await.unwrap());
+    let handle = AuditActor::spawn(store.clone()).await;
+
+    for action in [ActionType::QueryExecuted, ActionType::QueryExecuted, ActionType::BacktestRun, ActionType::Login] {
+        handle
+            .log("user-1".into(), "alice".into(), action, None, String::new(), None, None)
+            .await;
+    }
+
+    // 5s of compute on 2M rows, recorded by the lab
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let usage = RecordBatch::try_new(
+        Arc::new(schema::user_actions_arrow_schema()),
+        vec![
+            Arc::new(StringArray::from(vec!["a-1"])) as ArrayRef,
+            Arc::new(StringArray::from(vec![chrono::Utc::now().to_rfc3339()])),
+            Arc::new(StringArray::from(vec!["user-1"])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec!["backtest_run"])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(Int64Array::from(vec![Some(2_000_000)])),
+            Arc::new(Float64Array::from(vec![Some(5_000.0)])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec![today.as_str()])),
+        ],
+    )
+    .unwrap();
+    store.append(schema::TABLE_USER_ACTIONS, usage).await.unwrap();
+
+    let summary = handle
+        .billing_summary("user-1".into(), today.clone(), today)
+        .await
+        .unwrap();
+
+    assert_eq!(summary.total_actions, 4);
+    assert_eq!(summary.total_queries, 2);
+    assert_eq!(summary.total_backtests, 1);
+
+    // 2 queries * 1 + 1 backtest * 10 + login * 0, plus 5s * 0.1 and 2M rows * 1.0
+    assert!((summary.total_credits - 14.5).abs() < 1e-9, "{}", summary.total_credits);
+    assert_ne!(summary.total_credits, summary.total_actions as f64);
+}
@@ -4,6 +4,7 @@ use tempfile::TempDir;
 
 use polarway_lakehouse::auth::{AuthActor, SubscriptionTier, UserRole};
 use polarway_lakehouse::config::LakehouseConfig;
+use polarway_lakehouse::LakehouseError;
 
 fn test_config(dir: &TempDir) -> LakehouseConfig {
     LakehouseConfig::new(dir.path().to_str().unwrap())
@@ -31,14 +32,20 @@ async fn test_register_and_login() {
     assert_eq!(user.username, "alice");
     assert_eq!(user.role, UserRole::Pending);
     assert_eq!(user.subscription_tier, Some(SubscriptionTier::Pioneer));
+    assert!(!user.email_verified);
+
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
 
     // Login fails for pending users? No — login should succeed, just role is pending
-    let (token, logged_in) = handle
+    let (tokens, logged_in) = handle
         .login("alice".into(), "StrongP@ss123".into(), false)
         .await
         .unwrap();
 
-    assert!(!token.is_empty());
+    assert!(!tokens.access_token.is_empty());
     assert_eq!(logged_in.username, "alice");
 }
 
@@ -47,7 +54,7 @@ async fn test_verify_token() {
     let dir = TempDir::new().unwrap();
     let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
 
-    handle
+    let user = handle
         .register(
             "bob".into(),
             "bob@example.com".into(),
@@ -59,13 +66,18 @@ async fn test_verify_token() {
         .await
         .unwrap();
 
-    let (token, _) = handle
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let (tokens, _) = handle
         .login("bob".into(), "SecureP@ss99".into(), false)
         .await
         .unwrap();
 
     // Verify valid token
-    let user = handle.verify_token(token.clone()).await;
+    let user = handle.verify_token(tokens.access_token.clone()).await;
     assert!(user.is_some());
     assert_eq!(user.unwrap().username, "bob");
 
@@ -79,7 +91,7 @@ async fn test_logout() {
     let dir = TempDir::new().unwrap();
     let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
 
-    handle
+    let user = handle
         .register(
             "charlie".into(),
             "charlie@example.com".into(),
@@ -91,17 +103,22 @@ async fn test_logout() {
         .await
         .unwrap();
 
-    let (token, _) = handle
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let (tokens, _) = handle
         .login("charlie".into(), "MyP@ssword1".into(), false)
         .await
         .unwrap();
 
     // Logout
-    let ok = handle.logout(token.clone()).await;
+    let ok = handle.logout(tokens.access_token.clone()).await;
     assert!(ok);
 
     // Token should no longer verify
-    let user = handle.verify_token(token).await;
+    let user = handle.verify_token(tokens.access_token).await;
     assert!(user.is_none());
 }
 
@@ -138,6 +155,300 @@ async fn test_approve_user() {
     assert!(pending.is_empty());
 }
 
+#[tokio::test]
+async fn test_login_after_approval() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "erin".into(),
+            "erin@example.com".into(),
+            "Approv3dPass!".into(),
+            "Erin".into(),
+            "Moss".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    handle
+        .approve_user(user.user_id.clone(), SubscriptionTier::Pioneer)
+        .await
+        .unwrap();
+
+    // Approval must keep the original password hash
+    let (tokens, logged_in) = handle
+        .login("erin".into(), "Approv3dPass!".into(), false)
+        .await
+        .unwrap();
+    assert!(!tokens.access_token.is_empty());
+    assert_eq!(logged_in.user_id, user.user_id);
+    assert_ne!(logged_in.role, UserRole::Pending);
+}
+
+#[tokio::test]
+async fn test_login_sets_last_login() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "frank".into(),
+            "frank@example.com".into(),
+            "L0ginTracked!".into(),
+            "Frank".into(),
+            "Ocean".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+    assert!(user.last_login.is_none());
+
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let before = chrono::Utc::now();
+    handle
+        .login("frank".into(), "L0ginTracked!".into(), false)
+        .await
+        .unwrap();
+
+    let fetched = handle.get_user(user.user_id.clone()).await.unwrap();
+    let last_login = chrono::DateTime::parse_from_rfc3339(fetched.last_login.as_deref().unwrap())
+        .unwrap();
+    assert!(last_login >= before - chrono::Duration::seconds(1));
+    assert!(last_login <= chrono::Utc::now());
+
+    // The rewritten row keeps the password hash
+    handle
+        .login("frank".into(), "L0ginTracked!".into(), false)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_list_and_revoke_all_sessions() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "grace".into(),
+            "grace@example.com".into(),
+            "Sess1onsPass!".into(),
+            "Grace".into(),
+            "Hopper".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let (tokens_a, _) = handle
+        .login("grace".into(), "Sess1onsPass!".into(), false)
+        .await
+        .unwrap();
+    let (tokens_b, _) = handle
+        .login("grace".into(), "Sess1onsPass!".into(), true)
+        .await
+        .unwrap();
+    assert_ne!(tokens_a.access_token, tokens_b.access_token);
+
+    let sessions = handle.list_sessions(user.user_id.clone()).await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert!(sessions.iter().all(|s| !s.is_revoked && s.token_hash_prefix.len() == 12));
+
+    let revoked = handle.revoke_all_sessions(user.user_id.clone()).await.unwrap();
+    assert_eq!(revoked, 2);
+
+    assert!(handle.verify_token(tokens_a.access_token).await.is_none());
+    assert!(handle.verify_token(tokens_b.access_token).await.is_none());
+    assert!(handle.list_sessions(user.user_id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_email_verification_gate() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "ivy".into(),
+            "ivy@example.com".into(),
+            "Ver1fyMe!Pass".into(),
+            "Ivy".into(),
+            "League".into(),
+            SubscriptionTier::Free,
+        )
+        .await
+        .unwrap();
+    let token = user.email_verification_token.clone().unwrap();
+
+    // Unverified users can't log in
+    let result = handle
+        .login("ivy".into(), "Ver1fyMe!Pass".into(), false)
+        .await;
+    assert!(matches!(result, Err(LakehouseError::EmailNotVerified(_))));
+
+    // Unknown tokens are rejected
+    assert!(handle.verify_email("not-a-token".into()).await.is_err());
+
+    let verified = handle.verify_email(token.clone()).await.unwrap();
+    assert!(verified.email_verified);
+    assert_eq!(verified.user_id, user.user_id);
+
+    let (tokens, logged_in) = handle
+        .login("ivy".into(), "Ver1fyMe!Pass".into(), false)
+        .await
+        .unwrap();
+    assert!(!tokens.access_token.is_empty());
+    assert!(logged_in.email_verified);
+
+    // Tokens are single-use
+    assert!(handle.verify_email(token).await.is_err());
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_tokens() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "jack".into(),
+            "jack@example.com".into(),
+            "R0tateMe!Pass".into(),
+            "Jack".into(),
+            "Sparrow".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let (first, _) = handle
+        .login("jack".into(), "R0tateMe!Pass".into(), false)
+        .await
+        .unwrap();
+
+    let second = handle.refresh(first.refresh_token.clone()).await.unwrap();
+    assert_ne!(second.access_token, first.access_token);
+    assert_ne!(second.refresh_token, first.refresh_token);
+
+    // The old access token is retired along with its refresh token
+    assert!(handle.verify_token(first.access_token).await.is_none());
+    let verified = handle.verify_token(second.access_token.clone()).await.unwrap();
+    assert_eq!(verified.user_id, user.user_id);
+
+    let third = handle.refresh(second.refresh_token).await.unwrap();
+    assert!(handle.verify_token(third.access_token).await.is_some());
+
+    assert!(handle.refresh("not-a-refresh-token".into()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_refresh_token_reuse_revokes_chain() {
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir)).await.unwrap();
+
+    let user = handle
+        .register(
+            "kate".into(),
+            "kate@example.com".into(),
+            "St0lenToken!".into(),
+            "Kate".into(),
+            "Bishop".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
+    let (first, _) = handle
+        .login("kate".into(), "St0lenToken!".into(), false)
+        .await
+        .unwrap();
+    // A separate login is a separate chain and must survive
+    let (other, _) = handle
+        .login("kate".into(), "St0lenToken!".into(), true)
+        .await
+        .unwrap();
+
+    let second = handle.refresh(first.refresh_token.clone()).await.unwrap();
+
+    // Replaying the consumed token kills everything derived from it
+    let replay = handle.refresh(first.refresh_token).await;
+    assert!(matches!(replay, Err(LakehouseError::TokenInvalid(_))));
+    assert!(handle.verify_token(second.access_token).await.is_none());
+    assert!(handle.refresh(second.refresh_token).await.is_err());
+
+    assert!(handle.verify_token(other.access_token).await.is_some());
+}
+
+#[tokio::test]
+async fn test_access_token_outlives_only_its_ttl() {
+    use jsonwebtoken::{decode, DecodingKey, Validation};
+    use polarway_lakehouse::auth::types::JwtClaims;
+
+    let dir = TempDir::new().unwrap();
+    let handle = AuthActor::spawn(test_config(&dir).with_access_token_ttl_minutes(5))
+        .await
+        .unwrap();
+
+    let user = handle
+        .register(
+            "kate".into(),
+            "kate@example.com".into(),
+            "Sh0rtLived!Pass".into(),
+            "Kate".into(),
+            "Austen".into(),
+            SubscriptionTier::Pioneer,
+        )
+        .await
+        .unwrap();
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+    let (tokens, _) = handle
+        .login("kate".into(), "Sh0rtLived!Pass".into(), true)
+        .await
+        .unwrap();
+
+    let claims = decode::<JwtClaims>(
+        &tokens.access_token,
+        &DecodingKey::from_secret(b"test-secret-jwt-key-min-32-chars!!"),
+        &Validation::default(),
+    )
+    .unwrap()
+    .claims;
+    assert_eq!(claims.exp - claims.iat, 5 * 60);
+
+    // The session itself still runs for the remember-me 30 days
+    let sessions = handle.list_sessions(user.user_id.clone()).await.unwrap();
+    let created = chrono::DateTime::parse_from_rfc3339(&sessions[0].created_at).unwrap();
+    let expires = chrono::DateTime::parse_from_rfc3339(&sessions[0].expires_at).unwrap();
+    assert_eq!((expires - created).num_days(), 30);
+}
+
 #[tokio::test]
 async fn test_duplicate_registration() {
     let dir = TempDir::new().unwrap();
@@ -211,6 +522,11 @@ async fn test_change_password() {
         .await
         .unwrap();
 
+    handle
+        .verify_email(user.email_verification_token.clone().unwrap())
+        .await
+        .unwrap();
+
     // Change password
     handle
         .change_password(
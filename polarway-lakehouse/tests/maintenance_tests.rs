@@ -0,0 +1,154 @@
+//! MaintenanceScheduler tests — per-table schedules and one-shot reports
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use deltalake::arrow::array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+use tempfile::TempDir;
+
+use polarway_lakehouse::config::LakehouseConfig;
+use polarway_lakehouse::maintenance::{
+    MaintenanceSchedule, MaintenanceScheduler, MaintenanceStore, TableSchedule,
+};
+use polarway_lakehouse::schema;
+use polarway_lakehouse::store::{CompactMetrics, DeleteMetrics, DeltaStore, VacuumMetrics};
+use polarway_lakehouse::Result;
+
+/// Records every call as `"<op>:<table>"`
+#[derive(Default)]
+struct SpyStore {
+    calls: Mutex<HashMap<String, usize>>,
+}
+
+impl SpyStore {
+    fn record(&self, op: &str, table: &str) {
+        *self.calls.lock().unwrap().entry(format!("{op}:{table}")).or_default() += 1;
+    }
+
+    fn count(&self, op: &str, table: &str) -> usize {
+        self.calls.lock().unwrap().get(&format!("{op}:{table}")).copied().unwrap_or(0)
+    }
+}
+
+fn compact_metrics() -> CompactMetrics {
+    CompactMetrics { files_added: 0, files_removed: 0, new_version: 0 }
+}
+
+#[async_trait]
+impl MaintenanceStore for SpyStore {
+    async fn compact(&self, table_name: &str) -> Result<CompactMetrics> {
+        self.record("compact", table_name);
+        Ok(compact_metrics())
+    }
+
+    async fn z_order(&self, table_name: &str, _columns: &[&str]) -> Result<CompactMetrics> {
+        self.record("z_order", table_name);
+        Ok(compact_metrics())
+    }
+
+    async fn vacuum(&self, table_name: &str, _retention_hours: u64, dry_run: bool) -> Result<VacuumMetrics> {
+        self.record("vacuum", table_name);
+        Ok(VacuumMetrics { files_deleted: 0, dry_run })
+    }
+
+    async fn delete(&self, table_name: &str, _predicate: &str) -> Result<DeleteMetrics> {
+        self.record("delete", table_name);
+        Ok(DeleteMetrics { num_deleted_rows: 0, new_version: 0 })
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_per_table_compaction_interval() {
+    let spy = Arc::new(SpyStore::default());
+    let schedule = MaintenanceSchedule::new().with_table(
+        schema::TABLE_AUDIT_LOG,
+        TableSchedule {
+            compact_interval: Duration::from_secs(60),
+            ..TableSchedule::default_for(schema::TABLE_AUDIT_LOG)
+        },
+    );
+
+    let mut scheduler = MaintenanceScheduler::with_store(spy.clone()).with_schedule(schedule);
+    scheduler.start();
+
+    // Every interval fires once immediately, then audit_log every minute
+    tokio::time::sleep(Duration::from_secs(5 * 60 + 1)).await;
+
+    assert_eq!(spy.count("compact", schema::TABLE_AUDIT_LOG), 6);
+    assert_eq!(spy.count("compact", schema::TABLE_USERS), 1);
+    assert_eq!(spy.count("vacuum", schema::TABLE_AUDIT_LOG), 1);
+
+    // users has no Z-order columns by default
+    assert_eq!(spy.count("z_order", schema::TABLE_USERS), 0);
+    assert_eq!(spy.count("z_order", schema::TABLE_SESSIONS), 1);
+
+    scheduler.stop();
+}
+
+fn make_user_batch(user_id: &str) -> RecordBatch {
+    RecordBatch::try_new(
+        Arc::new(schema::users_arrow_schema()),
+        vec![
+            Arc::new(StringArray::from(vec![user_id])) as ArrayRef,
+            Arc::new(StringArray::from(vec![format!("user_{user_id}")])),
+            Arc::new(StringArray::from(vec![format!("{user_id}@example.com")])),
+            Arc::new(StringArray::from(vec!["$argon2id$fake_hash"])),
+            Arc::new(StringArray::from(vec!["registered"])),
+            Arc::new(StringArray::from(vec![Some("free")])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(BooleanArray::from(vec![true])),
+            Arc::new(StringArray::from(vec!["2025-01-01T00:00:00Z"])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+            Arc::new(StringArray::from(vec![Some("{}")])),
+            Arc::new(BooleanArray::from(vec![true])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
+        ],
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_run_once_reports_outcomes() {
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(LakehouseConfig::new(dir.path().to_str().unwrap()))
+        .await
+        .unwrap();
+
+    // Three small files in users, nothing elsewhere
+    for i in 0..3 {
+        store
+            .append(schema::TABLE_USERS, make_user_batch(&format!("u{i}")))
+            .await
+            .unwrap();
+    }
+
+    let report = MaintenanceScheduler::run_once(&store).await.unwrap();
+
+    assert!(report.errors.is_empty(), "{:?}", report.errors);
+    assert_eq!(report.sessions_deleted, 0);
+
+    let tables = schema::all_tables().len();
+    assert_eq!(report.per_table_compaction.len(), tables);
+    assert_eq!(report.per_table_vacuum.len(), tables);
+
+    let (_, users) = report
+        .per_table_compaction
+        .iter()
+        .find(|(t, _)| t == schema::TABLE_USERS)
+        .unwrap();
+    assert_eq!(users.files_removed, 3);
+    assert_eq!(users.files_added, 1);
+
+    let (_, sessions) = report
+        .per_table_compaction
+        .iter()
+        .find(|(t, _)| t == schema::TABLE_SESSIONS)
+        .unwrap();
+    assert_eq!(sessions.files_removed, 0);
+
+    // Default 7-day retention keeps the freshly compacted-away files
+    assert!(report.per_table_vacuum.iter().all(|(_, v)| v.files_deleted == 0 && !v.dry_run));
+}
@@ -2,7 +2,8 @@
 
 use std::sync::Arc;
 
-use deltalake::arrow::array::{ArrayRef, BooleanArray, RecordBatch, StringArray};
+use deltalake::arrow::array::{Array, ArrayRef, BooleanArray, RecordBatch, StringArray};
+use futures::StreamExt;
 use tempfile::TempDir;
 
 use polarway_lakehouse::config::LakehouseConfig;
@@ -30,6 +31,8 @@ fn make_user_batch(user_id: &str, username: &str, email: &str) -> RecordBatch {
             Arc::new(StringArray::from(vec!["2025-01-01T00:00:00Z"])),
             Arc::new(StringArray::from(vec![None::<&str>])),
             Arc::new(StringArray::from(vec![Some("{}")])),
+            Arc::new(BooleanArray::from(vec![true])),
+            Arc::new(StringArray::from(vec![None::<&str>])),
         ],
     )
     .unwrap()
@@ -158,6 +161,35 @@ async fn test_history() {
     assert!(history.len() >= 3); // create + 2 appends
 }
 
+#[tokio::test]
+async fn test_history_versions_match_time_travel() {
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir)).await.unwrap();
+
+    for i in 0..3 {
+        let batch = make_user_batch(
+            &format!("u{i}"),
+            &format!("user{i}"),
+            &format!("user{i}@example.com"),
+        );
+        store.append(schema::TABLE_USERS, batch).await.unwrap();
+    }
+
+    let history = store.history(schema::TABLE_USERS, None).await.unwrap();
+    let versions: Vec<i64> = history.iter().map(|v| v.version).collect();
+    assert_eq!(versions, vec![3, 2, 1, 0]);
+
+    // Version N holds exactly the first N appends
+    for info in &history {
+        let batches = store.read_version(schema::TABLE_USERS, info.version).await.unwrap();
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows as i64, info.version);
+    }
+
+    let limited = store.history(schema::TABLE_USERS, Some(2)).await.unwrap();
+    assert_eq!(limited.iter().map(|v| v.version).collect::<Vec<_>>(), vec![3, 2]);
+}
+
 #[tokio::test]
 async fn test_compact_and_vacuum() {
     let dir = TempDir::new().unwrap();
@@ -181,6 +213,131 @@ async fn test_compact_and_vacuum() {
     assert!(vacuum_metrics.dry_run);
 }
 
+#[tokio::test]
+async fn test_auto_compact_after_append() {
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir).with_auto_compact_threshold(3))
+        .await
+        .unwrap();
+
+    let append = |i: usize| {
+        let batch = make_user_batch(
+            &format!("u{i}"),
+            &format!("user{i}"),
+            &format!("user{i}@example.com"),
+        );
+        store.append(schema::TABLE_USERS, batch)
+    };
+
+    // 4th append leaves 4 files > 3 and starts a background compaction
+    for i in 0..4 {
+        append(i).await.unwrap();
+    }
+
+    // create + 4 appends = version 4; the compaction bumps it once more
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(30);
+    while store.version(schema::TABLE_USERS).await.unwrap() < 5 {
+        assert!(tokio::time::Instant::now() < deadline, "auto-compaction never ran");
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert_eq!(store.file_count(schema::TABLE_USERS).await.unwrap(), 1);
+
+    let history = store.history(schema::TABLE_USERS, Some(1)).await.unwrap();
+    assert_eq!(history[0].operation.as_deref(), Some("OPTIMIZE"));
+
+    // Only files added since that compaction count toward the next one
+    append(4).await.unwrap();
+    assert_eq!(store.version(schema::TABLE_USERS).await.unwrap(), 6);
+
+    let batches = store.scan(schema::TABLE_USERS).await.unwrap();
+    assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 5);
+}
+
+#[tokio::test]
+async fn test_add_column() {
+    use deltalake::arrow::datatypes::{DataType, Field, Schema};
+    use deltalake::kernel::{DataType as DeltaDataType, PrimitiveType, StructField};
+    use polarway_lakehouse::LakehouseError;
+
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir)).await.unwrap();
+    store
+        .ensure_table(
+            "widgets",
+            vec![StructField::new("id", DeltaDataType::Primitive(PrimitiveType::String), false)],
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    let narrow = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)])),
+        vec![Arc::new(StringArray::from(vec!["w1"])) as ArrayRef],
+    )
+    .unwrap();
+    store.append("widgets", narrow).await.unwrap();
+
+    let is_active = StructField::new("is_active", DeltaDataType::Primitive(PrimitiveType::Boolean), true);
+    store.add_column("widgets", is_active.clone()).await.unwrap();
+
+    // Same column again is a no-op, a different type is rejected
+    store.add_column("widgets", is_active).await.unwrap();
+    let clash = StructField::new("is_active", DeltaDataType::Primitive(PrimitiveType::String), true);
+    assert!(matches!(
+        store.add_column("widgets", clash).await,
+        Err(LakehouseError::SchemaMismatch { .. })
+    ));
+
+    let wide = RecordBatch::try_new(
+        Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("is_active", DataType::Boolean, true),
+        ])),
+        vec![
+            Arc::new(StringArray::from(vec!["w2"])) as ArrayRef,
+            Arc::new(BooleanArray::from(vec![Some(true)])),
+        ],
+    )
+    .unwrap();
+    store.append("widgets", wide).await.unwrap();
+
+    let batches = store.query("widgets", "id = 'w2'").await.unwrap();
+    let flags = batches[0]
+        .column_by_name("is_active")
+        .unwrap()
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap();
+    assert!(flags.value(0));
+
+    // Rows written before the change read the new column as null
+    let batches = store.query("widgets", "id = 'w1'").await.unwrap();
+    assert!(batches[0].column_by_name("is_active").unwrap().is_null(0));
+}
+
+#[tokio::test]
+async fn test_ensure_table_adds_missing_columns() {
+    use deltalake::kernel::{DataType as DeltaDataType, PrimitiveType, StructField};
+
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir)).await.unwrap();
+    let id = StructField::new("id", DeltaDataType::Primitive(PrimitiveType::String), false);
+    store.ensure_table("widgets", vec![id.clone()], vec![]).await.unwrap();
+    assert_eq!(store.version("widgets").await.unwrap(), 0);
+
+    // A newer definition of the same table adds its new column once
+    let flag = StructField::new("flag", DeltaDataType::Primitive(PrimitiveType::Boolean), false);
+    store
+        .ensure_table("widgets", vec![id.clone(), flag.clone()], vec![])
+        .await
+        .unwrap();
+    assert_eq!(store.version("widgets").await.unwrap(), 1);
+    store.ensure_table("widgets", vec![id, flag], vec![]).await.unwrap();
+    assert_eq!(store.version("widgets").await.unwrap(), 1);
+
+    assert!(store.scan_columns("widgets", &["id", "flag"]).await.is_ok());
+}
+
 #[tokio::test]
 async fn test_sql_query() {
     let dir = TempDir::new().unwrap();
@@ -219,3 +376,59 @@ async fn test_gdpr_delete() {
     let total: usize = r.iter().map(|b| b.num_rows()).sum();
     assert_eq!(total, 0);
 }
+
+#[tokio::test]
+async fn test_scan_stream_yields_all_rows() {
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir)).await.unwrap();
+
+    for i in 0..20 {
+        let batch = make_user_batch(
+            &format!("u{i}"),
+            &format!("user{i}"),
+            &format!("user{i}@example.com"),
+        );
+        store.append(schema::TABLE_USERS, batch).await.unwrap();
+    }
+
+    // Consume batch by batch; rows are counted as they arrive, never concatenated
+    let mut stream = store.scan_stream(schema::TABLE_USERS).await.unwrap();
+    let mut total_rows = 0;
+    let mut batches = 0;
+    while let Some(batch) = stream.next().await {
+        let batch = batch.unwrap();
+        assert_eq!(batch.schema().fields().len(), schema::users_arrow_schema().fields().len());
+        total_rows += batch.num_rows();
+        batches += 1;
+    }
+
+    assert_eq!(total_rows, 20);
+    assert!(batches >= 1);
+}
+
+#[tokio::test]
+async fn test_scan_columns_projects() {
+    let dir = TempDir::new().unwrap();
+    let store = DeltaStore::new(test_config(&dir)).await.unwrap();
+
+    let batch = make_user_batch("u1", "alice", "alice@example.com");
+    store.append(schema::TABLE_USERS, batch).await.unwrap();
+
+    let results = store
+        .scan_columns(schema::TABLE_USERS, &["username", "email"])
+        .await
+        .unwrap();
+    let total: usize = results.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(total, 1);
+    for batch in &results {
+        let names: Vec<&str> = batch.schema_ref().fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["username", "email"]);
+    }
+
+    // Unknown columns are rejected before reading
+    let err = store
+        .scan_columns(schema::TABLE_USERS, &["username", "nope"])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, polarway_lakehouse::LakehouseError::SchemaMismatch { .. }));
+}
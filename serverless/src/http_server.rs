@@ -2,18 +2,25 @@
 // Works on any cloud provider or self-hosted environment
 
 use axum::{
-    extract::State,
-    http::StatusCode,
+    extract::{ConnectInfo, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
     response::{IntoResponse, Response},
     routing::{get, post},
     Router,
 };
+use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 // Import our generic handler
-use polarway_serverless::{PolarwayHandler, ServerlessHandler, ServerlessRequest, ServerlessResponse};
+use polarway_serverless::{
+    IpCidr, PolarwayHandler, ServerlessError, ServerlessHandler, ServerlessRequest, ServerlessResponse,
+    TokenValidator, REQUEST_ID_HEADER,
+};
 
 /// Convert axum::Request to ServerlessRequest
 async fn to_serverless_request(
@@ -27,9 +34,15 @@ async fn to_serverless_request(
         method,
         uri,
         headers,
+        extensions,
         ..
     } = parts;
 
+    // Present when served via `into_make_service_with_connect_info`
+    let remote_addr = extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
     // Extract headers
     let header_map: std::collections::HashMap<String, String> = headers
         .iter()
@@ -57,6 +70,7 @@ async fn to_serverless_request(
         headers: header_map,
         body: body_bytes.to_vec(),
         query_params,
+        remote_addr,
     }
 }
 
@@ -76,17 +90,21 @@ async fn handle_request(
     State(handler): State<Arc<dyn ServerlessHandler>>,
     req: axum::extract::Request,
 ) -> Response {
-    let serverless_req = to_serverless_request(req).await;
+    let mut serverless_req = to_serverless_request(req).await;
+
+    // Fix the ID here so error responses carry the same one the handler logged
+    let request_id = serverless_req.request_id();
+    serverless_req
+        .headers
+        .insert(REQUEST_ID_HEADER.to_string(), request_id.clone());
 
     match handler.handle_request(serverless_req).await {
         Ok(resp) => from_serverless_response(resp),
         Err(e) => {
-            tracing::error!("Handler error: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("{{\"error\": \"{}\"}}", e),
-            )
-                .into_response()
+            tracing::error!(request_id = %request_id, "Handler error: {}", e);
+            let mut resp = ServerlessResponse::error(e.status_code(), &e.to_string());
+            resp.headers.insert(REQUEST_ID_HEADER.to_string(), request_id);
+            from_serverless_response(resp)
         }
     }
 }
@@ -103,6 +121,227 @@ async fn health_check() -> impl IntoResponse {
     )
 }
 
+/// `/ws/stream`: push a file's batches over a WebSocket
+///
+/// The client sends `{ "path": ..., "chunk_rows": ... }` as its first text
+/// message, with `path` relative to the data root, and then receives one
+/// Arrow IPC binary frame per batch, followed by a normal close. Failures
+/// close the socket with code 1011 and the error as the reason.
+#[cfg(feature = "ws-stream")]
+mod ws_stream {
+    use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade};
+    use axum::extract::State;
+    use axum::response::Response;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+    use polars_ipc::prelude::{IpcWriter, SerWriter};
+    use polars_streaming_adaptive::{AdaptiveStreamingReader, ChunkStrategy};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct StreamRequest {
+        path: String,
+        chunk_rows: usize,
+    }
+
+    /// Always asks the reader for `chunk_rows` rows
+    struct FixedRows(usize);
+
+    impl ChunkStrategy for FixedRows {
+        fn calculate_chunk_size(&self, _available_memory: usize, _row_size: usize) -> usize {
+            self.0
+        }
+
+        fn adjust(&mut self, _actual_memory_used: usize, _processing_time_ms: u64) {}
+    }
+
+    /// `root` must already be canonical
+    pub(crate) async fn handler(State(root): State<Arc<PathBuf>>, ws: WebSocketUpgrade) -> Response {
+        ws.on_upgrade(move |socket| stream_batches(socket, root))
+    }
+
+    /// `path` under `root`, refusing anything that resolves outside it
+    ///
+    /// Symlinks and `..` are resolved first. Missing files get the same
+    /// error, so callers can't probe what exists outside the root.
+    pub(crate) fn resolve(root: &Path, path: &str) -> Result<PathBuf, String> {
+        root.join(path)
+            .canonicalize()
+            .ok()
+            .filter(|resolved| resolved.starts_with(root))
+            .ok_or_else(|| format!("No such file under the data root: {}", path))
+    }
+
+    async fn close_with_error(mut socket: WebSocket, reason: String) {
+        tracing::warn!("WebSocket stream failed: {}", reason);
+        let frame = CloseFrame {
+            code: close_code::ERROR,
+            reason: reason.into(),
+        };
+        let _ = socket.send(Message::Close(Some(frame))).await;
+    }
+
+    async fn stream_batches(mut socket: WebSocket, root: Arc<PathBuf>) {
+        let request = match socket.recv().await {
+            Some(Ok(Message::Text(text))) => serde_json::from_str::<StreamRequest>(&text),
+            _ => return,
+        };
+        let request = match request {
+            Ok(request) if request.chunk_rows > 0 => request,
+            Ok(_) => return close_with_error(socket, "chunk_rows must be greater than 0".to_string()).await,
+            Err(e) => return close_with_error(socket, format!("Invalid stream request: {}", e)).await,
+        };
+        let path = match resolve(&root, &request.path) {
+            Ok(path) => path,
+            Err(e) => return close_with_error(socket, e).await,
+        };
+
+        // The reader blocks, so it runs on its own thread; the small channel
+        // keeps it at most a couple of batches ahead of the client
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<Result<Vec<u8>, String>>(2);
+        tokio::task::spawn_blocking(move || {
+            let reader = match AdaptiveStreamingReader::new(&path) {
+                Ok(reader) => reader.with_strategy(Box::new(FixedRows(request.chunk_rows))),
+                Err(e) => {
+                    let _ = tx.blocking_send(Err(e.to_string()));
+                    return;
+                }
+            };
+
+            for batch in reader.collect_batches_adaptive() {
+                let frame = batch.map_err(|e| e.to_string()).and_then(|mut df| {
+                    let mut buffer = Vec::new();
+                    IpcWriter::new(&mut buffer)
+                        .finish(&mut df)
+                        .map(|_| buffer)
+                        .map_err(|e| e.to_string())
+                });
+                let failed = frame.is_err();
+
+                // A send error means the client went away
+                if tx.blocking_send(frame).is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        while let Some(frame) = rx.recv().await {
+            match frame {
+                Ok(bytes) => {
+                    if socket.send(Message::Binary(bytes)).await.is_err() {
+                        tracing::debug!("WebSocket client disconnected, stopping stream");
+                        return;
+                    }
+                }
+                Err(e) => return close_with_error(socket, e).await,
+            }
+        }
+
+        let _ = socket.send(Message::Close(None)).await;
+    }
+}
+
+/// Reject requests without a valid `Authorization: Bearer` token
+async fn require_bearer(
+    State(validator): State<TokenValidator>,
+    req: axum::extract::Request,
+    next: Next,
+) -> Response {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    match token.map(|t| validator.validate(t)) {
+        Some(Ok(_)) => next.run(req).await,
+        _ => {
+            let e = ServerlessError::Unauthorized;
+            from_serverless_response(ServerlessResponse::error(e.status_code(), &e.to_string()))
+        }
+    }
+}
+
+/// Build the router; `compress` enables gzip/deflate per `Accept-Encoding`
+///
+/// With `auth`, `/api/*` and `/ws/*` require a bearer token; `/health`
+/// stays open. `/ws/stream` serves files under the canonical `data_root`
+/// and is only routed when one is given.
+fn build_router(
+    handler: Arc<dyn ServerlessHandler>,
+    compress: bool,
+    auth: Option<TokenValidator>,
+    #[cfg_attr(not(feature = "ws-stream"), allow(unused_variables))] data_root: Option<PathBuf>,
+) -> Router {
+    let mut api = Router::new()
+        .route("/api/*path", post(handle_request))
+        .route("/api/*path", get(handle_request));
+    #[cfg(feature = "ws-stream")]
+    if let Some(root) = data_root {
+        api = api.merge(
+            Router::new()
+                .route("/ws/stream", get(ws_stream::handler))
+                .with_state(Arc::new(root)),
+        );
+    }
+    if let Some(validator) = auth {
+        api = api.route_layer(middleware::from_fn_with_state(validator, require_bearer));
+    }
+
+    let app = Router::new()
+        .route("/health", get(health_check))
+        .merge(api)
+        .layer(CorsLayer::permissive())
+        .with_state(handler);
+
+    if compress {
+        app.layer(CompressionLayer::new())
+    } else {
+        app
+    }
+}
+
+/// Resolves on SIGTERM (e.g. from Kubernetes) or Ctrl+C
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
+
+/// Serve until `shutdown` resolves, then stop accepting connections and
+/// wait for in-flight requests to finish
+///
+/// Requests carry the peer address, which keys guest rate limits.
+async fn serve(
+    listener: tokio::net::TcpListener,
+    app: Router,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> std::io::Result<()> {
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(async move {
+            shutdown.await;
+            tracing::info!("Shutting down, draining in-flight requests");
+        })
+        .await
+}
+
 #[tokio::main]
 async fn main() {
     // Initialize tracing
@@ -115,16 +354,49 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
+    // Comma-separated CIDRs of reverse proxies whose x-forwarded-for is believed
+    let trusted_proxies = match std::env::var("POLARWAY_TRUSTED_PROXIES") {
+        Ok(list) => list
+            .split(',')
+            .filter(|cidr| !cidr.trim().is_empty())
+            .map(str::parse::<IpCidr>)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|e| {
+                tracing::error!("POLARWAY_TRUSTED_PROXIES: {}", e);
+                std::process::exit(1);
+            }),
+        Err(_) => Vec::new(),
+    };
+
     // Create handler
-    let handler: Arc<dyn ServerlessHandler> = Arc::new(PolarwayHandler::new());
+    let handler = Arc::new(PolarwayHandler::new().with_trusted_proxies(trusted_proxies));
 
-    // Build router
-    let app = Router::new()
-        .route("/health", get(health_check))
-        .route("/api/*path", post(handle_request))
-        .route("/api/*path", get(handle_request))
-        .layer(CorsLayer::permissive())
-        .with_state(handler);
+    // Set POLARWAY_DISABLE_COMPRESSION=1 to see raw bodies when debugging
+    let compress = !matches!(
+        std::env::var("POLARWAY_DISABLE_COMPRESSION").as_deref(),
+        Ok("1") | Ok("true")
+    );
+    // Set POLARWAY_REQUIRE_AUTH=1 to reject /api/* requests without a valid JWT
+    let require_auth = matches!(
+        std::env::var("POLARWAY_REQUIRE_AUTH").as_deref(),
+        Ok("1") | Ok("true")
+    );
+    let auth = match (require_auth, TokenValidator::from_env()) {
+        (false, _) => None,
+        (true, Some(validator)) => Some(validator),
+        (true, None) => {
+            tracing::error!("POLARWAY_REQUIRE_AUTH is set but JWT_SECRET is unset or empty; refusing to start");
+            std::process::exit(1);
+        }
+    };
+    // Directory /ws/stream may read from; the endpoint is off without it
+    let data_root = std::env::var("POLARWAY_DATA_ROOT").ok().map(|root| {
+        std::fs::canonicalize(&root).unwrap_or_else(|e| {
+            tracing::error!("POLARWAY_DATA_ROOT {}: {}", root, e);
+            std::process::exit(1);
+        })
+    });
+    let app = build_router(handler.clone(), compress, auth, data_root);
 
     // Get port from environment (cloud-agnostic)
     // Azure Functions uses FUNCTIONS_CUSTOMHANDLER_PORT, others use PORT
@@ -138,5 +410,277 @@ async fn main() {
     tracing::info!("🚀 Polarway HTTP server listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    serve(listener, app, shutdown_signal()).await.unwrap();
+
+    handler.shutdown();
+    tracing::info!("Server stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    /// Returns ~300 KB of JSON for any request
+    struct LargeJson;
+
+    #[async_trait::async_trait]
+    impl ServerlessHandler for LargeJson {
+        async fn handle_request(
+            &self,
+            _req: ServerlessRequest,
+        ) -> Result<ServerlessResponse, ServerlessError> {
+            let rows: Vec<_> = (0..10_000)
+                .map(|i| serde_json::json!({ "i": i, "symbol": "AAPL", "close": 101.5 }))
+                .collect();
+            Ok(ServerlessResponse::ok(serde_json::to_vec(&rows).unwrap()))
+        }
+    }
+
+    async fn fetch(compress: bool, accept_encoding: Option<&str>) -> Response {
+        let app = build_router(Arc::new(LargeJson), compress, None, None);
+        let mut req = Request::builder().method("POST").uri("/api/stream-data");
+        if let Some(encoding) = accept_encoding {
+            req = req.header(header::ACCEPT_ENCODING, encoding);
+        }
+        app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    async fn body_len(resp: Response) -> usize {
+        axum::body::to_bytes(resp.into_body(), usize::MAX).await.unwrap().len()
+    }
+
+    #[tokio::test]
+    async fn test_gzip_when_requested() {
+        let plain = fetch(true, None).await;
+        assert!(plain.headers().get(header::CONTENT_ENCODING).is_none());
+        let plain_len = body_len(plain).await;
+
+        let gzipped = fetch(true, Some("gzip")).await;
+        assert_eq!(gzipped.headers()[header::CONTENT_ENCODING], "gzip");
+        assert!(body_len(gzipped).await < plain_len / 5);
+    }
+
+    #[tokio::test]
+    async fn test_compression_opt_out() {
+        let resp = fetch(false, Some("gzip, deflate")).await;
+        assert!(resp.headers().get(header::CONTENT_ENCODING).is_none());
+    }
+
+    #[cfg(feature = "ws-stream")]
+    #[tokio::test]
+    async fn test_ws_stream_sends_one_frame_per_batch() {
+        use futures_util::{SinkExt, StreamExt};
+        use polars::prelude::*;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let root = ws_data_root();
+        let mut df = df! { "id" => (0..10i64).collect::<Vec<_>>() }.unwrap();
+        ParquetWriter::new(std::fs::File::create(root.join("prices.parquet")).unwrap())
+            .finish(&mut df)
+            .unwrap();
+
+        let mut socket = ws_connect(&root).await;
+        let request = serde_json::json!({ "path": "prices.parquet", "chunk_rows": 4 });
+        socket.send(Message::Text(request.to_string())).await.unwrap();
+
+        let mut frames = Vec::new();
+        while let Some(message) = socket.next().await {
+            match message.unwrap() {
+                Message::Binary(bytes) => frames.push(bytes),
+                Message::Close(frame) => {
+                    assert!(frame.is_none());
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        // 10 rows in batches of at most 4
+        assert_eq!(frames.len(), 3);
+        assert!(frames.iter().all(|frame| frame.starts_with(b"ARROW1")));
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    /// Fresh canonical directory under the system temp dir
+    #[cfg(feature = "ws-stream")]
+    fn ws_data_root() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("ws_stream_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&root).unwrap();
+        root.canonicalize().unwrap()
+    }
+
+    #[cfg(feature = "ws-stream")]
+    async fn ws_connect(
+        root: &std::path::Path,
+    ) -> tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>> {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = build_router(Arc::new(LargeJson), false, None, Some(root.to_path_buf()));
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let (socket, _) = tokio_tungstenite::connect_async(format!("ws://{}/ws/stream", addr))
+            .await
+            .unwrap();
+        socket
+    }
+
+    #[cfg(feature = "ws-stream")]
+    #[tokio::test]
+    async fn test_ws_stream_rejects_paths_outside_root() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let root = ws_data_root();
+        let outside = root.with_extension("parquet");
+        std::fs::write(&outside, b"not inside the root").unwrap();
+        let escape = format!("../{}", outside.file_name().unwrap().to_str().unwrap());
+
+        for path in [escape.as_str(), outside.to_str().unwrap()] {
+            let mut socket = ws_connect(&root).await;
+            let request = serde_json::json!({ "path": path, "chunk_rows": 4 });
+            socket.send(Message::Text(request.to_string())).await.unwrap();
+
+            match socket.next().await.unwrap().unwrap() {
+                Message::Close(Some(frame)) => {
+                    assert_eq!(frame.code, CloseCode::Error);
+                    assert!(frame.reason.contains("data root"));
+                }
+                other => panic!("expected a close frame, got {:?}", other),
+            }
+        }
+
+        std::fs::remove_file(&outside).ok();
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    /// Holds every request until released
+    struct Blocking {
+        started: tokio::sync::Notify,
+        release: tokio::sync::Notify,
+    }
+
+    #[async_trait::async_trait]
+    impl ServerlessHandler for Blocking {
+        async fn handle_request(
+            &self,
+            _req: ServerlessRequest,
+        ) -> Result<ServerlessResponse, ServerlessError> {
+            self.started.notify_one();
+            self.release.notified().await;
+            Ok(ServerlessResponse::ok(b"{}".to_vec()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_graceful_shutdown_drains_pending_request() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let handler = Arc::new(Blocking {
+            started: tokio::sync::Notify::new(),
+            release: tokio::sync::Notify::new(),
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(serve(
+            listener,
+            build_router(handler.clone(), false, None, None),
+            async {
+                let _ = shutdown_rx.await;
+            },
+        ));
+
+        let pending = tokio::spawn(async move {
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            stream
+                .write_all(b"POST /api/slow HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            let mut response = Vec::new();
+            stream.read_to_end(&mut response).await.unwrap();
+            String::from_utf8(response).unwrap()
+        });
+
+        handler.started.notified().await;
+        shutdown_tx.send(()).unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        assert!(tokio::net::TcpStream::connect(addr).await.is_err());
+        assert!(!server.is_finished());
+
+        handler.release.notify_one();
+        let response = pending.await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"));
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_error_response_carries_request_id() {
+        let app = build_router(Arc::new(PolarwayHandler::new()), false, None, None);
+        let req = Request::builder()
+            .method("POST")
+            .uri("/api/no-such-endpoint")
+            .header(REQUEST_ID_HEADER, "req-404")
+            .body(Body::empty())
+            .unwrap();
+
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(resp.headers()[REQUEST_ID_HEADER], "req-404");
+    }
+
+    #[cfg(feature = "auth")]
+    #[tokio::test]
+    async fn test_require_auth() {
+        let secret = "test-secret";
+        let app = build_router(Arc::new(LargeJson), false, Some(TokenValidator::new(secret)), None);
+        let call = |req: Request<Body>| app.clone().oneshot(req);
+
+        let health = Request::builder().uri("/health").body(Body::empty()).unwrap();
+        assert_eq!(call(health).await.unwrap().status(), StatusCode::OK);
+
+        let anonymous = Request::builder().method("POST").uri("/api/stream-data").body(Body::empty()).unwrap();
+        assert_eq!(call(anonymous).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+
+        let forged = Request::builder()
+            .method("POST")
+            .uri("/api/stream-data")
+            .header(header::AUTHORIZATION, "Bearer not-a-jwt")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(call(forged).await.unwrap().status(), StatusCode::UNAUTHORIZED);
+
+        let claims = serde_json::json!({
+            "sub": "user-1",
+            "tier": "professional",
+            "exp": std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                + 3600,
+        });
+        let token = jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(secret.as_ref()),
+        )
+        .unwrap();
+        let authorized = Request::builder()
+            .method("POST")
+            .uri("/api/stream-data")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(call(authorized).await.unwrap().status(), StatusCode::OK);
+    }
 }
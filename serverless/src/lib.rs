@@ -3,12 +3,14 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::sync::Arc;
 use thiserror::Error;
 use polars::prelude::*;
 use dashmap::DashMap;
 use uuid::Uuid;
 use std::time::Instant;
+use tracing::Instrument;
 
 #[cfg(feature = "auth")]
 use jsonwebtoken::{decode, DecodingKey, Validation, Algorithm};
@@ -32,6 +34,19 @@ pub enum ServerlessError {
     Polars(#[from] polars::error::PolarsError),
 }
 
+impl ServerlessError {
+    /// HTTP status code for this error
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ServerlessError::NotFound => 404,
+            ServerlessError::BadRequest(_) => 400,
+            ServerlessError::Unauthorized => 401,
+            ServerlessError::RateLimitExceeded => 429,
+            ServerlessError::Internal(_) | ServerlessError::Polars(_) => 500,
+        }
+    }
+}
+
 /// User tier for authentication and rate limiting
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UserTier {
@@ -42,6 +57,16 @@ pub enum UserTier {
 }
 
 impl UserTier {
+    /// Lowercase name, as used in JWT claims and metric labels
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserTier::Guest => "guest",
+            UserTier::Hobbyist => "hobbyist",
+            UserTier::Professional => "professional",
+            UserTier::Enterprise => "enterprise",
+        }
+    }
+
     pub fn rate_limit(&self) -> u64 {
         match self {
             UserTier::Guest => 5,
@@ -52,6 +77,115 @@ impl UserTier {
     }
 }
 
+/// Token-bucket rate limiter keyed by caller
+///
+/// Each key gets a bucket of `tier.rate_limit()` tokens that refills
+/// continuously over `window`.
+pub struct RateLimiter {
+    buckets: DashMap<String, TokenBucket>,
+    window: std::time::Duration,
+}
+
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(window: std::time::Duration) -> Self {
+        Self {
+            buckets: DashMap::new(),
+            window,
+        }
+    }
+
+    /// Take one token for `key`, or fail with `RateLimitExceeded`
+    pub fn check(&self, key: &str, tier: UserTier) -> Result<(), ServerlessError> {
+        let limit = tier.rate_limit();
+        if limit == u64::MAX {
+            return Ok(());
+        }
+        let capacity = limit as f64;
+
+        let mut bucket = self.buckets.entry(key.to_string()).or_insert_with(|| TokenBucket {
+            tokens: capacity,
+            last_refill: Instant::now(),
+        });
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * capacity / self.window.as_secs_f64()).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens < 1.0 {
+            return Err(ServerlessError::RateLimitExceeded);
+        }
+        bucket.tokens -= 1.0;
+        Ok(())
+    }
+
+    /// Drop buckets idle for a full window
+    ///
+    /// Such a bucket has refilled to capacity, so a fresh one is equivalent.
+    pub fn evict_idle(&self) {
+        let now = Instant::now();
+        self.buckets.retain(|_, bucket| now.duration_since(bucket.last_refill) < self.window);
+    }
+}
+
+impl Default for RateLimiter {
+    /// Limits are per minute
+    fn default() -> Self {
+        Self::new(std::time::Duration::from_secs(60))
+    }
+}
+
+/// An IP network in CIDR notation, e.g. `10.0.0.0/8` or `fd00::/8`
+///
+/// A bare address is a single-host network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpCidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpCidr {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let (net, ip, bits) = match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => (u32::from(net) as u128, u32::from(ip) as u128, 32),
+            (IpAddr::V6(net), IpAddr::V6(ip)) => (u128::from(net), u128::from(ip), 128),
+            _ => return false,
+        };
+        (net ^ ip).checked_shr(bits - self.prefix as u32).unwrap_or(0) == 0
+    }
+}
+
+impl std::str::FromStr for IpCidr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (addr, prefix) = match s.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s, None),
+        };
+        let addr = addr
+            .parse::<IpAddr>()
+            .map_err(|_| format!("Invalid CIDR: {}", s))?
+            .to_canonical();
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix
+                .parse::<u8>()
+                .ok()
+                .filter(|p| *p <= max)
+                .ok_or_else(|| format!("Invalid CIDR prefix: {}", s))?,
+            None => max,
+        };
+        Ok(Self { addr, prefix })
+    }
+}
+
 /// JWT claims structure
 #[derive(Debug, Serialize, Deserialize)]
 struct Claims {
@@ -60,6 +194,57 @@ struct Claims {
     exp: usize,
 }
 
+/// Verifies HS256 bearer tokens and extracts the caller's tier
+///
+/// Without the `auth` feature every token is rejected.
+#[derive(Clone)]
+pub struct TokenValidator {
+    #[cfg_attr(not(feature = "auth"), allow(dead_code))]
+    secret: String,
+}
+
+impl TokenValidator {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self { secret: secret.into() }
+    }
+
+    /// Secret from `JWT_SECRET`, or `None` when it is unset or empty
+    ///
+    /// Unit tests fall back to a fixed development secret.
+    pub fn from_env() -> Option<Self> {
+        let secret = std::env::var("JWT_SECRET").ok().filter(|s| !s.is_empty());
+        #[cfg(test)]
+        let secret = secret.or_else(|| Some("dev-secret-change-in-production".to_string()));
+        secret.map(Self::new)
+    }
+
+    /// Subject and tier of a valid token, or `Unauthorized`
+    #[cfg(feature = "auth")]
+    pub fn validate(&self, token: &str) -> Result<(String, UserTier), ServerlessError> {
+        let validation = Validation::new(Algorithm::HS256);
+        let token_data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_ref()),
+            &validation,
+        ).map_err(|_| ServerlessError::Unauthorized)?;
+        
+        let tier = match token_data.claims.tier.as_str() {
+            "guest" => UserTier::Guest,
+            "hobbyist" => UserTier::Hobbyist,
+            "professional" => UserTier::Professional,
+            "enterprise" => UserTier::Enterprise,
+            _ => UserTier::Guest,
+        };
+        
+        Ok((token_data.claims.sub, tier))
+    }
+
+    #[cfg(not(feature = "auth"))]
+    pub fn validate(&self, _token: &str) -> Result<(String, UserTier), ServerlessError> {
+        Err(ServerlessError::Unauthorized)
+    }
+}
+
 /// Metrics collector
 #[cfg(feature = "metrics")]
 pub struct Metrics {
@@ -176,6 +361,9 @@ impl Default for HandleManager {
     }
 }
 
+/// Header correlating a request with its log lines and response
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
 /// Cloud-agnostic HTTP request
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerlessRequest {
@@ -184,6 +372,9 @@ pub struct ServerlessRequest {
     pub headers: HashMap<String, String>,
     pub body: Vec<u8>,
     pub query_params: HashMap<String, String>,
+    /// Address of the connecting peer, if the platform provides one
+    #[serde(default)]
+    pub remote_addr: Option<IpAddr>,
 }
 
 /// Cloud-agnostic HTTP response
@@ -194,6 +385,18 @@ pub struct ServerlessResponse {
     pub body: Vec<u8>,
 }
 
+impl ServerlessRequest {
+    /// Caller-supplied `x-request-id`, or a fresh UUID
+    pub fn request_id(&self) -> String {
+        self.headers
+            .get(REQUEST_ID_HEADER)
+            .map(|id| id.trim())
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| Uuid::new_v4().to_string())
+    }
+}
+
 impl ServerlessResponse {
     pub fn ok(body: Vec<u8>) -> Self {
         Self {
@@ -225,73 +428,88 @@ pub trait ServerlessHandler: Send + Sync {
 /// Polarway-specific handler implementation with real DataFrame operations
 pub struct PolarwayHandler {
     handle_manager: Arc<HandleManager>,
+    rate_limiter: Arc<RateLimiter>,
+    trusted_proxies: Vec<IpCidr>,
     #[cfg(feature = "metrics")]
     metrics: Arc<Metrics>,
-    #[cfg(feature = "auth")]
-    jwt_secret: String,
+    /// `None` without `JWT_SECRET`; every caller is then a guest
+    token_validator: Option<TokenValidator>,
+    shutdown: tokio::sync::watch::Sender<bool>,
 }
 
 impl PolarwayHandler {
     pub fn new() -> Self {
         let handle_manager = Arc::new(HandleManager::default());
         
-        // Spawn cleanup task for expired handles
+        let rate_limiter = Arc::new(RateLimiter::default());
+
+        // Spawn cleanup task for expired handles and idle rate-limit
+        // buckets; it stops on `shutdown` or when the handler is dropped
+        let (shutdown, mut stop) = tokio::sync::watch::channel(false);
         let manager_clone = Arc::clone(&handle_manager);
+        let limiter_clone = Arc::clone(&rate_limiter);
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(std::time::Duration::from_secs(300));
             loop {
-                interval.tick().await;
-                manager_clone.cleanup_expired();
+                tokio::select! {
+                    _ = interval.tick() => {
+                        manager_clone.cleanup_expired();
+                        limiter_clone.evict_idle();
+                    }
+                    _ = stop.changed() => break,
+                }
             }
+            tracing::debug!("Handle cleanup task stopped");
         });
         
         Self {
             handle_manager,
+            rate_limiter,
+            trusted_proxies: Vec::new(),
             #[cfg(feature = "metrics")]
             metrics: Arc::new(Metrics::new()),
-            #[cfg(feature = "auth")]
-            jwt_secret: std::env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-change-in-production".to_string()),
+            token_validator: TokenValidator::from_env(),
+            shutdown,
         }
     }
-    
-    #[cfg(feature = "auth")]
-    fn validate_token(&self, token: &str) -> Result<UserTier, ServerlessError> {
-        let validation = Validation::new(Algorithm::HS256);
-        let token_data = decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(self.jwt_secret.as_ref()),
-            &validation,
-        ).map_err(|_| ServerlessError::Unauthorized)?;
-        
-        let tier = match token_data.claims.tier.as_str() {
-            "guest" => UserTier::Guest,
-            "hobbyist" => UserTier::Hobbyist,
-            "professional" => UserTier::Professional,
-            "enterprise" => UserTier::Enterprise,
-            _ => UserTier::Guest,
-        };
-        
-        Ok(tier)
+
+    /// Proxies whose `x-forwarded-for` / `x-real-ip` headers are believed
+    ///
+    /// Without any, guests are keyed by the peer address alone.
+    pub fn with_trusted_proxies(mut self, proxies: Vec<IpCidr>) -> Self {
+        self.trusted_proxies = proxies;
+        self
     }
-    
-    #[cfg(not(feature = "auth"))]
-    fn validate_token(&self, _token: &str) -> Result<UserTier, ServerlessError> {
-        Ok(UserTier::Guest)
+
+    /// Stop background tasks; request handling keeps working
+    pub fn shutdown(&self) {
+        let _ = self.shutdown.send(true);
     }
     
-    fn extract_tier(&self, req: &ServerlessRequest) -> UserTier {
-        if let Some(auth_header) = req.headers.get("authorization") {
+    /// Rate-limit key and tier of the caller
+    ///
+    /// Authenticated callers are keyed by JWT subject; everyone else is a
+    /// guest keyed by client IP. Guests with no known address are rejected
+    /// rather than sharing one bucket.
+    fn extract_identity(&self, req: &ServerlessRequest) -> Result<(String, UserTier), ServerlessError> {
+        if let (Some(validator), Some(auth_header)) = (&self.token_validator, req.headers.get("authorization")) {
             if let Some(token) = auth_header.strip_prefix("Bearer ") {
-                return self.validate_token(token).unwrap_or(UserTier::Guest);
+                if let Ok((sub, tier)) = validator.validate(token) {
+                    return Ok((format!("user:{}", sub), tier));
+                }
             }
         }
-        UserTier::Guest
+        let ip = client_ip(req, &self.trusted_proxies).ok_or(ServerlessError::Unauthorized)?;
+        Ok((format!("ip:{}", ip), UserTier::Guest))
     }
 
     /// Real DataFrame pair discovery using correlation analysis
-    async fn discover_pairs(&self, req: ServerlessRequest) -> Result<ServerlessResponse, ServerlessError> {
+    ///
+    /// Price data comes from a `handle` (e.g. from `/api/fetch-rest`) or a
+    /// parquet `path`, with one close-price column per symbol.
+    async fn discover_pairs(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
         #[cfg(feature = "metrics")]
-        let timer = self.metrics.request_duration.with_label_values(&["discover_pairs", "unknown"]).start_timer();
+        let timer = self.metrics.request_duration.with_label_values(&["discover_pairs", tier.as_str()]).start_timer();
         
         // Parse request body
         #[derive(Deserialize)]
@@ -301,6 +519,10 @@ impl PolarwayHandler {
             method: String, // "pearson" or "spearman"
             #[serde(default = "default_min_correlation")]
             min_correlation: f64,
+            #[serde(default)]
+            handle: Option<String>,
+            #[serde(default)]
+            path: Option<String>,
         }
         
         fn default_min_correlation() -> f64 { 0.7 }
@@ -312,28 +534,35 @@ impl PolarwayHandler {
             return Err(ServerlessError::BadRequest("Need at least 2 symbols".to_string()));
         }
 
-        // For now, generate correlation matrix using random data
-        // In production, this would fetch real market data and compute correlations
-        let num_symbols = params.symbols.len();
-        let mut correlations = Vec::new();
-        
-        // Generate sample correlation matrix (replace with real data in production)
-        for i in 0..num_symbols {
-            for j in (i+1)..num_symbols {
-                // Mock correlation (in production: compute from real price data)
-                let correlation = 0.5 + (i as f64 * 0.1 + j as f64 * 0.05).min(0.45);
-                
-                if correlation >= params.min_correlation {
-                    correlations.push(serde_json::json!({
-                        "symbol1": params.symbols[i],
-                        "symbol2": params.symbols[j],
-                        "correlation": (correlation * 100.0).round() / 100.0,
-                        "method": if params.method.is_empty() { "pearson" } else { &params.method }
-                    }));
-                }
+        let method = match params.method.as_str() {
+            "" | "pearson" => "pearson",
+            "spearman" => "spearman",
+            other => return Err(ServerlessError::BadRequest(format!("Unsupported method: {}", other))),
+        };
+
+        let df = self.load_prices(params.handle.as_deref(), params.path.clone()).await?;
+        for symbol in &params.symbols {
+            if df.column(symbol).is_err() {
+                return Err(ServerlessError::BadRequest(format!("No price column for symbol: {}", symbol)));
             }
         }
 
+        let symbols = params.symbols.clone();
+        let matrix = tokio::task::spawn_blocking(move || correlation_pairs(&df, &symbols, method))
+            .await
+            .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))??;
+
+        let correlations: Vec<_> = matrix
+            .into_iter()
+            .filter(|(_, _, correlation)| *correlation >= params.min_correlation)
+            .map(|(i, j, correlation)| serde_json::json!({
+                "symbol1": params.symbols[i],
+                "symbol2": params.symbols[j],
+                "correlation": (correlation * 100.0).round() / 100.0,
+                "method": method
+            }))
+            .collect();
+
         let response = serde_json::json!({
             "pairs": correlations,
             "timestamp": chrono::Utc::now().to_rfc3339(),
@@ -348,10 +577,27 @@ impl PolarwayHandler {
         ))
     }
 
+    /// Resolve price data from a handle or a parquet path
+    async fn load_prices(&self, handle: Option<&str>, path: Option<String>) -> Result<DataFrame, ServerlessError> {
+        if let Some(handle) = handle {
+            return Ok(self.handle_manager.get_dataframe(handle)?.as_ref().clone());
+        }
+        let path = path.ok_or_else(|| {
+            ServerlessError::BadRequest("Provide a `handle` or parquet `path` with price data".to_string())
+        })?;
+
+        tokio::task::spawn_blocking(move || {
+            LazyFrame::scan_parquet(&path, Default::default())?.collect()
+        })
+        .await
+        .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))?
+        .map_err(ServerlessError::Polars)
+    }
+
     /// Real DataFrame streaming using Polars scan_parquet
-    async fn stream_data(&self, req: ServerlessRequest) -> Result<ServerlessResponse, ServerlessError> {
+    async fn stream_data(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
         #[cfg(feature = "metrics")]
-        let timer = self.metrics.request_duration.with_label_values(&["stream_data", "unknown"]).start_timer();
+        let timer = self.metrics.request_duration.with_label_values(&["stream_data", tier.as_str()]).start_timer();
         
         // Parse request
         #[derive(Deserialize)]
@@ -411,7 +657,8 @@ impl PolarwayHandler {
         let response = serde_json::json!({
             "rows": df.height(),
             "columns": df.width(),
-            "data": serde_json::from_slice::<serde_json::Value>(&json_data).unwrap(),
+            "data": serde_json::from_slice::<serde_json::Value>(&json_data)
+                .map_err(|e| ServerlessError::Internal(format!("Invalid JSON output: {}", e)))?,
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
@@ -423,11 +670,216 @@ impl PolarwayHandler {
         ))
     }
 
+    /// Run SQL against a stored handle, registered as table `df`
+    async fn query(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
+        #[cfg(feature = "metrics")]
+        let timer = self.metrics.request_duration.with_label_values(&["query", tier.as_str()]).start_timer();
+
+        #[derive(Deserialize)]
+        struct QueryRequest {
+            handle: String,
+            sql: String,
+        }
+
+        let params: QueryRequest = serde_json::from_slice(&req.body)
+            .map_err(|e| ServerlessError::BadRequest(e.to_string()))?;
+
+        let df = self.handle_manager.get_dataframe(&params.handle)?;
+
+        let result = tokio::task::spawn_blocking(move || -> Result<DataFrame, ServerlessError> {
+            let mut ctx = polars::sql::SQLContext::new();
+            ctx.register("df", df.as_ref().clone().lazy());
+            let lazy_df = ctx
+                .execute(&params.sql)
+                .map_err(|e| ServerlessError::BadRequest(format!("Invalid query: {}", e)))?;
+            lazy_df.collect().map_err(ServerlessError::Polars)
+        })
+        .await
+        .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))??;
+
+        let mut json_data = Vec::new();
+        polars::io::json::JsonWriter::new(&mut json_data)
+            .with_json_format(polars::io::json::JsonFormat::Json)
+            .finish(&mut result.clone())
+            .map_err(ServerlessError::Polars)?;
+
+        let response = serde_json::json!({
+            "rows": result.height(),
+            "columns": result.width(),
+            "schema": result.get_column_names(),
+            "data": serde_json::from_slice::<serde_json::Value>(&json_data)
+                .map_err(|e| ServerlessError::Internal(format!("Invalid JSON output: {}", e)))?,
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        });
+
+        #[cfg(feature = "metrics")]
+        timer.observe_duration();
+
+        Ok(ServerlessResponse::ok(
+            serde_json::to_vec(&response).unwrap(),
+        ))
+    }
+
+    /// Join two stored handles and store the result as a new handle
+    async fn join(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
+        #[cfg(feature = "metrics")]
+        let timer = self.metrics.request_duration.with_label_values(&["join", tier.as_str()]).start_timer();
+
+        #[derive(Deserialize)]
+        struct JoinRequest {
+            left_handle: String,
+            right_handle: String,
+            on: Vec<String>,
+            #[serde(default = "default_how")]
+            how: String,
+        }
+
+        fn default_how() -> String { "inner".to_string() }
+
+        let params: JoinRequest = serde_json::from_slice(&req.body)
+            .map_err(|e| ServerlessError::BadRequest(e.to_string()))?;
+
+        if params.on.is_empty() {
+            return Err(ServerlessError::BadRequest("`on` must name at least one join key".to_string()));
+        }
+        if !matches!(params.how.as_str(), "inner" | "left" | "outer") {
+            return Err(ServerlessError::BadRequest(format!(
+                "Unsupported join type: {} (expected inner, left or outer)",
+                params.how
+            )));
+        }
+
+        let left = self.handle_manager.get_dataframe(&params.left_handle)?;
+        let right = self.handle_manager.get_dataframe(&params.right_handle)?;
+
+        for (side, df) in [("left", &left), ("right", &right)] {
+            if let Some(key) = params.on.iter().find(|key| df.column(key).is_err()) {
+                return Err(ServerlessError::BadRequest(format!(
+                    "Join key '{}' not found in {} frame",
+                    key, side
+                )));
+            }
+        }
+
+        let joined = tokio::task::spawn_blocking(move || {
+            let on = params.on.as_slice();
+            match params.how.as_str() {
+                "left" => left.left_join(&right, on, on),
+                "outer" => left.outer_join(&right, on, on),
+                _ => left.inner_join(&right, on, on),
+            }
+        })
+        .await
+        .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))?
+        .map_err(ServerlessError::Polars)?;
+
+        let response = serde_json::json!({
+            "handle": self.handle_manager.create_handle(joined.clone()),
+            "rows": joined.height(),
+            "columns": joined.width(),
+            "schema": joined.get_column_names(),
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        });
+
+        #[cfg(feature = "metrics")]
+        timer.observe_duration();
+
+        Ok(ServerlessResponse::ok(
+            serde_json::to_vec(&response).unwrap(),
+        ))
+    }
+
+    /// Group a stored handle and store the aggregates as a new handle
+    ///
+    /// Each aggregation produces a `{col}_{op}` column; groups keep the
+    /// order in which they first appear.
+    async fn group_by(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
+        #[cfg(feature = "metrics")]
+        let timer = self.metrics.request_duration.with_label_values(&["groupby", tier.as_str()]).start_timer();
+
+        #[derive(Deserialize, Clone, Copy)]
+        #[serde(rename_all = "lowercase")]
+        enum AggOp {
+            Sum,
+            Mean,
+            Min,
+            Max,
+            Count,
+        }
+
+        #[derive(Deserialize)]
+        struct AggSpec {
+            col: String,
+            op: AggOp,
+        }
+
+        #[derive(Deserialize)]
+        struct GroupByRequest {
+            handle: String,
+            by: Vec<String>,
+            aggs: Vec<AggSpec>,
+        }
+
+        let params: GroupByRequest = serde_json::from_slice(&req.body)
+            .map_err(|e| ServerlessError::BadRequest(e.to_string()))?;
+
+        if params.by.is_empty() || params.aggs.is_empty() {
+            return Err(ServerlessError::BadRequest("`by` and `aggs` must not be empty".to_string()));
+        }
+
+        let df = self.handle_manager.get_dataframe(&params.handle)?;
+
+        let columns = params.by.iter().chain(params.aggs.iter().map(|agg| &agg.col));
+        for name in columns {
+            if df.column(name).is_err() {
+                return Err(ServerlessError::BadRequest(format!("Column '{}' not found", name)));
+            }
+        }
+
+        let keys: Vec<Expr> = params.by.iter().map(|c| col(c)).collect();
+        let aggs: Vec<Expr> = params
+            .aggs
+            .iter()
+            .map(|agg| {
+                let (expr, suffix) = match agg.op {
+                    AggOp::Sum => (col(&agg.col).sum(), "sum"),
+                    AggOp::Mean => (col(&agg.col).mean(), "mean"),
+                    AggOp::Min => (col(&agg.col).min(), "min"),
+                    AggOp::Max => (col(&agg.col).max(), "max"),
+                    AggOp::Count => (col(&agg.col).count(), "count"),
+                };
+                expr.alias(&format!("{}_{}", agg.col, suffix))
+            })
+            .collect();
+
+        let grouped = tokio::task::spawn_blocking(move || {
+            df.as_ref().clone().lazy().group_by_stable(keys).agg(aggs).collect()
+        })
+        .await
+        .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))?
+        .map_err(ServerlessError::Polars)?;
+
+        let response = serde_json::json!({
+            "handle": self.handle_manager.create_handle(grouped.clone()),
+            "rows": grouped.height(),
+            "columns": grouped.width(),
+            "schema": grouped.get_column_names(),
+            "timestamp": chrono::Utc::now().to_rfc3339()
+        });
+
+        #[cfg(feature = "metrics")]
+        timer.observe_duration();
+
+        Ok(ServerlessResponse::ok(
+            serde_json::to_vec(&response).unwrap(),
+        ))
+    }
+
     /// Fetch data from REST API and return DataFrame
     #[cfg(feature = "rest-api")]
-    async fn fetch_rest(&self, req: ServerlessRequest) -> Result<ServerlessResponse, ServerlessError> {
+    async fn fetch_rest(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
         #[cfg(feature = "metrics")]
-        let timer = self.metrics.request_duration.with_label_values(&["fetch_rest", "unknown"]).start_timer();
+        let timer = self.metrics.request_duration.with_label_values(&["fetch_rest", tier.as_str()]).start_timer();
         
         #[derive(Deserialize)]
         struct FetchRequest {
@@ -515,9 +967,14 @@ impl PolarwayHandler {
     }
 
     /// Backtest strategy on historical data
-    async fn backtest(&self, req: ServerlessRequest) -> Result<ServerlessResponse, ServerlessError> {
+    ///
+    /// Prices come from a `handle` or parquet `path`; the price column is
+    /// `price_column`, else the column named after the symbol, else `close`.
+    /// Only rows whose `date_column` falls in `start_date..=end_date` are
+    /// used, in their stored order; a missing price among them is an error.
+    async fn backtest(&self, req: ServerlessRequest, tier: UserTier) -> Result<ServerlessResponse, ServerlessError> {
         #[cfg(feature = "metrics")]
-        let timer = self.metrics.request_duration.with_label_values(&["backtest", "unknown"]).start_timer();
+        let timer = self.metrics.request_duration.with_label_values(&["backtest", tier.as_str()]).start_timer();
         
         // Parse request
         #[derive(Deserialize)]
@@ -526,22 +983,91 @@ impl PolarwayHandler {
             start_date: String,
             end_date: String,
             #[serde(default)]
-            strategy: String,
+            strategy: String, // "momentum" or "sma_crossover"
+            #[serde(default)]
+            handle: Option<String>,
+            #[serde(default)]
+            path: Option<String>,
+            #[serde(default)]
+            price_column: Option<String>,
+            #[serde(default = "default_date_column")]
+            date_column: String,
+            #[serde(default = "default_fast_window")]
+            fast_window: usize,
+            #[serde(default = "default_slow_window")]
+            slow_window: usize,
         }
         
+        fn default_date_column() -> String { "date".to_string() }
+        fn default_fast_window() -> usize { 5 }
+        fn default_slow_window() -> usize { 20 }
+        
         let params: BacktestRequest = serde_json::from_slice(&req.body)
             .map_err(|e| ServerlessError::BadRequest(e.to_string()))?;
 
-        // TODO: Implement real backtesting logic with DataFrame operations
-        // For now, return mock results
+        let strategy = match params.strategy.as_str() {
+            "" | "momentum" => Strategy::Momentum { window: params.slow_window },
+            "sma_crossover" => Strategy::SmaCrossover { fast: params.fast_window, slow: params.slow_window },
+            other => return Err(ServerlessError::BadRequest(format!("Unsupported strategy: {}", other))),
+        };
+        if params.fast_window == 0 || params.slow_window == 0 || params.fast_window >= params.slow_window {
+            return Err(ServerlessError::BadRequest("Need 0 < fast_window < slow_window".to_string()));
+        }
+        let parse_date = |date: &str| {
+            chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| ServerlessError::BadRequest(format!("Invalid date (expected YYYY-MM-DD): {}", date)))
+        };
+        let start = parse_date(&params.start_date)?;
+        let end = parse_date(&params.end_date)?;
+        if start > end {
+            return Err(ServerlessError::BadRequest("start_date is after end_date".to_string()));
+        }
+
+        let df = self.load_prices(params.handle.as_deref(), params.path.clone()).await?;
+        let price_column = params.price_column.clone().unwrap_or_else(|| {
+            if df.column(&params.symbol).is_ok() { params.symbol.clone() } else { "close".to_string() }
+        });
+
+        if df.column(&price_column).is_err() {
+            return Err(ServerlessError::BadRequest(format!("No price column: {}", price_column)));
+        }
+        if df.column(&params.date_column).is_err() {
+            return Err(ServerlessError::BadRequest(format!("No date column: {}", params.date_column)));
+        }
+
+        let date_column = params.date_column.clone();
+        let (bars, stats) = tokio::task::spawn_blocking(move || -> Result<(usize, BacktestStats), ServerlessError> {
+            let date = col(&date_column).cast(DataType::Date);
+            let period = df
+                .lazy()
+                .filter(date.clone().gt_eq(lit(start)).and(date.lt_eq(lit(end))))
+                .select([col(&price_column).cast(DataType::Float64)])
+                .collect()?;
+
+            // Dropping nulls would splice non-adjacent bars into one return
+            let prices = period.column(&price_column)?.f64()?;
+            if prices.null_count() > 0 {
+                return Err(ServerlessError::BadRequest(format!(
+                    "{} missing prices in {} for the selected period",
+                    prices.null_count(),
+                    price_column
+                )));
+            }
+            let prices: Vec<f64> = prices.into_no_null_iter().collect();
+            Ok((prices.len(), run_backtest(&prices, strategy)))
+        })
+        .await
+        .map_err(|e| ServerlessError::Internal(format!("Task join error: {}", e)))??;
+
         let response = serde_json::json!({
             "results": {
                 "symbol": params.symbol,
                 "period": format!("{} to {}", params.start_date, params.end_date),
-                "total_return": 0.15,
-                "sharpe_ratio": 1.8,
-                "max_drawdown": -0.08,
-                "num_trades": 42,
+                "bars": bars,
+                "total_return": stats.total_return,
+                "sharpe_ratio": stats.sharpe_ratio,
+                "max_drawdown": stats.max_drawdown,
+                "num_trades": stats.num_trades,
                 "strategy": if params.strategy.is_empty() { "momentum" } else { params.strategy.as_str() }
             },
             "timestamp": chrono::Utc::now().to_rfc3339()
@@ -579,25 +1105,184 @@ impl PolarwayHandler {
     }
 }
 
-#[async_trait::async_trait]
-impl ServerlessHandler for PolarwayHandler {
-    async fn handle_request(
-        &self,
-        req: ServerlessRequest,
-    ) -> Result<ServerlessResponse, ServerlessError> {
+/// Client IP for rate limiting
+///
+/// The peer address, unless the peer is a trusted proxy: then the right-most
+/// `x-forwarded-for` hop that is not itself trusted, else `x-real-ip`.
+/// Headers from any other peer are ignored, since clients can set them.
+fn client_ip(req: &ServerlessRequest, trusted_proxies: &[IpCidr]) -> Option<IpAddr> {
+    let trusted = |ip: IpAddr| trusted_proxies.iter().any(|net| net.contains(ip));
+    let peer = req.remote_addr?;
+    if !trusted(peer) {
+        return Some(peer);
+    }
+
+    if let Some(forwarded) = req.headers.get("x-forwarded-for") {
+        for hop in forwarded.rsplit(',') {
+            match hop.trim().parse::<IpAddr>() {
+                Ok(ip) if trusted(ip) => continue,
+                Ok(ip) => return Some(ip),
+                // Hops left of a garbled one can't be attributed
+                Err(_) => break,
+            }
+        }
+        return Some(peer);
+    }
+    req.headers
+        .get("x-real-ip")
+        .and_then(|ip| ip.trim().parse().ok())
+        .or(Some(peer))
+}
+
+/// Long-only signal rules for `/api/backtest`
+#[derive(Debug, Clone, Copy)]
+enum Strategy {
+    /// Long while the price is above its `window`-bar SMA
+    Momentum { window: usize },
+    /// Long while the `fast` SMA is above the `slow` SMA
+    SmaCrossover { fast: usize, slow: usize },
+}
+
+/// Backtest metrics over a price series
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BacktestStats {
+    total_return: f64,
+    /// Annualized over 252 bars
+    sharpe_ratio: f64,
+    /// Worst peak-to-trough equity drop, as a fraction ≤ 0
+    max_drawdown: f64,
+    /// Number of position changes (entries and exits)
+    num_trades: usize,
+}
+
+/// Simple moving average ending at each bar, `None` during warm-up
+fn sma(prices: &[f64], window: usize) -> Vec<Option<f64>> {
+    let mut out = vec![None; prices.len()];
+    let mut sum = 0.0;
+    for (i, price) in prices.iter().enumerate() {
+        sum += price;
+        if i >= window {
+            sum -= prices[i - window];
+        }
+        if i + 1 >= window {
+            out[i] = Some(sum / window as f64);
+        }
+    }
+    out
+}
+
+/// Run `strategy` over `prices`, trading on the bar after each signal
+fn run_backtest(prices: &[f64], strategy: Strategy) -> BacktestStats {
+    let signal: Vec<bool> = match strategy {
+        Strategy::Momentum { window } => sma(prices, window)
+            .iter()
+            .zip(prices)
+            .map(|(avg, price)| avg.is_some_and(|avg| *price > avg))
+            .collect(),
+        Strategy::SmaCrossover { fast, slow } => sma(prices, fast)
+            .iter()
+            .zip(sma(prices, slow))
+            .map(|(f, s)| matches!((f, s), (Some(f), Some(s)) if *f > s))
+            .collect(),
+    };
+
+    let mut equity = 1.0;
+    let mut peak = 1.0;
+    let mut max_drawdown: f64 = 0.0;
+    let mut num_trades = 0;
+    let mut position = false;
+    let mut returns = Vec::with_capacity(prices.len());
+
+    for i in 1..prices.len() {
+        // Position held over bar i was decided at the close of bar i-1
+        let held = signal[i - 1];
+        if held != position {
+            num_trades += 1;
+            position = held;
+        }
+        let bar_return = if held && prices[i - 1] != 0.0 { prices[i] / prices[i - 1] - 1.0 } else { 0.0 };
+        returns.push(bar_return);
+
+        equity *= 1.0 + bar_return;
+        peak = f64::max(peak, equity);
+        max_drawdown = max_drawdown.min(equity / peak - 1.0);
+    }
+
+    let sharpe_ratio = if returns.len() > 1 {
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let var = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        if var > 0.0 { mean / var.sqrt() * 252f64.sqrt() } else { 0.0 }
+    } else {
+        0.0
+    };
+
+    BacktestStats { total_return: equity - 1.0, sharpe_ratio, max_drawdown, num_trades }
+}
+
+/// Pairwise correlation of the `symbols` columns of `df`
+///
+/// Returns `(i, j, correlation)` for every `i < j` with a defined value;
+/// rows with a null in any symbol column are dropped first. Spearman is
+/// Pearson over average ranks.
+fn correlation_pairs(
+    df: &DataFrame,
+    symbols: &[String],
+    method: &str,
+) -> Result<Vec<(usize, usize, f64)>, ServerlessError> {
+    let prices = |symbol: &str| {
+        let price = col(symbol).cast(DataType::Float64);
+        if method == "spearman" {
+            price.rank(RankOptions { method: RankMethod::Average, descending: false }, None)
+        } else {
+            price
+        }
+    };
+
+    let mut exprs = Vec::new();
+    let mut pairs = Vec::new();
+    for i in 0..symbols.len() {
+        for j in (i + 1)..symbols.len() {
+            exprs.push(pearson_corr(prices(&symbols[i]), prices(&symbols[j]), 1).alias(&format!("{}_{}", i, j)));
+            pairs.push((i, j));
+        }
+    }
+
+    let subset: Vec<Expr> = symbols.iter().map(|s| col(s)).collect();
+    let out = df.clone().lazy().drop_nulls(Some(subset)).select(exprs).collect()?;
+
+    let mut result = Vec::new();
+    for (i, j) in pairs {
+        let value = out.column(&format!("{}_{}", i, j))?.cast(&DataType::Float64)?.f64()?.get(0);
+        // Constant or too-short series have no correlation
+        if let Some(correlation) = value.filter(|c| c.is_finite()) {
+            result.push((i, j, correlation));
+        }
+    }
+    Ok(result)
+}
+
+impl PolarwayHandler {
+    /// Route a request to its endpoint
+    async fn dispatch(&self, req: ServerlessRequest) -> Result<ServerlessResponse, ServerlessError> {
         #[cfg(feature = "metrics")]
         self.metrics.request_count.inc();
         
-        let tier = self.extract_tier(&req);
+        let (caller, tier) = self.extract_identity(&req)?;
         tracing::info!("Handling request: {} {} (tier: {:?})", req.method, req.path, tier);
 
+        self.rate_limiter.check(&caller, tier)?;
+
         match req.path.as_str() {
             "/health" | "/api/health" => self.health_check().await,
-            "/api/discover-pairs" => self.discover_pairs(req).await,
-            "/api/stream-data" => self.stream_data(req).await,
-            "/api/backtest" => self.backtest(req).await,
+            "/api/discover-pairs" => self.discover_pairs(req, tier).await,
+            "/api/stream-data" => self.stream_data(req, tier).await,
+            "/api/backtest" => self.backtest(req, tier).await,
+            "/api/query" => self.query(req, tier).await,
+            "/api/join" => self.join(req, tier).await,
+            "/api/groupby" => self.group_by(req, tier).await,
             #[cfg(all(feature = "rest-api", feature = "metrics"))]
-            "/api/fetch-rest" => self.fetch_rest(req).await,
+            "/api/fetch-rest" => self.fetch_rest(req, tier).await,
             #[cfg(feature = "metrics")]
             "/metrics" => self.metrics_endpoint().await,
             _ => Err(ServerlessError::NotFound),
@@ -605,6 +1290,29 @@ impl ServerlessHandler for PolarwayHandler {
     }
 }
 
+#[async_trait::async_trait]
+impl ServerlessHandler for PolarwayHandler {
+    /// Handle a request inside a span tagged with its request ID
+    ///
+    /// The ID is echoed in the `x-request-id` response header.
+    async fn handle_request(
+        &self,
+        req: ServerlessRequest,
+    ) -> Result<ServerlessResponse, ServerlessError> {
+        let request_id = req.request_id();
+        let span = tracing::info_span!("request", request_id = %request_id);
+
+        let result = self.dispatch(req).instrument(span.clone()).await;
+        if let Err(e) = &result {
+            span.in_scope(|| tracing::warn!("Request failed: {}", e));
+        }
+
+        let mut resp = result?;
+        resp.headers.insert(REQUEST_ID_HEADER.to_string(), request_id);
+        Ok(resp)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -618,6 +1326,7 @@ mod tests {
             headers: HashMap::new(),
             body: vec![],
             query_params: HashMap::new(),
+            remote_addr: Some(IpAddr::from([127, 0, 0, 1])),
         };
 
         let resp = handler.handle_request(req).await.unwrap();
@@ -625,19 +1334,388 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_discover_pairs() {
+    async fn test_request_id_is_echoed() {
         let handler = PolarwayHandler::new();
-        let req = ServerlessRequest {
+        let health = |headers: HashMap<String, String>| ServerlessRequest {
+            method: "GET".to_string(),
+            path: "/health".to_string(),
+            headers,
+            body: vec![],
+            query_params: HashMap::new(),
+            remote_addr: Some(IpAddr::from([127, 0, 0, 1])),
+        };
+
+        let req = health(HashMap::from([(REQUEST_ID_HEADER.to_string(), "req-42".to_string())]));
+        let resp = handler.handle_request(req).await.unwrap();
+        assert_eq!(resp.headers[REQUEST_ID_HEADER], "req-42");
+
+        let resp = handler.handle_request(health(HashMap::new())).await.unwrap();
+        assert!(Uuid::parse_str(&resp.headers[REQUEST_ID_HEADER]).is_ok());
+    }
+
+    fn post(path: &str, body: serde_json::Value) -> ServerlessRequest {
+        ServerlessRequest {
             method: "POST".to_string(),
-            path: "/api/discover-pairs".to_string(),
+            path: path.to_string(),
             headers: HashMap::new(),
-            body: serde_json::json!({
-                "symbols": ["AAPL", "MSFT", "GOOGL"]
-            }).to_string().into_bytes(),
+            body: body.to_string().into_bytes(),
             query_params: HashMap::new(),
-        };
+            remote_addr: Some(IpAddr::from([127, 0, 0, 1])),
+        }
+    }
+
+    fn pairs(resp: &ServerlessResponse) -> Vec<serde_json::Value> {
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        body["pairs"].as_array().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_discover_pairs() {
+        let handler = PolarwayHandler::new();
+        let prices = df! {
+            "AAPL" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "MSFT" => [2.0, 4.0, 6.0, 8.0, 10.0],
+            "GOOGL" => [5.0, 3.0, 4.0, 1.0, 2.0],
+        }.unwrap();
+        let handle = handler.handle_manager.create_handle(prices);
+
+        let req = post("/api/discover-pairs", serde_json::json!({
+            "symbols": ["AAPL", "MSFT", "GOOGL"],
+            "min_correlation": 0.9,
+            "handle": handle
+        }));
 
         let resp = handler.handle_request(req).await.unwrap();
         assert_eq!(resp.status_code, 200);
+
+        let pairs = pairs(&resp);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0]["symbol1"], "AAPL");
+        assert_eq!(pairs[0]["symbol2"], "MSFT");
+        assert!((pairs[0]["correlation"].as_f64().unwrap() - 1.0).abs() < 1e-9);
+        assert_eq!(pairs[0]["method"], "pearson");
+    }
+
+    #[tokio::test]
+    async fn test_discover_pairs_spearman() {
+        let handler = PolarwayHandler::new();
+        // Monotonic but not linear: Spearman is exactly 1
+        let prices = df! {
+            "A" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "B" => [1.0, 4.0, 9.0, 16.0, 100.0],
+        }.unwrap();
+        let handle = handler.handle_manager.create_handle(prices);
+
+        let req = post("/api/discover-pairs", serde_json::json!({
+            "symbols": ["A", "B"],
+            "method": "spearman",
+            "min_correlation": 0.99,
+            "handle": handle
+        }));
+
+        let resp = handler.handle_request(req).await.unwrap();
+        let pairs = pairs(&resp);
+        assert_eq!(pairs.len(), 1);
+        assert_eq!(pairs[0]["correlation"].as_f64().unwrap(), 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_discover_pairs_requires_price_data() {
+        let handler = PolarwayHandler::new();
+        let req = post("/api/discover-pairs", serde_json::json!({
+            "symbols": ["AAPL", "MSFT"]
+        }));
+
+        assert!(matches!(
+            handler.handle_request(req).await,
+            Err(ServerlessError::BadRequest(_))
+        ));
+    }
+
+    /// `count` daily dates from 2025-01-01, as strings
+    fn daily_dates(count: usize) -> Vec<String> {
+        let start = chrono::NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        (0..count)
+            .map(|i| (start + chrono::Duration::days(i as i64)).format("%Y-%m-%d").to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_backtest_rising_series() {
+        let handler = PolarwayHandler::new();
+        let close: Vec<f64> = (0..100).map(|i| 100.0 + i as f64).collect();
+        let handle = handler.handle_manager.create_handle(df! { "date" => daily_dates(100), "close" => close }.unwrap());
+
+        for strategy in ["momentum", "sma_crossover"] {
+            let req = post("/api/backtest", serde_json::json!({
+                "symbol": "BTC",
+                "start_date": "2025-01-01",
+                "end_date": "2025-04-10",
+                "strategy": strategy,
+                "handle": handle
+            }));
+
+            let resp = handler.handle_request(req).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+            let results = &body["results"];
+
+            assert!(results["total_return"].as_f64().unwrap() > 0.0);
+            assert_eq!(results["max_drawdown"].as_f64().unwrap(), 0.0);
+            assert!(results["sharpe_ratio"].as_f64().unwrap() > 0.0);
+            // Enters once after warm-up and never leaves
+            assert_eq!(results["num_trades"].as_u64().unwrap(), 1);
+            assert_eq!(results["strategy"], strategy);
+            assert_eq!(results["bars"].as_u64().unwrap(), 100);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backtest_period_and_nulls() {
+        let handler = PolarwayHandler::new();
+        // Rises for 60 days, then falls
+        let close: Vec<Option<f64>> = (0..100)
+            .map(|i| Some(if i < 60 { 100.0 + i as f64 } else { 160.0 - (i - 60) as f64 }))
+            .collect();
+        let handle = handler
+            .handle_manager
+            .create_handle(df! { "date" => daily_dates(100), "close" => close.clone() }.unwrap());
+        let backtest = |handle: &str, end_date: &str| post("/api/backtest", serde_json::json!({
+            "symbol": "BTC",
+            "start_date": "2025-01-01",
+            "end_date": end_date,
+            "handle": handle
+        }));
+
+        let resp = handler.handle_request(backtest(&handle, "2025-03-01")).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["results"]["bars"].as_u64().unwrap(), 60);
+        assert_eq!(body["results"]["max_drawdown"].as_f64().unwrap(), 0.0);
+
+        let resp = handler.handle_request(backtest(&handle, "2025-04-10")).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["results"]["bars"].as_u64().unwrap(), 100);
+        assert!(body["results"]["max_drawdown"].as_f64().unwrap() < 0.0);
+
+        let mut gappy = close;
+        gappy[10] = None;
+        let gappy = handler
+            .handle_manager
+            .create_handle(df! { "date" => daily_dates(100), "close" => gappy }.unwrap());
+        assert!(matches!(
+            handler.handle_request(backtest(&gappy, "2025-03-01")).await,
+            Err(ServerlessError::BadRequest(_))
+        ));
+        // The gap is outside this period
+        assert!(handler.handle_request(backtest(&gappy, "2025-01-10")).await.is_ok());
+    }
+
+    #[test]
+    fn test_backtest_drawdown_and_exits() {
+        // Up, then down: momentum is long into the decline until the SMA catches up
+        let mut prices: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        prices.extend((0..30).map(|i| 129.0 - 2.0 * i as f64));
+
+        let stats = run_backtest(&prices, Strategy::Momentum { window: 5 });
+        assert!(stats.max_drawdown < 0.0);
+        assert!(stats.num_trades >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_guest_rate_limit() {
+        let handler = PolarwayHandler::new();
+        let health = |ip: [u8; 4], forwarded_for: &str| ServerlessRequest {
+            method: "GET".to_string(),
+            path: "/health".to_string(),
+            headers: HashMap::from([("x-forwarded-for".to_string(), forwarded_for.to_string())]),
+            body: vec![],
+            query_params: HashMap::new(),
+            remote_addr: Some(IpAddr::from(ip)),
+        };
+
+        // Spoofed headers from an untrusted peer don't buy a fresh bucket
+        for i in 0..5 {
+            let req = health([10, 0, 0, 1], &format!("192.0.2.{}", i));
+            assert_eq!(handler.handle_request(req).await.unwrap().status_code, 200);
+        }
+        let err = handler.handle_request(health([10, 0, 0, 1], "192.0.2.99")).await.unwrap_err();
+        assert!(matches!(err, ServerlessError::RateLimitExceeded));
+        assert_eq!(err.status_code(), 429);
+
+        // Other guests have their own bucket
+        assert!(handler.handle_request(health([10, 0, 0, 2], "")).await.is_ok());
+
+        // Guests with no address can't be told apart
+        let mut anonymous = health([10, 0, 0, 3], "");
+        anonymous.remote_addr = None;
+        assert!(matches!(handler.handle_request(anonymous).await, Err(ServerlessError::Unauthorized)));
+    }
+
+    #[test]
+    fn test_client_ip_behind_trusted_proxy() {
+        let proxies: Vec<IpCidr> = vec!["10.0.0.0/8".parse().unwrap(), "fd00::1".parse().unwrap()];
+        let req = |peer: &str, headers: &[(&str, &str)]| ServerlessRequest {
+            method: "GET".to_string(),
+            path: "/health".to_string(),
+            headers: headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            body: vec![],
+            query_params: HashMap::new(),
+            remote_addr: Some(peer.parse().unwrap()),
+        };
+        let ip = |s: &str| Some(s.parse::<IpAddr>().unwrap());
+
+        let forwarded = [("x-forwarded-for", "198.51.100.7, 203.0.113.5, 10.1.2.3")];
+        assert_eq!(client_ip(&req("10.0.0.1", &forwarded), &proxies), ip("203.0.113.5"));
+        assert_eq!(client_ip(&req("192.0.2.1", &forwarded), &proxies), ip("192.0.2.1"));
+        assert_eq!(client_ip(&req("fd00::1", &[("x-real-ip", "203.0.113.9")]), &proxies), ip("203.0.113.9"));
+        assert_eq!(client_ip(&req("10.0.0.1", &[("x-forwarded-for", "bogus")]), &proxies), ip("10.0.0.1"));
+        assert_eq!(client_ip(&req("::ffff:10.0.0.1", &[("x-real-ip", "203.0.113.9")]), &proxies), ip("203.0.113.9"));
+
+        assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+        assert!("0.0.0.0/0".parse::<IpCidr>().unwrap().contains("8.8.8.8".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_rate_limiter_evicts_idle_buckets() {
+        let limiter = RateLimiter::new(std::time::Duration::from_millis(20));
+        limiter.check("ip:10.0.0.1", UserTier::Guest).unwrap();
+        limiter.evict_idle();
+        assert_eq!(limiter.buckets.len(), 1);
+
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        limiter.check("ip:10.0.0.2", UserTier::Guest).unwrap();
+        limiter.evict_idle();
+        assert!(!limiter.buckets.contains_key("ip:10.0.0.1"));
+        assert!(limiter.buckets.contains_key("ip:10.0.0.2"));
+    }
+
+    #[tokio::test]
+    async fn test_query_handle() {
+        let handler = PolarwayHandler::new();
+        let handle = handler.handle_manager.create_handle(df! {
+            "symbol" => ["AAPL", "MSFT", "AAPL"],
+            "close" => [1.0, 2.0, 3.0],
+        }.unwrap());
+
+        let req = post("/api/query", serde_json::json!({
+            "handle": handle,
+            "sql": "SELECT COUNT(*) AS n FROM df WHERE symbol = 'AAPL'"
+        }));
+        let resp = handler.handle_request(req).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["rows"], 1);
+        assert_eq!(body["data"][0]["n"], 2);
+
+        let req = post("/api/query", serde_json::json!({
+            "handle": "no-such-handle",
+            "sql": "SELECT * FROM df"
+        }));
+        assert!(matches!(
+            handler.handle_request(req).await,
+            Err(ServerlessError::BadRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_join_handles() {
+        let handler = PolarwayHandler::new();
+        let left = handler.handle_manager.create_handle(df! {
+            "symbol" => ["AAPL", "MSFT", "GOOGL"],
+            "close" => [1.0, 2.0, 3.0],
+        }.unwrap());
+        let right = handler.handle_manager.create_handle(df! {
+            "symbol" => ["MSFT", "GOOGL", "TSLA"],
+            "sector" => ["tech", "tech", "auto"],
+        }.unwrap());
+
+        let req = post("/api/join", serde_json::json!({
+            "left_handle": left,
+            "right_handle": right,
+            "on": ["symbol"],
+            "how": "inner"
+        }));
+        let resp = handler.handle_request(req).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["rows"], 2);
+        assert_eq!(body["columns"], 3);
+
+        let joined = handler.handle_manager.get_dataframe(body["handle"].as_str().unwrap()).unwrap();
+        let symbols: Vec<_> = joined.column("symbol").unwrap().str().unwrap().into_no_null_iter().collect();
+        assert_eq!(symbols, ["MSFT", "GOOGL"]);
+
+        let req = post("/api/join", serde_json::json!({
+            "left_handle": left,
+            "right_handle": right,
+            "on": ["close"]
+        }));
+        assert!(matches!(
+            handler.handle_request(req).await,
+            Err(ServerlessError::BadRequest(msg)) if msg.contains("right frame")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_group_by_handle() {
+        let handler = PolarwayHandler::new();
+        let handle = handler.handle_manager.create_handle(df! {
+            "symbol" => ["AAPL", "MSFT", "AAPL", "MSFT", "AAPL"],
+            "volume" => [10.0, 20.0, 30.0, 40.0, 50.0],
+        }.unwrap());
+
+        let req = post("/api/groupby", serde_json::json!({
+            "handle": handle,
+            "by": ["symbol"],
+            "aggs": [{"col": "volume", "op": "sum"}]
+        }));
+        let resp = handler.handle_request(req).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["rows"], 2);
+
+        let grouped = handler.handle_manager.get_dataframe(body["handle"].as_str().unwrap()).unwrap();
+        let sums: Vec<_> = grouped.column("volume_sum").unwrap().f64().unwrap().into_no_null_iter().collect();
+        assert_eq!(sums, [90.0, 60.0]);
+
+        for aggs in [
+            serde_json::json!([{"col": "price", "op": "sum"}]),
+            serde_json::json!([{"col": "volume", "op": "median"}]),
+        ] {
+            let req = post("/api/groupby", serde_json::json!({
+                "handle": handle,
+                "by": ["symbol"],
+                "aggs": aggs
+            }));
+            assert!(matches!(
+                handler.handle_request(req).await,
+                Err(ServerlessError::BadRequest(_))
+            ));
+        }
+    }
+
+    #[cfg(all(feature = "metrics", feature = "auth"))]
+    #[tokio::test]
+    async fn test_metrics_labelled_with_tier() {
+        let handler = PolarwayHandler::new();
+        let claims = Claims {
+            sub: "user-1".to_string(),
+            tier: "enterprise".to_string(),
+            exp: (chrono::Utc::now().timestamp() + 3600) as usize,
+        };
+        let token = jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(handler.token_validator.as_ref().unwrap().secret.as_ref()),
+        ).unwrap();
+
+        let handle = handler.handle_manager.create_handle(df! { "x" => [1, 2, 3] }.unwrap());
+        let mut req = post("/api/query", serde_json::json!({
+            "handle": handle,
+            "sql": "SELECT * FROM df"
+        }));
+        req.headers.insert("authorization".to_string(), format!("Bearer {}", token));
+        handler.handle_request(req).await.unwrap();
+
+        let exported = handler.metrics.export();
+        assert!(exported.contains(r#"endpoint="query""#));
+        assert!(exported.contains(r#"tier="enterprise""#));
+        assert!(!exported.contains(r#"tier="unknown""#));
     }
 }